@@ -4,11 +4,14 @@
 //! that the loop re-queues. Variants wrap the per-area command/event enums.
 
 use serde::Deserialize;
+use std::path::PathBuf;
 use strum::Display;
 
+use crate::app::Mode;
 use crate::cargo::{CargoCommand, CargoEvent};
 use crate::components::home::HomeCommand;
 use crate::components::status_bar::StatusCommand;
+use crate::config::Config;
 use crate::search::{SearchCommand, SearchEvent};
 
 #[derive(Debug, Clone, Display, Deserialize)]
@@ -28,14 +31,35 @@ pub enum Action {
 
     // Commands
     ToggleSettings,
+    SwitchMode(Mode),
     Home(HomeCommand),
     Search(SearchCommand),
     Cargo(CargoCommand),
     Status(StatusCommand),
+    /// Open a directory in the user's editor, releasing the terminal while it runs.
+    #[serde(skip)]
+    OpenInEditor(PathBuf),
+    /// Render a README in the terminal, releasing it while the renderer runs, and open the
+    /// repository instead when no renderer could.
+    #[serde(skip)]
+    RenderReadme {
+        name: String,
+        markdown: String,
+        repository: Option<String>,
+    },
+    /// Open the config file in the user's editor, then reload the config from disk.
+    EditConfig,
+    /// Reload the config from disk, e.g. after editing it outside the app.
+    ReloadConfig,
+    /// Open the log file in the user's editor, releasing the terminal while it runs.
+    OpenLog,
 
     // Events
     #[serde(skip)]
     SearchEvent(SearchEvent),
     #[serde(skip)]
     CargoEvent(CargoEvent),
+    /// The config was changed at runtime (e.g. from the settings screen).
+    #[serde(skip)]
+    ConfigChanged(Box<Config>),
 }
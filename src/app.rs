@@ -3,25 +3,38 @@
 //! `App` owns the components, the `Action` channel, and the shared cargo environment. Each
 //! iteration translates terminal events into `Action`s, dispatches them, and renders.
 
+use color_eyre::eyre::eyre;
 use crossterm::event::KeyEvent;
 use ratatui::layout::{Constraint, Layout, Rect};
 use serde::{Deserialize, Serialize};
+use std::io::IsTerminal;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
 use tokio::sync::{RwLock, mpsc};
 use tracing::{debug, error, info};
 
 use crate::action::Action;
 use crate::cargo;
-use crate::cargo::{CargoCommand, CargoEnv, CargoError, CargoEvent, OutputMode};
+use crate::cargo::{
+    CargoCommand, CargoEnv, CargoError, CargoEvent, ManifestSearch, OutputMode, Registry,
+};
 use crate::components::app_id::AppId;
 use crate::components::fps::FpsCounter;
 use crate::components::home::Home;
+use crate::components::home::action_handler::readme_fallback;
+use crate::components::settings::Settings;
 use crate::components::status_bar::{StatusBar, StatusCommand, StatusLevel};
 use crate::components::{Component, Placement};
-use crate::config::Config;
+use crate::config::{Config, get_config_file};
+use crate::editor;
 use crate::errors::AppResult;
+use crate::logging;
+use crate::notification;
+use crate::pager;
+use crate::readme;
+use crate::search::InitialSearch;
 use crate::tui::{Event, Tui};
 
 pub struct App {
@@ -44,6 +57,17 @@ pub enum Mode {
     App,
     #[default]
     Home,
+    Settings,
+}
+
+/// A status line summarizing the keybindings skipped while loading `config`, if any.
+fn keybinding_errors_message(config: &Config) -> Option<String> {
+    (!config.keybinding_errors.is_empty()).then(|| {
+        format!(
+            "Ignored invalid keybindings: {}",
+            config.keybinding_errors.join("; ")
+        )
+    })
 }
 
 impl App {
@@ -52,20 +76,30 @@ impl App {
         frame_rate: f64,
         show_counter: bool,
         project_dir: Option<PathBuf>,
-        initial_search_term: Option<String>,
+        manifest_path: Option<PathBuf>,
+        initial_search: InitialSearch,
     ) -> AppResult<Self> {
         let (action_tx, action_rx) = mpsc::unbounded_channel();
 
-        let cargo_env = Arc::new(RwLock::new(CargoEnv::new(project_dir)));
+        let config = Config::new()?;
+        cargo::set_cargo_path(config.config.cargo_path.clone());
+        cargo::set_manifest_path(manifest_path.clone());
+        let cargo_env = Arc::new(RwLock::new(CargoEnv::new(
+            project_dir,
+            manifest_path,
+            ManifestSearch::from_config(&config.config),
+        )));
 
         let mut components: Vec<Box<dyn Component>> = vec![
             Box::new(Home::new(
-                initial_search_term,
+                &config,
+                initial_search,
                 cargo_env.clone(),
                 action_tx.clone(),
             )?),
+            Box::new(Settings::new()),
             Box::new(StatusBar::new(action_tx.clone())),
-            Box::new(AppId::new()), // Should be after other components so it gets drawn on top of them
+            Box::new(AppId::new(cargo_env.clone())), // Should be after other components so it gets drawn on top of them
         ];
 
         if show_counter {
@@ -76,7 +110,7 @@ impl App {
             cargo_env,
             cargo_busy: Arc::new(AtomicBool::new(false)),
             mode: Mode::Home,
-            config: Config::new()?,
+            config,
             components,
             tick_rate,
             frame_rate,
@@ -89,6 +123,15 @@ impl App {
     }
 
     pub async fn run(&mut self) -> AppResult<()> {
+        // Without a terminal, entering the TUI fails with a raw crossterm error; explain instead.
+        if !std::io::stdout().is_terminal() {
+            return Err(eyre!(
+                "cargo-seek needs an interactive terminal, but its output isn't one (is it \
+                 redirected to a file or a pipe?). To add crates without the TUI, pipe them to \
+                 `cargo seek --batch-add`, one per line."
+            ));
+        }
+
         self.cargo_env.write().await.refresh_blocking();
 
         let mut tui = Tui::new()?
@@ -114,6 +157,12 @@ impl App {
         }
 
         let action_tx = self.action_tx.clone();
+        if let Some(message) = keybinding_errors_message(&self.config) {
+            action_tx.send(Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Error,
+                message,
+            )))?;
+        }
         loop {
             self.handle_events(tui).await?;
             self.handle_actions(tui).await?;
@@ -131,6 +180,74 @@ impl App {
         Ok(())
     }
 
+    /// Opens the config file in the editor, then reloads the config so the edits take effect. Any
+    /// runtime changes from the settings screen are replaced by what's on disk.
+    async fn edit_config(&mut self, tui: &mut Tui) -> AppResult<()> {
+        let path = get_config_file();
+        let edited = Self::with_terminal_released(tui, move || {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            editor::open(&path)
+        })
+        .await;
+        if let Err(report) = edited {
+            error!("failed to open the editor: {report:?}");
+            self.action_tx
+                .send(Action::Status(StatusCommand::UpdateStatus(
+                    StatusLevel::Error,
+                    format!("Couldn't open the editor: {report:#}"),
+                )))?;
+            return Ok(());
+        }
+
+        self.reload_config()
+    }
+
+    /// Opens the log file in the editor and shows its path in the status bar, so it can be found
+    /// even when no editor is set.
+    async fn open_log(&mut self, tui: &mut Tui) -> AppResult<()> {
+        let path = logging::log_path();
+        let shown = path.display().to_string();
+        let (level, message) =
+            match Self::with_terminal_released(tui, move || editor::open(&path)).await {
+                Ok(()) => (StatusLevel::Info, format!("Log: {shown}")),
+                Err(report) => {
+                    error!("failed to open the editor: {report:?}");
+                    (
+                        StatusLevel::Error,
+                        format!("Couldn't open the editor: {report:#}. Log: {shown}"),
+                    )
+                }
+            };
+        self.action_tx
+            .send(Action::Status(StatusCommand::UpdateStatus(level, message)))?;
+        Ok(())
+    }
+
+    /// Re-reads the config from disk and hands it to every component. A config that fails to load
+    /// leaves the current one in place.
+    fn reload_config(&mut self) -> AppResult<()> {
+        let (level, message) = match Config::new() {
+            Ok(config) => {
+                let status = match keybinding_errors_message(&config) {
+                    Some(message) => (StatusLevel::Error, message),
+                    None => (StatusLevel::Success, "Config reloaded".to_string()),
+                };
+                self.action_tx
+                    .send(Action::ConfigChanged(Box::new(config)))?;
+                status
+            }
+            Err(err) => (
+                StatusLevel::Error,
+                format!("Couldn't reload the config: {err}"),
+            ),
+        };
+        self.action_tx
+            .send(Action::Status(StatusCommand::UpdateStatus(level, message)))?;
+        Ok(())
+    }
+
     async fn handle_events(&mut self, tui: &mut Tui) -> AppResult<()> {
         let Some(event) = tui.next_event().await else {
             return Ok(());
@@ -141,7 +258,9 @@ impl App {
             Event::Tick => action_tx.send(Action::Tick)?,
             Event::Render => action_tx.send(Action::Render)?,
             Event::Resize(x, y) => action_tx.send(Action::Resize { w: x, h: y })?,
-            Event::Key(key) => self.handle_key_event(key)?,
+            // A key bound in the keymap is spent on its action, so a component doesn't also act on
+            // it (e.g. `Alt + s` staging the focused crate).
+            Event::Key(key) if self.handle_key_event(key)? => return Ok(()),
             _ => {}
         }
         for component in self.components.iter_mut() {
@@ -152,31 +271,31 @@ impl App {
         Ok(())
     }
 
-    fn handle_key_event(&mut self, key: KeyEvent) -> AppResult<()> {
+    /// Sends the action `key` is bound to in the keymap, returning whether it was bound.
+    fn handle_key_event(&mut self, key: KeyEvent) -> AppResult<bool> {
         let action_tx = self.action_tx.clone();
 
-        for mode in [&self.mode, &Mode::App] {
-            if let Some(keymap) = self.config.keybindings.get(mode) {
-                if let Some(action) = keymap.get(&vec![key]) {
-                    info!("Got action: {action:?}");
-                    action_tx.send(action.clone())?;
-                    return Ok(());
-                }
-
-                // If the key was not handled as a single key action,
-                // then consider it for multi-key combinations.
-                self.last_tick_key_events.push(key);
-
-                // Check for multi-key combinations
-                if let Some(action) = keymap.get(&self.last_tick_key_events) {
-                    info!("Got action: {action:?}");
-                    action_tx.send(action.clone())?;
-                    return Ok(());
-                }
-            }
+        // Recorded once per key (not once per keymap) so multi-key combinations like `gg` match.
+        self.last_tick_key_events.push(key);
+
+        // A component that has, say, a prompt open gets the keys its mode binds; a prompt's
+        // Ctrl+h is a backspace, not the help screen.
+        let raw_keys = self
+            .components
+            .iter()
+            .any(|component| component.wants_raw_keys());
+        if let Some(action) =
+            self.config
+                .keybindings
+                .action_for(self.mode, &self.last_tick_key_events, raw_keys)
+        {
+            info!("Got action: {action:?}");
+            action_tx.send(action.clone())?;
+            self.last_tick_key_events.clear();
+            return Ok(true);
         }
 
-        Ok(())
+        Ok(false)
     }
 
     async fn handle_actions(&mut self, tui: &mut Tui) -> AppResult<()> {
@@ -195,9 +314,59 @@ impl App {
                 Action::ClearScreen => tui.terminal.clear()?,
                 Action::Resize { w, h } => self.handle_resize(tui, *w, *h)?,
                 Action::Render => self.render(tui)?,
+                Action::ToggleSettings => {
+                    let mode = if self.mode == Mode::Settings {
+                        Mode::Home
+                    } else {
+                        Mode::Settings
+                    };
+                    self.action_tx.send(Action::SwitchMode(mode))?;
+                }
+                Action::SwitchMode(mode) => self.mode = *mode,
+                Action::ConfigChanged(config) => {
+                    // Components keep their own copy of the config, so hand every one the update.
+                    self.config = config.as_ref().clone();
+                    cargo::set_cargo_path(self.config.config.cargo_path.clone());
+                    for component in self.components.iter_mut() {
+                        component.register_config_handler(self.config.clone())?;
+                    }
+                }
                 Action::Cargo(cargo_action) => {
                     self.handle_cargo_actions(tui, cargo_action.clone()).await?
                 }
+                Action::OpenInEditor(path) => {
+                    let path = path.clone();
+                    if let Err(report) =
+                        Self::with_terminal_released(tui, move || editor::open(&path)).await
+                    {
+                        error!("failed to open the editor: {report:?}");
+                        self.action_tx
+                            .send(Action::Status(StatusCommand::UpdateStatus(
+                                StatusLevel::Error,
+                                format!("Couldn't open the editor: {report:#}"),
+                            )))?;
+                    }
+                }
+                Action::RenderReadme {
+                    name,
+                    markdown,
+                    repository,
+                } => {
+                    let markdown = markdown.clone();
+                    if let Err(report) =
+                        Self::with_terminal_released(tui, move || readme::render(&markdown)).await
+                    {
+                        error!("failed to render the README: {report:?}");
+                        for action in
+                            readme_fallback(name, repository.clone(), &format!("{report:#}"))
+                        {
+                            self.action_tx.send(action)?;
+                        }
+                    }
+                }
+                Action::EditConfig => self.edit_config(tui).await?,
+                Action::ReloadConfig => self.reload_config()?,
+                Action::OpenLog => self.open_log(tui).await?,
                 Action::Error(message) => {
                     error!("{message}");
                     self.action_tx
@@ -225,15 +394,29 @@ impl App {
     }
 
     async fn handle_cargo_actions(&mut self, tui: &mut Tui, action: CargoCommand) -> AppResult<()> {
+        let registry = Registry::from_config(&self.config.config);
         match action {
             CargoCommand::Add {
                 name,
                 version,
                 features,
                 no_default_features,
+                optional,
+                package,
+                source,
             } => {
-                let progress = format!("Adding {name} v{version}");
-                let success = format!("Added {name} v{version}");
+                let target = package
+                    .as_ref()
+                    .map(|package| format!(" to {package}"))
+                    .unwrap_or_default();
+                let what = source.describe(&version);
+                let what = if optional {
+                    format!("{what} as optional")
+                } else {
+                    what
+                };
+                let progress = format!("Adding {name} {what}{target}");
+                let success = format!("Added {name} {what}{target}");
                 let failure = format!("Failed to add {name}");
                 self.run_cargo_action(
                     tui,
@@ -242,14 +425,28 @@ impl App {
                     success,
                     failure,
                     move |out| {
-                        cargo::add(&name, Some(version), &features, no_default_features, out)
+                        cargo::add(
+                            &name,
+                            Some(version),
+                            &source,
+                            &features,
+                            no_default_features,
+                            optional,
+                            package.as_deref(),
+                            &registry,
+                            out,
+                        )
                     },
                 )
                 .await?;
             }
-            CargoCommand::Remove(name) => {
-                let progress = format!("Removing {name}");
-                let success = format!("Removed {name}");
+            CargoCommand::Remove { name, package } => {
+                let target = package
+                    .as_ref()
+                    .map(|package| format!(" from {package}"))
+                    .unwrap_or_default();
+                let progress = format!("Removing {name}{target}");
+                let success = format!("Removed {name}{target}");
                 let failure = format!("Failed to remove {name}");
                 self.run_cargo_action(
                     tui,
@@ -257,7 +454,7 @@ impl App {
                     progress,
                     success,
                     failure,
-                    move |out| cargo::remove(name, out),
+                    move |out| cargo::remove(name, package.as_deref(), out),
                 )
                 .await?;
             }
@@ -267,8 +464,11 @@ impl App {
                 features,
                 no_default_features,
             } => {
-                let progress = format!("Installing {name} v{version}");
-                let success = format!("Installed {name} v{version}");
+                // The new binary must show up on the refresh that follows.
+                self.cargo_env.write().await.invalidate_installed_binaries();
+                let what = cargo::version_label(&version);
+                let progress = format!("Installing {name} {what}");
+                let success = format!("Installed {name} {what}");
                 let failure = format!("Failed to install {name}");
                 self.run_cargo_action(
                     tui,
@@ -277,12 +477,20 @@ impl App {
                     success,
                     failure,
                     move |out| {
-                        cargo::install(name, Some(version), &features, no_default_features, out)
+                        cargo::install(
+                            name,
+                            Some(version),
+                            &features,
+                            no_default_features,
+                            &registry,
+                            out,
+                        )
                     },
                 )
                 .await?;
             }
             CargoCommand::Uninstall(name) => {
+                self.cargo_env.write().await.invalidate_installed_binaries();
                 let progress = format!("Uninstalling {name}");
                 let success = format!("Uninstalled {name}");
                 let failure = format!("Failed to uninstall {name}");
@@ -296,21 +504,51 @@ impl App {
                 )
                 .await?;
             }
+            CargoCommand::UpdateBinaries(names) => {
+                self.cargo_env.write().await.invalidate_installed_binaries();
+                let what = match names.as_slice() {
+                    [name] => name.clone(),
+                    names => format!("{} binaries", names.len()),
+                };
+                let progress = format!("Updating {what}");
+                let success = format!("Updated {what}");
+                let failure = format!("Failed to update {what}");
+                self.run_cargo_action(
+                    tui,
+                    OutputMode::Inherit,
+                    progress,
+                    success,
+                    failure,
+                    move |out| cargo::update_binaries(&names, &registry, out),
+                )
+                .await?;
+            }
+            CargoCommand::Tree(name) => {
+                let shown = Self::with_terminal_released(tui, move || {
+                    pager::show(&cargo::inverse_tree(&name)?)
+                })
+                .await;
+                if let Err(report) = shown {
+                    error!("failed to show the dependency tree: {report:?}");
+                    let detail = report
+                        .downcast_ref::<CargoError>()
+                        .map(CargoError::summary)
+                        .unwrap_or_else(|| format!("{report:#}"));
+                    self.action_tx
+                        .send(Action::Status(StatusCommand::UpdateStatus(
+                            StatusLevel::Error,
+                            format!("Couldn't show why it's a dependency: {detail}"),
+                        )))?;
+                }
+            }
             CargoCommand::Refresh => {
                 // The cargo subprocesses block, so gather off the event-loop task — running them
                 // here (under the write lock) would freeze rendering. Only the fast apply locks.
                 let cargo_env = self.cargo_env.clone();
-                let (project_dir, project) = {
-                    let env = cargo_env.read().await;
-                    (env.project_dir(), env.project.clone())
-                };
+                let snapshot = cargo_env.read().await.snapshot();
                 let tx = self.action_tx.clone();
                 tokio::spawn(async move {
-                    match tokio::task::spawn_blocking(move || {
-                        CargoEnv::gather(project_dir, project)
-                    })
-                    .await
-                    {
+                    match tokio::task::spawn_blocking(move || CargoEnv::gather(snapshot)).await {
                         Ok(gathered) => {
                             cargo_env.write().await.apply(gathered);
                             tx.send(Action::CargoEvent(CargoEvent::Refreshed)).ok();
@@ -330,7 +568,8 @@ impl App {
     /// `out` drives both how cargo connects to the terminal and how the loop runs it:
     /// `OutputMode::Inherit` (add/install) releases the terminal and awaits (nothing renders
     /// meanwhile); `OutputMode::Capture` (remove/uninstall) keeps the TUI up and runs detached. The
-    /// same `out` reaches `op`, so terminal handling and output mode can't diverge.
+    /// same `out` reaches `op`, so terminal handling and output mode can't diverge. Attached commands
+    /// that ran long send a desktop notification when done, with `notify_on_completion` on.
     async fn run_cargo_action<F>(
         &mut self,
         tui: &mut Tui,
@@ -364,8 +603,19 @@ impl App {
 
         match out {
             OutputMode::Inherit => {
+                let started = Instant::now();
                 let result = Self::with_terminal_released(tui, move || op(out)).await;
                 busy.store(false, Ordering::SeqCst);
+                if notification::should_notify(
+                    self.config.config.notify_on_completion,
+                    started.elapsed(),
+                ) {
+                    notification::notify(if result.is_ok() {
+                        success.clone()
+                    } else {
+                        failure.clone()
+                    });
+                }
                 Self::report_cargo_result(&tx, result, success, failure);
             }
             OutputMode::Capture => {
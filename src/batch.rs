@@ -0,0 +1,81 @@
+//! `--batch-add`: adds the crates listed on stdin with `cargo add`, without the TUI, for setup
+//! scripts and CI.
+
+use color_eyre::eyre::eyre;
+use std::io::BufRead;
+
+use crate::cargo::{self, CargoError, DependencySource, OutputMode, Registry};
+use crate::config::Config;
+use crate::errors::AppResult;
+
+/// Adds each crate read from `input`, one `name` or `name@version` per line, printing whether
+/// each was added. Blank lines and `#` comments are skipped. Fails once all have been tried if any
+/// couldn't be added.
+pub fn add_all(input: impl BufRead) -> AppResult<()> {
+    let config = Config::new()?;
+    cargo::set_cargo_path(config.config.cargo_path.clone());
+    let registry = Registry::from_config(&config.config);
+
+    let mut failed = 0;
+    for line in input.lines() {
+        let line = line?;
+        let Some((name, version)) = parse_line(&line) else {
+            continue;
+        };
+        let result = cargo::add(
+            name,
+            version.map(str::to_string),
+            &DependencySource::Registry,
+            &[],
+            false,
+            false,
+            None,
+            &registry,
+            OutputMode::Capture,
+        );
+        match result {
+            Ok(()) => println!("added {}", line.trim()),
+            Err(err) => {
+                failed += 1;
+                let reason = err
+                    .downcast_ref::<CargoError>()
+                    .map_or_else(|| format!("{err:#}"), CargoError::summary);
+                println!("failed {}: {reason}", line.trim());
+            }
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        1 => Err(eyre!("1 crate couldn't be added")),
+        n => Err(eyre!("{n} crates couldn't be added")),
+    }
+}
+
+/// The crate name and version, if any, on a line of `--batch-add` input. `None` for a blank line
+/// or a comment.
+fn parse_line(line: &str) -> Option<(&str, Option<&str>)> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+    match line.split_once('@') {
+        Some((name, version)) => Some((name.trim(), Some(version.trim()))),
+        None => Some((line, None)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_line_reads_names_and_versions() {
+        assert_eq!(parse_line("serde"), Some(("serde", None)));
+        assert_eq!(parse_line("  tokio@1.40 "), Some(("tokio", Some("1.40"))));
+        assert_eq!(parse_line(""), None);
+        assert_eq!(parse_line("   "), None);
+        assert_eq!(parse_line("# dev tools"), None);
+    }
+}
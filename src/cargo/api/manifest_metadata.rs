@@ -1,4 +1,6 @@
-﻿use serde::{Deserialize, Serialize};
+﻿use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ManifestMetadata {
@@ -11,6 +13,8 @@ pub struct Package {
     pub version: Option<String>,
     pub description: Option<String>,
     pub dependencies: Vec<Dependency>,
+    /// The package's `Cargo.toml`; its directory holds the package's source.
+    pub manifest_path: PathBuf,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
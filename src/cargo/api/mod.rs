@@ -1,11 +1,14 @@
+use std::env;
+use std::ffi::OsString;
 #[cfg(windows)]
 use std::os::windows::process::CommandExt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::{PoisonError, RwLock};
 
 use color_eyre::eyre::WrapErr;
 
-use crate::cargo::CargoError;
+use crate::cargo::{CargoError, DependencySource, GitReference, Registry};
 use crate::errors::AppResult;
 
 mod installed_binary;
@@ -14,10 +17,37 @@ mod manifest_metadata;
 pub use installed_binary::*;
 pub use manifest_metadata::*;
 
+/// Reads the workspace's own packages (no dependencies) from `cargo metadata`.
 pub fn get_metadata(manifest_path: &Path) -> AppResult<ManifestMetadata> {
-    let output = cargo_cmd()
-        .arg("metadata")
-        .arg("--no-deps")
+    run_metadata(manifest_path, true)
+}
+
+/// Finds the source directory of the package named `name` among the workspace's packages and their
+/// resolved dependencies, e.g. under `~/.cargo/registry/src`. When several versions are in the
+/// dependency graph, the newest one wins. Returns `None` when the package isn't in the graph.
+pub fn locate_package_dir(manifest_path: &Path, name: &str) -> AppResult<Option<PathBuf>> {
+    let metadata = run_metadata(manifest_path, false)?;
+    let newest = metadata
+        .packages
+        .into_iter()
+        .filter(|package| package.name == name)
+        .max_by_key(|package| {
+            package
+                .version
+                .as_deref()
+                .and_then(|v| semver::Version::parse(v).ok())
+        });
+
+    Ok(newest.and_then(|package| package.manifest_path.parent().map(Path::to_path_buf)))
+}
+
+fn run_metadata(manifest_path: &Path, no_deps: bool) -> AppResult<ManifestMetadata> {
+    let mut cmd = cargo_cmd();
+    cmd.arg("metadata");
+    if no_deps {
+        cmd.arg("--no-deps");
+    }
+    let output = cmd
         .arg("--format-version")
         .arg("1")
         .arg("--manifest-path")
@@ -33,9 +63,8 @@ pub fn get_metadata(manifest_path: &Path) -> AppResult<ManifestMetadata> {
         .into());
     }
 
-    let stdout =
-        String::from_utf8(output.stdout).wrap_err("`cargo metadata` produced invalid UTF-8")?;
-    serde_json::from_str(&stdout).wrap_err("failed to parse `cargo metadata` output")
+    serde_json::from_str(&lossy_utf8(output.stdout))
+        .wrap_err("failed to parse `cargo metadata` output")
 }
 
 pub fn get_installed_binaries() -> AppResult<Vec<InstalledBinary>> {
@@ -53,9 +82,7 @@ pub fn get_installed_binaries() -> AppResult<Vec<InstalledBinary>> {
         .into());
     }
 
-    let stdout = String::from_utf8(output.stdout)
-        .wrap_err("`cargo install --list` produced invalid UTF-8")?;
-    Ok(parse_installed_binaries(&stdout))
+    Ok(parse_installed_binaries(&lossy_utf8(output.stdout)))
 }
 
 /// Parses the output of `cargo install --list`.
@@ -98,6 +125,80 @@ fn parse_installed_binaries(stdout: &str) -> Vec<InstalledBinary> {
     packages
 }
 
+/// A crate listed by `cargo search`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchHit {
+    pub name: String,
+    pub version: String,
+    pub description: Option<String>,
+}
+
+/// Searches `registry` with `cargo search`, returning at most `limit` hits (cargo caps this at 100)
+/// and the total number of matches the registry reported.
+pub fn search(term: &str, limit: usize, registry: &Registry) -> AppResult<(Vec<SearchHit>, usize)> {
+    let limit = limit.min(100).to_string();
+    let mut args = vec!["search", "--limit", limit.as_str()];
+    args.extend(registry.cargo_args());
+    args.push(term);
+
+    let output = cargo_cmd()
+        .args(args)
+        .output()
+        .wrap_err("failed to run `cargo search`")?;
+
+    if !output.status.success() {
+        return Err(CargoError::Failed {
+            command: "search".to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        }
+        .into());
+    }
+
+    Ok(parse_search_output(&lossy_utf8(output.stdout)))
+}
+
+/// Parses the output of `cargo search`.
+///
+/// Each hit is a line of the form `<name> = "<version>"    # <description>` (the description is
+/// optional). When more crates matched than were listed, a trailing
+/// `... and <n> crates more (use --limit N for more)` line gives the remainder.
+fn parse_search_output(stdout: &str) -> (Vec<SearchHit>, usize) {
+    let mut hits = Vec::new();
+    let mut more = 0;
+
+    for line in stdout.lines() {
+        if let Some(rest) = line.strip_prefix("... and ") {
+            more = rest
+                .split_whitespace()
+                .next()
+                .and_then(|n| n.parse().ok())
+                .unwrap_or_default();
+            continue;
+        }
+
+        let Some((name, rest)) = line.split_once(" = \"") else {
+            continue;
+        };
+        let Some((version, rest)) = rest.split_once('"') else {
+            continue;
+        };
+        let description = rest
+            .trim()
+            .strip_prefix('#')
+            .map(|d| d.trim().to_string())
+            .filter(|d| !d.is_empty());
+
+        hits.push(SearchHit {
+            name: name.trim().to_string(),
+            version: version.to_string(),
+            description,
+        });
+    }
+
+    let total = hits.len() + more;
+    (hits, total)
+}
+
 /// How a cargo subprocess connects to the terminal.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputMode {
@@ -110,33 +211,173 @@ pub enum OutputMode {
     Capture,
 }
 
+/// Adds `crate_name` to the project. `package` picks the workspace member to add it to (`-p`);
+/// `None` targets the manifest set with [`set_manifest_path`], else the nearest one.
+#[allow(clippy::too_many_arguments)]
 pub fn add(
     crate_name: &str,
     version: Option<String>,
+    source: &DependencySource,
     features: &[String],
     no_default_features: bool,
+    optional: bool,
+    package: Option<&str>,
+    registry: &Registry,
     out: OutputMode,
 ) -> AppResult<()> {
-    let spec = match version {
-        Some(v) => format!("{crate_name}@{v}"),
-        None => crate_name.to_string(),
+    let mut args = add_args(
+        crate_name,
+        version,
+        source,
+        features,
+        no_default_features,
+        optional,
+        package,
+        registry,
+    );
+    args.extend(manifest_path_args());
+    run_cargo_with(out, args.iter().map(String::as_str).collect())
+}
+
+/// Runs the same `cargo add` as [`add`] with `--dry-run`, returning what cargo reports it would
+/// change (the resolved version and its enabled features) without touching the manifest.
+#[allow(clippy::too_many_arguments)]
+pub fn add_dry_run(
+    crate_name: &str,
+    version: Option<String>,
+    source: &DependencySource,
+    features: &[String],
+    no_default_features: bool,
+    optional: bool,
+    package: Option<&str>,
+    registry: &Registry,
+) -> AppResult<String> {
+    let mut args = add_args(
+        crate_name,
+        version,
+        source,
+        features,
+        no_default_features,
+        optional,
+        package,
+        registry,
+    );
+    args.extend(manifest_path_args());
+    args.push("--dry-run".to_string());
+
+    let stderr = run_cargo_captured(args.iter().map(String::as_str).collect())?;
+    Ok(clean_dry_run_output(&stderr))
+}
+
+/// The `cargo add` arguments. `version` and `registry` only apply to a registry source; an empty
+/// `version` leaves cargo to pick the latest.
+#[allow(clippy::too_many_arguments)]
+fn add_args(
+    crate_name: &str,
+    version: Option<String>,
+    source: &DependencySource,
+    features: &[String],
+    no_default_features: bool,
+    optional: bool,
+    package: Option<&str>,
+    registry: &Registry,
+) -> Vec<String> {
+    let spec = match (source, version.filter(|v| !v.is_empty())) {
+        (DependencySource::Registry, Some(v)) => format!("{crate_name}@{v}"),
+        _ => crate_name.to_string(),
     };
-    let features = features.join(",");
 
-    let mut args = vec!["add", spec.as_str()];
+    let mut args = vec!["add".to_string(), spec];
+    if let Some(package) = package {
+        args.extend(["-p".to_string(), package.to_string()]);
+    }
+    match source {
+        DependencySource::Registry => {
+            args.extend(registry.cargo_args().into_iter().map(String::from));
+        }
+        DependencySource::Git { url, reference } => {
+            args.extend(["--git".to_string(), url.clone()]);
+            match reference {
+                Some(GitReference::Branch(branch)) => {
+                    args.extend(["--branch".to_string(), branch.clone()]);
+                }
+                Some(GitReference::Rev(rev)) => args.extend(["--rev".to_string(), rev.clone()]),
+                None => {}
+            }
+        }
+        DependencySource::Path(path) => {
+            args.extend(["--path".to_string(), path.display().to_string()]);
+        }
+    }
     if no_default_features {
-        args.push("--no-default-features");
+        args.push("--no-default-features".to_string());
     }
     if !features.is_empty() {
-        args.push("--features");
-        args.push(features.as_str());
+        args.push("--features".to_string());
+        args.push(features.join(","));
+    }
+    if optional {
+        args.push("--optional".to_string());
     }
+    args
+}
 
-    run_cargo_with(out, args)
+/// Drops the closing "aborting add/remove due to dry run" warning from `--dry-run` output, and the
+/// blank lines and trailing whitespace around what's left.
+fn clean_dry_run_output(stderr: &str) -> String {
+    stderr
+        .lines()
+        .filter(|line| !line.contains("due to dry run"))
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_matches('\n')
+        .to_string()
 }
 
-pub fn remove(crate_name: String, out: OutputMode) -> AppResult<()> {
-    run_cargo_with(out, vec!["remove", crate_name.as_str()])
+/// Removes `crate_name` from the project, from the workspace member `package` when given.
+pub fn remove(crate_name: String, package: Option<&str>, out: OutputMode) -> AppResult<()> {
+    let args = remove_args(&crate_name, package);
+    run_cargo_with(out, args.iter().map(String::as_str).collect())
+}
+
+/// Runs the same `cargo remove` as [`remove`] with `--dry-run`, returning what cargo reports it
+/// would change. Fails with a [`CargoError`] carrying cargo's diagnostics when the removal can't
+/// go through, e.g. while a feature still enables the dependency.
+pub fn remove_dry_run(crate_name: &str, package: Option<&str>) -> AppResult<String> {
+    let mut args = remove_args(crate_name, package);
+    args.push("--dry-run".to_string());
+
+    let stderr = run_cargo_captured(args.iter().map(String::as_str).collect())?;
+    Ok(clean_dry_run_output(&stderr))
+}
+
+fn remove_args(crate_name: &str, package: Option<&str>) -> Vec<String> {
+    let mut args = vec!["remove".to_string(), crate_name.to_string()];
+    if let Some(package) = package {
+        args.extend(["-p".to_string(), package.to_string()]);
+    }
+    args.extend(manifest_path_args());
+    args
+}
+
+/// What `cargo tree -i` prints for `crate_name`: the project's dependencies that pull it in, up
+/// to the project itself.
+pub fn inverse_tree(crate_name: &str) -> AppResult<String> {
+    let mut args = vec!["tree".to_string(), "-i".to_string(), crate_name.to_string()];
+    args.extend(manifest_path_args());
+    let command = args[0].clone();
+
+    let output = cargo_cmd()
+        .args(&args)
+        .output()
+        .wrap_err("failed to run cargo")?;
+    if !output.status.success() {
+        let stderr = lossy_utf8(output.stderr);
+        return Err(CargoError::Failed { command, stderr }.into());
+    }
+
+    Ok(lossy_utf8(output.stdout))
 }
 
 pub fn install(
@@ -144,15 +385,17 @@ pub fn install(
     version: Option<String>,
     features: &[String],
     no_default_features: bool,
+    registry: &Registry,
     out: OutputMode,
 ) -> AppResult<()> {
-    let spec = match version {
+    let spec = match version.filter(|v| !v.is_empty()) {
         Some(v) => format!("{crate_name}@{v}"),
         None => crate_name,
     };
     let features = features.join(",");
 
     let mut args = vec!["install", "--locked", spec.as_str()];
+    args.extend(registry.cargo_args());
     if no_default_features {
         args.push("--no-default-features");
     }
@@ -164,6 +407,18 @@ pub fn install(
     run_cargo_with(out, args)
 }
 
+/// Reinstalls `crate_names` at their latest versions in one `cargo install --force`.
+pub fn update_binaries(
+    crate_names: &[String],
+    registry: &Registry,
+    out: OutputMode,
+) -> AppResult<()> {
+    let mut args = vec!["install", "--locked", "--force"];
+    args.extend(crate_names.iter().map(String::as_str));
+    args.extend(registry.cargo_args());
+    run_cargo_with(out, args)
+}
+
 pub fn uninstall(crate_name: String, out: OutputMode) -> AppResult<()> {
     run_cargo_with(out, vec!["uninstall", crate_name.as_str()])
 }
@@ -204,8 +459,7 @@ fn run_cargo_captured(args: Vec<&str>) -> AppResult<String> {
         .args(args)
         .output()
         .wrap_err("failed to run cargo")?;
-    let stderr =
-        String::from_utf8(output.stderr).wrap_err("cargo wrote invalid UTF-8 to stderr")?;
+    let stderr = lossy_utf8(output.stderr);
 
     if !output.status.success() {
         return Err(CargoError::Failed { command, stderr }.into());
@@ -214,17 +468,68 @@ fn run_cargo_captured(args: Vec<&str>) -> AppResult<String> {
     Ok(stderr)
 }
 
+/// Cargo's output as text, with any invalid UTF-8 (e.g. from an exotic locale) replaced by `�`
+/// rather than failing the whole command over it.
+fn lossy_utf8(bytes: Vec<u8>) -> String {
+    String::from_utf8(bytes)
+        .unwrap_or_else(|err| String::from_utf8_lossy(err.as_bytes()).into_owned())
+}
+
+/// The `cargo_path` from the config, set at startup and whenever the config changes.
+static CARGO_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Makes every cargo subprocess run `path` (the `cargo_path` config option). `None` falls back to
+/// the `CARGO` environment variable, then to `cargo` on `PATH`.
+pub fn set_cargo_path(path: Option<PathBuf>) {
+    *CARGO_PATH.write().unwrap_or_else(PoisonError::into_inner) = path;
+}
+
+/// The `--manifest-path` given on the command line, set at startup.
+static MANIFEST_PATH: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Makes `cargo add` and `cargo remove` edit the project at `path` rather than the one nearest to
+/// the working directory.
+pub fn set_manifest_path(path: Option<PathBuf>) {
+    *MANIFEST_PATH
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = path;
+}
+
+/// `--manifest-path` and the manifest set with [`set_manifest_path`], if any.
+fn manifest_path_args() -> Vec<String> {
+    MANIFEST_PATH
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .map(|path| vec!["--manifest-path".to_string(), path.display().to_string()])
+        .unwrap_or_default()
+}
+
+fn cargo_program() -> OsString {
+    let configured = CARGO_PATH.read().unwrap_or_else(PoisonError::into_inner);
+    resolve_cargo_program(configured.as_deref(), env::var_os("CARGO"))
+}
+
+/// Picks the cargo to run: the configured path, else `$CARGO` (set by cargo for the subcommands it
+/// runs, and by rustup toolchain shims), else `cargo` from `PATH`.
+fn resolve_cargo_program(configured: Option<&Path>, cargo_env: Option<OsString>) -> OsString {
+    configured
+        .map(|path| path.as_os_str().to_owned())
+        .or(cargo_env.filter(|cargo| !cargo.is_empty()))
+        .unwrap_or_else(|| OsString::from("cargo"))
+}
+
 fn cargo_cmd() -> Command {
     #[cfg(windows)]
     {
-        let mut cmd = Command::new("cargo");
+        let mut cmd = Command::new(cargo_program());
         const CREATE_NO_WINDOW: u32 = 0x08000000;
         cmd.creation_flags(CREATE_NO_WINDOW);
         cmd
     }
     #[cfg(not(windows))]
     {
-        Command::new("cargo")
+        Command::new(cargo_program())
     }
 }
 
@@ -233,6 +538,35 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn add_args_for_a_git_source_skip_the_version_and_registry() {
+        let source = DependencySource::Git {
+            url: "https://github.com/serde-rs/serde".into(),
+            reference: Some(GitReference::Branch("master".into())),
+        };
+        let registry = Registry::Index("sparse+https://my.registry/index/".into());
+        assert_eq!(
+            add_args(
+                "serde",
+                Some("1.0.0".into()),
+                &source,
+                &[],
+                false,
+                false,
+                None,
+                &registry
+            ),
+            vec![
+                "add",
+                "serde",
+                "--git",
+                "https://github.com/serde-rs/serde",
+                "--branch",
+                "master"
+            ]
+        );
+    }
+
     fn bin(name: &str, version: &str) -> InstalledBinary {
         InstalledBinary {
             name: name.to_string(),
@@ -240,6 +574,119 @@ mod tests {
         }
     }
 
+    fn hit(name: &str, version: &str, description: Option<&str>) -> SearchHit {
+        SearchHit {
+            name: name.to_string(),
+            version: version.to_string(),
+            description: description.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn add_args_mark_an_optional_dependency() {
+        let args = add_args(
+            "serde",
+            None,
+            &DependencySource::Registry,
+            &["derive".into()],
+            false,
+            true,
+            None,
+            &Registry::CratesIo,
+        );
+        assert_eq!(
+            args,
+            vec!["add", "serde", "--features", "derive", "--optional"]
+        );
+    }
+
+    #[test]
+    fn parses_search_hits_and_the_remaining_count() {
+        let stdout = "serde = \"1.0.228\"         # A generic serialization framework\n\
+                      serde_json = \"1.0.145\"    # A JSON serialization file format\n\
+                      ... and 12345 crates more (use --limit N for more)\n";
+        assert_eq!(
+            parse_search_output(stdout),
+            (
+                vec![
+                    hit(
+                        "serde",
+                        "1.0.228",
+                        Some("A generic serialization framework")
+                    ),
+                    hit(
+                        "serde_json",
+                        "1.0.145",
+                        Some("A JSON serialization file format")
+                    ),
+                ],
+                12347
+            )
+        );
+    }
+
+    #[test]
+    fn cargo_program_prefers_the_configured_path_then_the_env() {
+        let configured = Path::new("/opt/rust/bin/cargo");
+        let from_env = Some(OsString::from(
+            "/home/me/.rustup/toolchains/stable/bin/cargo",
+        ));
+
+        assert_eq!(
+            resolve_cargo_program(Some(configured), from_env.clone()),
+            "/opt/rust/bin/cargo"
+        );
+        assert_eq!(
+            resolve_cargo_program(None, from_env),
+            "/home/me/.rustup/toolchains/stable/bin/cargo"
+        );
+        assert_eq!(resolve_cargo_program(None, Some(OsString::new())), "cargo");
+        assert_eq!(resolve_cargo_program(None, None), "cargo");
+    }
+
+    #[test]
+    fn dry_run_output_drops_the_abort_warning() {
+        let stderr = concat!(
+            "      Adding serde v1.0.228 to dependencies\n",
+            "             Features:\n",
+            "             + std   \n",
+            "warning: aborting add due to dry run\n",
+        );
+        assert_eq!(
+            clean_dry_run_output(stderr),
+            concat!(
+                "      Adding serde v1.0.228 to dependencies\n",
+                "             Features:\n",
+                "             + std",
+            )
+        );
+    }
+
+    #[test]
+    fn dry_run_output_drops_the_remove_abort_warning() {
+        let stderr =
+            "    Removing serde from dependencies\nwarning: aborting remove due to dry run\n";
+        assert_eq!(
+            clean_dry_run_output(stderr),
+            "    Removing serde from dependencies"
+        );
+    }
+
+    #[test]
+    fn parses_search_hits_without_a_description() {
+        let stdout = "internal-tool = \"0.3.0\"\n";
+        assert_eq!(
+            parse_search_output(stdout),
+            (vec![hit("internal-tool", "0.3.0", None)], 1)
+        );
+    }
+
+    #[test]
+    fn search_output_skips_unrelated_lines() {
+        assert_eq!(parse_search_output("").0, Vec::new());
+        assert_eq!(parse_search_output("note: something\n").0, Vec::new());
+    }
+
     #[test]
     fn parses_standard_output() {
         let stdout = "cargo-seek v0.1.0:\n    cargo-seek\nripgrep v14.1.0:\n    rg\n";
@@ -272,4 +719,13 @@ mod tests {
             vec![bin("ripgrep", "14.1.0")]
         );
     }
+
+    #[test]
+    fn invalid_utf8_output_still_parses() {
+        let stdout = b"ripgrep v14.1.0:\n    rg\nw\xffird v1.0.0 (/tmp/\xfe):\n".to_vec();
+        assert_eq!(
+            parse_installed_binaries(&lossy_utf8(stdout)),
+            vec![bin("ripgrep", "14.1.0"), bin("w\u{fffd}ird", "1.0.0")]
+        );
+    }
 }
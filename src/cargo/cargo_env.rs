@@ -1,70 +1,174 @@
+use std::collections::HashSet;
+use std::fs;
 use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
 
 use tracing::warn;
 
-use crate::cargo::{InstalledBinary, Project, get_installed_binaries};
+use crate::cargo::{InstalledBinary, ManifestSearch, Project, get_installed_binaries};
+use crate::errors::AppResult;
+use crate::search::Crate;
+
+/// How long a listing of installed binaries is reused before `cargo install --list` runs again.
+/// Installs and uninstalls made through the app invalidate it right away.
+const INSTALLED_BINARIES_TTL: Duration = Duration::from_secs(30);
 
 /// The current cargo environment (installed binaries and current project, if any)
 pub struct CargoEnv {
     pub project: Option<Project>,
     pub installed_binaries: Vec<InstalledBinary>,
+    /// The project's dependencies and the installed binaries as search results, rebuilt on every
+    /// [`CargoEnv::apply`] so searches only have to filter them.
+    pub project_index: Vec<IndexedCrate>,
+    pub binary_index: Vec<IndexedCrate>,
     project_dir: Option<PathBuf>,
+    /// The project's manifest as given with `--manifest-path`, used instead of searching
+    /// `project_dir`.
+    manifest_path: Option<PathBuf>,
+    /// How far up from `project_dir` to look for the project's manifest.
+    manifest_search: ManifestSearch,
+    /// When `installed_binaries` was listed. `None` makes the next gather list them again.
+    binaries_listed_at: Option<Instant>,
+    /// The newest mtime among the project's manifests when `project` was last read. `None` makes
+    /// the next gather re-read it.
+    manifests_modified: Option<SystemTime>,
+}
+
+/// A local crate ready to be matched against search terms.
+pub struct IndexedCrate {
+    /// The crate's name, lowercased once rather than on every search.
+    pub name: String,
+    pub result: Crate,
+}
+
+/// The state [`CargoEnv::gather`] starts from, so it can keep what is still fresh.
+pub struct EnvSnapshot {
+    project_dir: Option<PathBuf>,
+    manifest_path: Option<PathBuf>,
+    manifest_search: ManifestSearch,
+    project: Option<Project>,
+    installed_binaries: Vec<InstalledBinary>,
+    binaries_listed_at: Option<Instant>,
+    manifests_modified: Option<SystemTime>,
 }
 
 /// Snapshot returned by [`CargoEnv::gather`] and consumed by [`CargoEnv::apply`].
 pub struct GatheredEnv {
     installed_binaries: Vec<InstalledBinary>,
+    binaries_listed_at: Option<Instant>,
     project: Option<Project>,
+    manifests_modified: Option<SystemTime>,
 }
 
 impl CargoEnv {
-    pub fn new(project_dir: Option<PathBuf>) -> Self {
+    pub fn new(
+        project_dir: Option<PathBuf>,
+        manifest_path: Option<PathBuf>,
+        manifest_search: ManifestSearch,
+    ) -> Self {
         Self {
             project_dir,
+            manifest_path,
+            manifest_search,
             project: None,
             installed_binaries: Vec::new(),
+            project_index: Vec::new(),
+            binary_index: Vec::new(),
+            binaries_listed_at: None,
+            manifests_modified: None,
         }
     }
 
-    /// Runs the blocking cargo subprocesses (`cargo install --list`, `cargo metadata`) that
-    /// populate the environment. Pass the existing `project` (if any) so a transient manifest
-    /// read failure retains the last-good data rather than clearing it.
-    pub fn gather(project_dir: Option<PathBuf>, project: Option<Project>) -> GatheredEnv {
-        let installed_binaries = get_installed_binaries().unwrap_or_else(|err| {
-            warn!("failed to list installed binaries: {err:#}");
-            Vec::new()
-        });
+    /// The current state, to hand to [`CargoEnv::gather`] off the event-loop task.
+    pub fn snapshot(&self) -> EnvSnapshot {
+        EnvSnapshot {
+            project_dir: self.project_dir.clone(),
+            manifest_path: self.manifest_path.clone(),
+            manifest_search: self.manifest_search.clone(),
+            project: self.project.clone(),
+            installed_binaries: self.installed_binaries.clone(),
+            binaries_listed_at: self.binaries_listed_at,
+            manifests_modified: self.manifests_modified,
+        }
+    }
+
+    /// Runs the blocking cargo subprocesses that populate the environment, skipping those whose
+    /// results in `snapshot` are still fresh: `cargo install --list` within
+    /// [`INSTALLED_BINARIES_TTL`], and `cargo metadata` while no manifest has been modified since.
+    /// A transient failure keeps the last-good data rather than clearing it.
+    pub fn gather(snapshot: EnvSnapshot) -> GatheredEnv {
+        let (installed_binaries, binaries_listed_at) = list_installed_binaries(
+            snapshot.installed_binaries,
+            snapshot.binaries_listed_at,
+            Instant::now(),
+            get_installed_binaries,
+        );
 
-        let project = project
-            .or_else(|| project_dir.as_deref().and_then(Project::from))
+        let mut manifests_modified = None;
+        let project = snapshot
+            .project
+            .or_else(|| match snapshot.manifest_path {
+                Some(manifest_path) => Some(Project::from_manifest(manifest_path)),
+                None => snapshot
+                    .project_dir
+                    .as_deref()
+                    .and_then(|dir| Project::from(dir, &snapshot.manifest_search)),
+            })
             .map(|mut project| {
-                if let Err(err) = project.read() {
-                    warn!("failed to read project manifest: {err:#}");
+                // Taken before reading, so an edit made during the read triggers another one.
+                let modified = newest_manifest_mtime(&project);
+                if modified.is_some() && modified == snapshot.manifests_modified {
+                    manifests_modified = modified;
+                } else {
+                    match project.read() {
+                        Ok(()) => manifests_modified = modified,
+                        Err(err) => warn!("failed to read project manifest: {err:#}"),
+                    }
                 }
                 project
             });
 
         GatheredEnv {
             installed_binaries,
+            binaries_listed_at,
             project,
+            manifests_modified,
         }
     }
 
     /// Stores a [`GatheredEnv`]. No I/O.
     pub fn apply(&mut self, gathered: GatheredEnv) {
         self.installed_binaries = gathered.installed_binaries;
+        self.binaries_listed_at = gathered.binaries_listed_at;
         self.project = gathered.project;
+        self.manifests_modified = gathered.manifests_modified;
+
+        self.project_index = index(
+            self.project
+                .iter()
+                .flat_map(|project| &project.packages)
+                .flat_map(|package| &package.dependencies)
+                .map(Crate::from_dependency),
+        );
+        self.binary_index = index(self.installed_binaries.iter().map(Crate::from_binary));
     }
 
     /// Gathers and applies the environment inline. Blocks on the cargo subprocesses, so use only
     /// before the UI is up; the running app refreshes off the event-loop task instead.
     pub fn refresh_blocking(&mut self) {
-        let gathered = Self::gather(self.project_dir.clone(), self.project.take());
+        let gathered = Self::gather(self.snapshot());
         self.apply(gathered);
     }
 
-    pub fn project_dir(&self) -> Option<PathBuf> {
-        self.project_dir.clone()
+    /// Makes the next gather list installed binaries again, e.g. after installing one.
+    pub fn invalidate_installed_binaries(&mut self) {
+        self.binaries_listed_at = None;
+    }
+
+    /// Makes the next gather redo everything, for refreshes the user asked for.
+    pub fn invalidate(&mut self) {
+        self.binaries_listed_at = None;
+        self.manifests_modified = None;
     }
 
     /// Gets the installed version of the given crate name if it is installed, None otherwise.
@@ -76,10 +180,65 @@ impl CargoEnv {
     }
 }
 
+/// Indexes `crates` by lowercased name, keeping the first of each (workspace members often share
+/// dependencies).
+fn index(crates: impl Iterator<Item = Crate>) -> Vec<IndexedCrate> {
+    let mut seen = HashSet::new();
+    crates
+        .filter(|cr| seen.insert(cr.name.clone()))
+        .map(|result| IndexedCrate {
+            name: result.name.to_lowercase(),
+            result,
+        })
+        .collect()
+}
+
+/// The installed binaries as of `now`: `installed` while still fresh, else what `list` reports.
+/// When `list` fails, `installed` is kept with its old `listed_at`, so the next gather tries again.
+fn list_installed_binaries(
+    installed: Vec<InstalledBinary>,
+    listed_at: Option<Instant>,
+    now: Instant,
+    list: impl FnOnce() -> AppResult<Vec<InstalledBinary>>,
+) -> (Vec<InstalledBinary>, Option<Instant>) {
+    if binaries_fresh(listed_at, now) {
+        return (installed, listed_at);
+    }
+    match list() {
+        Ok(binaries) => (binaries, Some(now)),
+        Err(err) => {
+            warn!("failed to list installed binaries: {err:#}");
+            (installed, listed_at)
+        }
+    }
+}
+
+fn binaries_fresh(listed_at: Option<Instant>, now: Instant) -> bool {
+    listed_at
+        .is_some_and(|listed_at| now.saturating_duration_since(listed_at) < INSTALLED_BINARIES_TTL)
+}
+
+/// The newest mtime among the project's root manifest and its members' (adding with `-p` only
+/// touches the member's), or `None` when none can be read.
+fn newest_manifest_mtime(project: &Project) -> Option<SystemTime> {
+    std::iter::once(&project.manifest_file_path)
+        .chain(
+            project
+                .packages
+                .iter()
+                .map(|package| &package.manifest_path),
+        )
+        .filter_map(|path| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .max()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cargo::Package;
+    use color_eyre::eyre::eyre;
     use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
 
     fn binary(name: &str, version: &str) -> InstalledBinary {
         InstalledBinary {
@@ -92,7 +251,13 @@ mod tests {
         CargoEnv {
             project: None,
             installed_binaries,
+            project_index: Vec::new(),
+            binary_index: Vec::new(),
             project_dir: None,
+            manifest_path: None,
+            manifest_search: ManifestSearch::default(),
+            binaries_listed_at: None,
+            manifests_modified: None,
         }
     }
 
@@ -113,4 +278,88 @@ mod tests {
         let env = env(vec![binary("ripgrep", "14.1.0")]);
         assert_eq!(env.get_installed_version("bat"), None);
     }
+
+    #[test]
+    fn installed_binaries_go_stale_after_the_ttl() {
+        let listed_at = Instant::now();
+        assert!(binaries_fresh(
+            Some(listed_at),
+            listed_at + Duration::from_secs(1)
+        ));
+        assert!(!binaries_fresh(
+            Some(listed_at),
+            listed_at + INSTALLED_BINARIES_TTL
+        ));
+        assert!(!binaries_fresh(None, listed_at));
+    }
+
+    #[test]
+    fn failing_to_list_binaries_keeps_the_last_good_list() {
+        let listed_at = Instant::now();
+        let now = listed_at + INSTALLED_BINARIES_TTL;
+
+        let (binaries, kept_listed_at) = list_installed_binaries(
+            vec![binary("ripgrep", "14.1.0")],
+            Some(listed_at),
+            now,
+            || Err(eyre!("cargo install --list failed")),
+        );
+        assert_eq!(binaries, vec![binary("ripgrep", "14.1.0")]);
+        assert_eq!(kept_listed_at, Some(listed_at));
+
+        let (binaries, relisted_at) =
+            list_installed_binaries(binaries, Some(listed_at), now, || {
+                Ok(vec![binary("bat", "0.25.0")])
+            });
+        assert_eq!(binaries, vec![binary("bat", "0.25.0")]);
+        assert_eq!(relisted_at, Some(now));
+    }
+
+    #[test]
+    fn newest_manifest_mtime_covers_workspace_members() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().join("Cargo.toml");
+        let member = dir.path().join("member.toml");
+        fs::write(&root, "").unwrap();
+        fs::write(&member, "").unwrap();
+        let newer = SystemTime::now() + Duration::from_secs(60);
+        fs::File::options()
+            .write(true)
+            .open(&member)
+            .unwrap()
+            .set_modified(newer)
+            .unwrap();
+
+        let project = Project {
+            manifest_file_path: root,
+            packages: vec![Package {
+                name: "member".into(),
+                version: None,
+                description: None,
+                dependencies: Vec::new(),
+                manifest_path: member,
+            }],
+        };
+        assert_eq!(newest_manifest_mtime(&project), Some(newer));
+    }
+
+    #[test]
+    fn index_lowercases_names_and_keeps_the_first_of_each() {
+        let indexed = index(
+            [
+                binary("Inflector", "0.11.4"),
+                binary("ripgrep", "14.1.0"),
+                binary("Inflector", "0.10.0"),
+            ]
+            .iter()
+            .map(Crate::from_binary),
+        );
+        assert_eq!(
+            indexed
+                .iter()
+                .map(|e| (e.name.as_str(), e.result.version.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("inflector", "0.11.4"), ("ripgrep", "14.1.0")]
+        );
+    }
 }
@@ -7,31 +7,48 @@ mod api;
 mod cargo_env;
 mod error;
 mod project;
+mod registry;
 
 use serde::Deserialize;
+use std::path::PathBuf;
 use strum::Display;
 
 pub use api::*;
-pub use cargo_env::CargoEnv;
+pub use cargo_env::{CargoEnv, IndexedCrate};
 pub use error::CargoError;
 pub use project::*;
+pub use registry::Registry;
 
 /// A cargo command to execute.
-#[derive(Debug, Clone, Display, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Display, Deserialize)]
 pub enum CargoCommand {
     Add {
         name: String,
+        /// Empty for the latest version.
         version: String,
         /// Features to enable. Empty means none beyond the defaults.
         features: Vec<String>,
         /// Pass `--no-default-features` (set when the user unchecks a default feature).
         no_default_features: bool,
+        /// Add it as an optional dependency (`--optional`), to be enabled by a feature.
+        #[serde(default)]
+        optional: bool,
+        /// The workspace member to add to (`-p`). `None` targets the nearest manifest.
+        package: Option<String>,
+        /// Where the dependency comes from. `version` only applies to the registry.
+        #[serde(default)]
+        source: DependencySource,
+    },
+    Remove {
+        name: String,
+        /// The workspace member to remove from (`-p`). `None` targets the nearest manifest.
+        package: Option<String>,
     },
-    Remove(String),
     // Update(String),
     // UpdateAll,
     Install {
         name: String,
+        /// Empty for the latest version.
         version: String,
         /// Features to enable. Empty means none beyond the defaults.
         features: Vec<String>,
@@ -39,13 +56,102 @@ pub enum CargoCommand {
         no_default_features: bool,
     },
     Uninstall(String),
+    /// Reinstall these binaries at their latest version (`cargo install --force`).
+    UpdateBinaries(Vec<String>),
+    /// Show why a dependency is in the project: `cargo tree -i` for it, in the pager.
+    Tree(String),
     /// Re-read the cargo environment.
     Refresh,
 }
 
+/// Where `cargo add` takes a dependency from.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub enum DependencySource {
+    /// The registry searched (crates.io or the configured alternate registry).
+    #[default]
+    Registry,
+    /// A git repository (`--git`), at its default branch unless `reference` is set.
+    Git {
+        url: String,
+        reference: Option<GitReference>,
+    },
+    /// A local directory (`--path`).
+    Path(PathBuf),
+}
+
+impl DependencySource {
+    /// What's being added, for status messages: `v{version}` from the registry, else where from.
+    pub fn describe(&self, version: &str) -> String {
+        match self {
+            DependencySource::Registry => version_label(version),
+            DependencySource::Git { url, reference } => match reference {
+                Some(GitReference::Branch(branch)) => format!("from {url} ({branch})"),
+                Some(GitReference::Rev(rev)) => format!("from {url} ({rev})"),
+                None => format!("from {url}"),
+            },
+            DependencySource::Path(path) => format!("from {}", path.display()),
+        }
+    }
+}
+
+/// `v{version}`, or `latest` when no version was given.
+pub fn version_label(version: &str) -> String {
+    if version.is_empty() {
+        "latest".to_string()
+    } else {
+        format!("v{version}")
+    }
+}
+
+/// What to check out from a git dependency.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum GitReference {
+    Branch(String),
+    Rev(String),
+}
+
+impl GitReference {
+    /// Reads what the user typed as a commit when it looks like a (possibly abbreviated) hash, and
+    /// as a branch otherwise. `None` when it's blank.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if text.is_empty() {
+            None
+        } else if (7..=40).contains(&text.len()) && text.chars().all(|c| c.is_ascii_hexdigit()) {
+            Some(GitReference::Rev(text.to_string()))
+        } else {
+            Some(GitReference::Branch(text.to_string()))
+        }
+    }
+}
+
 /// A cargo-environment event.
 #[derive(Debug, Clone, Display)]
 pub enum CargoEvent {
     /// The cargo environment finished refreshing.
     Refreshed,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn git_reference_reads_hashes_as_revs_and_anything_else_as_branches() {
+        assert_eq!(
+            GitReference::parse("1a2b3c4"),
+            Some(GitReference::Rev("1a2b3c4".into()))
+        );
+        assert_eq!(
+            GitReference::parse(" main "),
+            Some(GitReference::Branch("main".into()))
+        );
+        // Too short to be a hash.
+        assert_eq!(
+            GitReference::parse("cafe"),
+            Some(GitReference::Branch("cafe".into()))
+        );
+        assert_eq!(GitReference::parse("  "), None);
+    }
+}
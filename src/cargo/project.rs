@@ -6,10 +6,34 @@ use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
 
 use color_eyre::eyre::{WrapErr, bail};
+use directories::BaseDirs;
 
 use crate::cargo::{Package, get_metadata};
+use crate::config::AppConfig;
 use crate::errors::AppResult;
 
+/// How far up from the starting directory to look for a `Cargo.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManifestSearch {
+    /// How many parent directories to try above the starting one. `None` walks up to the root.
+    pub max_depth: Option<usize>,
+    /// Don't look above this directory when starting inside it (the user's home, when
+    /// `project_search_stop_at_home` is on).
+    pub stop_at: Option<PathBuf>,
+}
+
+impl ManifestSearch {
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            max_depth: config.project_search_depth,
+            stop_at: config
+                .project_search_stop_at_home
+                .then(|| BaseDirs::new().map(|dirs| dirs.home_dir().to_path_buf()))
+                .flatten(),
+        }
+    }
+}
+
 /// A local cargo project.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Project {
@@ -18,12 +42,12 @@ pub struct Project {
 }
 
 impl Project {
-    pub fn from(path: &Path) -> Option<Project> {
+    pub fn from(path: &Path, search: &ManifestSearch) -> Option<Project> {
         if !path.try_exists().ok().unwrap_or_default() || !path.is_dir() {
             return None;
         }
 
-        if let Ok(Some(manifest_file_path)) = find_project_manifest(path) {
+        if let Ok(Some(manifest_file_path)) = find_project_manifest(path, search) {
             Some(Project {
                 manifest_file_path,
                 packages: Vec::new(),
@@ -33,6 +57,14 @@ impl Project {
         }
     }
 
+    /// The project whose manifest is `manifest_file_path`, without searching for one.
+    pub fn from_manifest(manifest_file_path: PathBuf) -> Project {
+        Project {
+            manifest_file_path,
+            packages: Vec::new(),
+        }
+    }
+
     /// Reads the current project and updates internal state.
     pub fn read(&mut self) -> AppResult<()> {
         if !self.manifest_file_path.exists() {
@@ -72,11 +104,47 @@ impl Project {
             Some(reqs.into_iter().collect::<Vec<_>>().join(", "))
         }
     }
+
+    /// Whether the project declares `package_name` as a normal dependency somewhere, rather than
+    /// only as a dev or build dependency.
+    pub fn is_normal_dependency(&self, package_name: &str) -> bool {
+        self.packages
+            .iter()
+            .flat_map(|package| &package.dependencies)
+            .any(|dependency| {
+                dependency.name == package_name
+                    && matches!(dependency.kind.as_deref(), None | Some("normal"))
+            })
+    }
+
+    /// A short name for the project: its package's name, or the directory name of a workspace
+    /// with several members (falling back to the directory name before metadata is read).
+    pub fn name(&self) -> String {
+        match self.packages.as_slice() {
+            [package] => package.name.clone(),
+            _ => self
+                .manifest_file_path
+                .parent()
+                .and_then(Path::file_name)
+                .map(|dir| dir.to_string_lossy().into_owned())
+                .unwrap_or_else(|| self.manifest_file_path.display().to_string()),
+        }
+    }
 }
 
-fn find_project_manifest(starting_dir_path: &Path) -> AppResult<Option<PathBuf>> {
+/// Looks for a `Cargo.toml` in `starting_dir_path`, then its ancestors, within the limits of
+/// `search`.
+fn find_project_manifest(
+    starting_dir_path: &Path,
+    search: &ManifestSearch,
+) -> AppResult<Option<PathBuf>> {
+    let stop_at = search
+        .stop_at
+        .as_deref()
+        .filter(|stop_at| starting_dir_path.starts_with(stop_at));
     let mut search_path = Some(starting_dir_path);
     let mut manifest_file: Option<DirEntry> = None;
+    let mut depth = 0;
 
     while search_path.is_some() && manifest_file.is_none() {
         let path = search_path.unwrap();
@@ -98,7 +166,11 @@ fn find_project_manifest(starting_dir_path: &Path) -> AppResult<Option<PathBuf>>
             break;
         }
 
+        if stop_at == Some(path) || search.max_depth.is_some_and(|max| depth >= max) {
+            break;
+        }
         search_path = path.parent();
+        depth += 1;
     }
 
     if let Some(manifest_file) = manifest_file {
@@ -131,6 +203,7 @@ mod tests {
             version: None,
             description: None,
             dependencies,
+            manifest_path: PathBuf::from(name).join("Cargo.toml"),
         }
     }
 
@@ -141,12 +214,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn is_normal_dependency_ignores_dev_and_build_only_ones() {
+        let dev = |name: &str| Dependency {
+            kind: Some("dev".into()),
+            ..dep(name, "1")
+        };
+        let workspace = project(vec![
+            package("app", vec![dep("serde", "1"), dev("insta")]),
+            package("core", vec![dev("serde")]),
+        ]);
+        assert!(workspace.is_normal_dependency("serde"));
+        assert!(!workspace.is_normal_dependency("insta"));
+        assert!(!workspace.is_normal_dependency("tokio"));
+    }
+
+    #[test]
+    fn name_is_the_package_or_the_workspace_dir() {
+        let mut workspace = project(vec![package("app", vec![]), package("core", vec![])]);
+        workspace.manifest_file_path = PathBuf::from("/src/shop/Cargo.toml");
+        assert_eq!(workspace.name(), "shop");
+
+        workspace.packages.truncate(1);
+        assert_eq!(workspace.name(), "app");
+    }
+
     #[test]
     fn finds_manifest_in_the_starting_dir() {
         let dir = TempDir::new().unwrap();
         fs::write(dir.path().join("Cargo.toml"), "[package]").unwrap();
         assert_eq!(
-            find_project_manifest(dir.path()).unwrap(),
+            find_project_manifest(dir.path(), &ManifestSearch::default()).unwrap(),
             Some(dir.path().join("Cargo.toml"))
         );
     }
@@ -158,7 +256,7 @@ mod tests {
         let nested = root.path().join("a").join("b");
         fs::create_dir_all(&nested).unwrap();
         assert_eq!(
-            find_project_manifest(&nested).unwrap(),
+            find_project_manifest(&nested, &ManifestSearch::default()).unwrap(),
             Some(root.path().join("Cargo.toml"))
         );
     }
@@ -168,7 +266,7 @@ mod tests {
         let dir = TempDir::new().unwrap();
         fs::write(dir.path().join("cargo.toml"), "[package]").unwrap();
         assert_eq!(
-            find_project_manifest(dir.path()).unwrap(),
+            find_project_manifest(dir.path(), &ManifestSearch::default()).unwrap(),
             Some(dir.path().join("cargo.toml"))
         );
     }
@@ -179,7 +277,49 @@ mod tests {
         let nested = dir.path().join("x").join("y");
         fs::create_dir_all(&nested).unwrap();
         // Walks up to the filesystem root without finding a manifest.
-        assert_eq!(find_project_manifest(&nested).unwrap(), None);
+        assert_eq!(
+            find_project_manifest(&nested, &ManifestSearch::default()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn stops_walking_up_past_the_max_depth() {
+        let root = TempDir::new().unwrap();
+        fs::write(root.path().join("Cargo.toml"), "[package]").unwrap();
+        let nested = root.path().join("a").join("b");
+        fs::create_dir_all(&nested).unwrap();
+
+        let search = |max_depth| ManifestSearch {
+            max_depth: Some(max_depth),
+            stop_at: None,
+        };
+        assert_eq!(find_project_manifest(&nested, &search(1)).unwrap(), None);
+        assert_eq!(
+            find_project_manifest(&nested, &search(2)).unwrap(),
+            Some(root.path().join("Cargo.toml"))
+        );
+    }
+
+    #[test]
+    fn stops_walking_up_at_the_stop_dir() {
+        let root = TempDir::new().unwrap();
+        fs::write(root.path().join("Cargo.toml"), "[package]").unwrap();
+        let home = root.path().join("home");
+        let nested = home.join("projects");
+        fs::create_dir_all(&nested).unwrap();
+
+        let search = ManifestSearch {
+            max_depth: None,
+            stop_at: Some(home.clone()),
+        };
+        assert_eq!(find_project_manifest(&nested, &search).unwrap(), None);
+
+        // Starting outside the stop dir isn't limited by it.
+        assert_eq!(
+            find_project_manifest(root.path(), &search).unwrap(),
+            Some(root.path().join("Cargo.toml"))
+        );
     }
 
     #[test]
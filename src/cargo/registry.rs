@@ -0,0 +1,128 @@
+//! The crate registry to search and to add/install from.
+
+use std::env;
+
+use crate::config::AppConfig;
+
+/// Which registry crates come from. Anything other than crates.io is searched through `cargo
+/// search`, which resolves sparse/git indexes and credentials the same way `cargo add` does.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Registry {
+    #[default]
+    CratesIo,
+    /// A registry defined by name in cargo's config (`[registries.<name>]`) or through
+    /// `CARGO_REGISTRIES_<NAME>_INDEX`. `index` is the URL from that env var, when set.
+    Named { name: String, index: Option<String> },
+    /// A registry given by its index URL, e.g. `sparse+https://my.registry/index/`.
+    Index(String),
+}
+
+impl Registry {
+    /// Picks the registry from config: `registry` (a name) wins over `registry_index` (a URL).
+    /// With neither set, cargo's own `CARGO_REGISTRY_DEFAULT` is honored, and otherwise crates.io
+    /// is used so existing behavior is unchanged.
+    pub fn from_config(config: &AppConfig) -> Self {
+        let name = config
+            .registry
+            .clone()
+            .or_else(|| env::var("CARGO_REGISTRY_DEFAULT").ok())
+            .filter(|name| !name.is_empty());
+
+        match (name, config.registry_index.as_ref()) {
+            (Some(name), _) if name != "crates-io" => {
+                let index = env::var(index_env_var(&name)).ok();
+                Registry::Named { name, index }
+            }
+            (None, Some(index)) if !index.is_empty() => Registry::Index(index.clone()),
+            _ => Registry::CratesIo,
+        }
+    }
+
+    pub fn is_crates_io(&self) -> bool {
+        *self == Registry::CratesIo
+    }
+
+    /// The arguments that point a cargo subcommand (`add`, `install`, `search`) at this registry.
+    pub fn cargo_args(&self) -> Vec<&str> {
+        match self {
+            Registry::CratesIo => Vec::new(),
+            Registry::Named { name, .. } => vec!["--registry", name.as_str()],
+            Registry::Index(index) => vec!["--index", index.as_str()],
+        }
+    }
+}
+
+impl std::fmt::Display for Registry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Registry::CratesIo => write!(f, "crates.io"),
+            Registry::Named {
+                name,
+                index: Some(index),
+            } => write!(f, "{name} ({index})"),
+            Registry::Named { name, index: None } => write!(f, "{name}"),
+            Registry::Index(index) => write!(f, "{index}"),
+        }
+    }
+}
+
+/// The env var cargo reads a named registry's index URL from: `my-reg` → `CARGO_REGISTRIES_MY_REG_INDEX`.
+fn index_env_var(name: &str) -> String {
+    format!(
+        "CARGO_REGISTRIES_{}_INDEX",
+        name.to_uppercase().replace('-', "_")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn index_env_var_follows_cargo_naming() {
+        assert_eq!(index_env_var("my-reg"), "CARGO_REGISTRIES_MY_REG_INDEX");
+    }
+
+    #[test]
+    fn cargo_args_point_at_the_registry() {
+        assert!(Registry::CratesIo.cargo_args().is_empty());
+        assert_eq!(
+            Registry::Named {
+                name: "corp".into(),
+                index: None
+            }
+            .cargo_args(),
+            vec!["--registry", "corp"]
+        );
+        assert_eq!(
+            Registry::Index("sparse+https://r.example/".into()).cargo_args(),
+            vec!["--index", "sparse+https://r.example/"]
+        );
+    }
+
+    #[test]
+    fn index_is_used_when_no_name_is_configured() {
+        let config = AppConfig {
+            registry: None,
+            registry_index: Some("sparse+https://r.example/".into()),
+            ..Default::default()
+        };
+        // `CARGO_REGISTRY_DEFAULT` would take precedence; it isn't set under `cargo test`.
+        if env::var("CARGO_REGISTRY_DEFAULT").is_err() {
+            assert_eq!(
+                Registry::from_config(&config),
+                Registry::Index("sparse+https://r.example/".into())
+            );
+        }
+    }
+
+    #[test]
+    fn crates_io_by_name_is_the_default_registry() {
+        let config = AppConfig {
+            registry: Some("crates-io".into()),
+            ..Default::default()
+        };
+        assert!(Registry::from_config(&config).is_crates_io());
+    }
+}
@@ -3,10 +3,11 @@
 //! `Cli` defines the flags shared by the standalone `cargo-seek` binary and the `cargo seek`
 //! subcommand.
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use std::path::PathBuf;
 
 use crate::config::{get_config_dir, get_data_dir};
+use crate::search::{Scope, Sort};
 
 fn get_current_dir() -> Option<PathBuf> {
     std::env::current_dir().ok()
@@ -19,10 +20,23 @@ pub struct Cli {
     #[arg(default_value=get_current_dir().unwrap_or_default().into_os_string())]
     pub project_dir: Option<PathBuf>,
 
+    /// Path to the Cargo.toml of the project to use, e.g. a workspace member's, instead of
+    /// searching PROJECT_DIR for one
+    #[arg(long, value_name = "PATH", conflicts_with = "project_dir")]
+    pub manifest_path: Option<PathBuf>,
+
     /// Search term to trigger search at startup
     #[arg(short, long = "search", value_name = "TERM")]
     pub search_term: Option<String>,
 
+    /// Scope to start searching in, instead of the configured one
+    #[arg(long, value_enum, value_name = "SCOPE")]
+    pub scope: Option<Scope>,
+
+    /// Sort to start with, instead of the configured one
+    #[arg(long, value_enum, value_name = "SORT")]
+    pub sort: Option<Sort>,
+
     /// Frame rate, i.e. number of frames per second
     #[arg(short, long = "fps", value_name = "FLOAT", default_value_t = 30.0)]
     pub frame_rate: f64,
@@ -34,10 +48,32 @@ pub struct Cli {
     /// Show TPS/FPS counter
     #[arg(long)]
     pub counter: bool,
+
+    /// Read crate names (`name` or `name@version`, one per line) from stdin and `cargo add` each
+    /// to the project, then exit, without starting the UI
+    #[arg(long, conflicts_with = "search_term")]
+    pub batch_add: bool,
+
+    /// Print version and build information as JSON, then exit
+    #[arg(long)]
+    pub version_json: bool,
 }
 
 const VERSION_MESSAGE: &str = env!("CARGO_PKG_VERSION");
 
+/// Version and build information for tooling, as printed by `--version-json`. The git and build
+/// date fields are `null` unless the build sets `VERGEN_GIT_DESCRIBE`/`VERGEN_BUILD_DATE`.
+pub fn version_json() -> String {
+    serde_json::json!({
+        "version": VERSION_MESSAGE,
+        "git": option_env!("VERGEN_GIT_DESCRIBE"),
+        "build_date": option_env!("VERGEN_BUILD_DATE"),
+        "config_dir": get_config_dir(),
+        "data_dir": get_data_dir(),
+    })
+    .to_string()
+}
+
 pub fn version() -> String {
     let description = clap::crate_description!();
 
@@ -45,9 +81,15 @@ pub fn version() -> String {
     let config_dir_path = get_config_dir().display().to_string();
     let data_dir_path = get_data_dir().display().to_string();
 
+    let version = version_line(
+        VERSION_MESSAGE,
+        option_env!("VERGEN_GIT_DESCRIBE"),
+        option_env!("VERGEN_BUILD_DATE"),
+    );
+
     format!(
         "\
-{VERSION_MESSAGE}
+{version}
 
 {description}
 
@@ -55,3 +97,96 @@ Config dir: {config_dir_path}
 Data dir:   {data_dir_path}"
     )
 }
+
+/// The `cargo seek` command that starts the same search: `term` (if any) in `scope`, sorted by
+/// `sort`. Both are given even at their defaults, which the reader's config may change.
+pub fn search_command(term: &str, scope: &Scope, sort: &Sort) -> String {
+    let mut command = String::from("cargo seek");
+    if !term.is_empty() {
+        command.push_str(&format!(" -s {}", quote(term)));
+    }
+    command.push_str(&format!(
+        " --scope {} --sort {}",
+        value_name(scope),
+        value_name(sort)
+    ));
+    command
+}
+
+/// The name `value` is given as on the command line, e.g. `recent-downloads`.
+fn value_name(value: &impl ValueEnum) -> String {
+    value
+        .to_possible_value()
+        .expect("no variant is skipped")
+        .get_name()
+        .to_string()
+}
+
+/// `value` in double quotes, with the characters a shell still expands in them escaped.
+fn quote(value: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in value.chars() {
+        if matches!(c, '"' | '\\' | '$' | '`') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// The package version followed by the git revision and build date, whichever the build provides.
+/// Builds outside a git checkout, e.g. from the crates.io package, report the version alone.
+fn version_line(version: &str, git: Option<&str>, build_date: Option<&str>) -> String {
+    let git = git.filter(|git| !git.is_empty());
+    let build_date = build_date.filter(|date| !date.is_empty());
+    match (git, build_date) {
+        (Some(git), Some(date)) => format!("{version} ({git}, built {date})"),
+        (Some(git), None) => format!("{version} ({git})"),
+        (None, Some(date)) => format!("{version} (built {date})"),
+        (None, None) => version.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn version_line_falls_back_to_the_package_version() {
+        assert_eq!(version_line("0.4.0", None, None), "0.4.0");
+        assert_eq!(version_line("0.4.0", Some(""), None), "0.4.0");
+        assert_eq!(
+            version_line("0.4.0", Some("v0.4.0-2-gabc1234"), Some("2024-05-01")),
+            "0.4.0 (v0.4.0-2-gabc1234, built 2024-05-01)"
+        );
+        assert_eq!(
+            version_line("0.4.0", None, Some("2024-05-01")),
+            "0.4.0 (built 2024-05-01)"
+        );
+    }
+
+    #[test]
+    fn search_command_reproduces_the_search() {
+        assert_eq!(
+            search_command("http client", &Scope::Online, &Sort::RecentDownloads),
+            r#"cargo seek -s "http client" --scope online --sort recent-downloads"#
+        );
+        assert_eq!(
+            search_command("", &Scope::All, &Sort::Relevance),
+            "cargo seek --scope all --sort relevance"
+        );
+        assert_eq!(
+            search_command(r#"say "$HOME""#, &Scope::All, &Sort::Name),
+            r#"cargo seek -s "say \"\$HOME\"" --scope all --sort name"#
+        );
+    }
+
+    #[test]
+    fn scope_and_sort_flags_parse() {
+        let cli = Cli::parse_from(["cargo-seek", "--scope", "project", "--sort", "newly-added"]);
+        assert_eq!(cli.scope, Some(Scope::Project));
+        assert_eq!(cli.sort, Some(Sort::NewlyAdded));
+    }
+}
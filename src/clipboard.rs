@@ -0,0 +1,30 @@
+//! System clipboard access.
+//!
+//! On some platforms (notably X11) the copied text is served by the process that owns it, so the
+//! underlying [`arboard::Clipboard`] is created once and kept alive for the app's lifetime rather
+//! than per copy.
+
+use color_eyre::eyre::WrapErr;
+
+use crate::errors::AppResult;
+
+/// A lazily-opened handle to the system clipboard.
+#[derive(Default)]
+pub struct Clipboard {
+    inner: Option<arboard::Clipboard>,
+}
+
+impl Clipboard {
+    /// Replaces the clipboard contents with `text`.
+    pub fn copy(&mut self, text: &str) -> AppResult<()> {
+        let clipboard = match self.inner.as_mut() {
+            Some(clipboard) => clipboard,
+            None => self
+                .inner
+                .insert(arboard::Clipboard::new().wrap_err("failed to open the clipboard")?),
+        };
+        clipboard
+            .set_text(text)
+            .wrap_err("failed to copy to the clipboard")
+    }
+}
@@ -1,27 +1,36 @@
 use ratatui::{
     Frame,
     layout::{Constraint, Layout, Rect},
-    text::Span,
+    style::Stylize,
+    text::{Line, Span},
     widgets::Paragraph,
 };
+use std::sync::Arc;
+use tokio::sync::RwLock;
 
 use super::Component;
+use super::ux::Icon;
 
 use crate::app::Mode;
+use crate::cargo::CargoEnv;
 use crate::config::Config;
 use crate::errors::AppResult;
+use crate::util::elide;
 
-/// A component that renders the name and version of the app.
+/// A component that renders the name and version of the app, next to the project being worked on.
 pub struct AppId {
-    id: String,
     config: Config,
+    cargo_env: Arc<RwLock<CargoEnv>>,
+    /// The project label last rendered, reused while a refresh holds the cargo env's write lock.
+    project: Option<String>,
 }
 
 impl AppId {
-    pub fn new() -> Self {
+    pub fn new(cargo_env: Arc<RwLock<CargoEnv>>) -> Self {
         Self {
-            id: format!(" 📦 cargo-seek v{} ", env!("CARGO_PKG_VERSION")),
             config: Config::default(),
+            cargo_env,
+            project: None,
         }
     }
 }
@@ -33,9 +42,28 @@ impl Component for AppId {
     }
 
     fn draw(&mut self, _: &Mode, frame: &mut Frame, area: Rect) -> AppResult<()> {
+        let ascii = self.config.config.ascii_icons;
+        // The project dir can come from the command line, so show which one is in use.
+        if let Ok(cargo_env) = self.cargo_env.try_read() {
+            self.project = cargo_env.project.as_ref().map(|project| {
+                let dir = project
+                    .manifest_file_path
+                    .parent()
+                    .unwrap_or(&project.manifest_file_path);
+                Icon::Project.label(format!("{} ({})", project.name(), dir.display()), ascii)
+            });
+        }
+
         let [left, _] = Layout::horizontal([Constraint::Min(0), Constraint::Length(1)]).areas(area);
-        let span = Span::styled(&self.id, self.config.theme.title);
-        let paragraph = Paragraph::new(span).right_aligned();
+        let mut spans = Vec::new();
+        if let Some(project) = &self.project {
+            // Keep clear of the titles drawn on the left of the same row.
+            let max_width = usize::from(left.width / 2);
+            spans.push(Span::raw(elide(project, max_width)).dim());
+        }
+        let id = Icon::Package.label(format!("cargo-seek v{}", env!("CARGO_PKG_VERSION")), ascii);
+        spans.push(Span::styled(id, self.config.theme.title));
+        let paragraph = Paragraph::new(Line::from(spans)).right_aligned();
         frame.render_widget(paragraph, left);
         Ok(())
     }
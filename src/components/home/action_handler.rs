@@ -1,35 +1,48 @@
 use reqwest::Url;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::action::Action;
-use crate::cargo::CargoEvent;
+use crate::cargo::{self, CargoCommand, CargoError, CargoEvent, Registry};
+use crate::cli;
 use crate::components::home::cargo_request::{
-    FeatureStep, PendingCargoRequest, decide_feature_step,
+    FeatureStep, PackageStep, PendingCargoRequest, decide_feature_step, decide_package_step,
+    route_through_package_step,
 };
+use crate::components::home::dependency_list::DependencyList;
+use crate::components::home::detail_fields;
+use crate::components::home::dry_run_preview::DryRunPreview;
+use crate::components::home::feature_diff::FeatureDiffView;
 use crate::components::home::focusable::Focusable;
+use crate::components::home::links_menu::LinksMenu;
 use crate::components::home::overlay::Overlay;
+use crate::components::home::package_picker::PackagePicker;
+use crate::components::home::related_menu::RelatedMenu;
 use crate::components::home::{Home, HomeCommand};
 use crate::components::status_bar::{StatusCommand, StatusDuration, StatusLevel};
+use crate::components::ux::Confirm;
 use crate::errors::AppResult;
-use crate::search::{DEFAULT_PER_PAGE, SearchCommand, SearchEvent, SearchOptions};
-use crate::tui::Tui;
+use crate::readme;
+use crate::search::{
+    DEFAULT_PER_PAGE, LinkFilter, Scope, SearchCommand, SearchEvent, SearchOptions, SearchResults,
+    Sort, cycle, exact_name,
+};
 
-pub async fn handle_action(
-    home: &mut Home,
-    action: &Action,
-    tui: &mut Tui,
-) -> AppResult<Option<Action>> {
-    let _ = tui;
+/// Shown when a project-scoped search runs outside of any cargo project.
+const NO_PROJECT_FOUND: &str = "No Cargo project found in this directory";
+
+pub async fn handle_action(home: &mut Home, action: &Action) -> AppResult<Option<Action>> {
     match action {
-        Action::Tick => {
-            if home.is_searching {
-                home.spinner_state.calc_next();
-            }
+        Action::Tick if home.is_searching || home.is_loading_details() => {
+            home.spinner_state.calc_next();
         }
 
         Action::Home(command) => match command {
             HomeCommand::Focus(focusable) => {
                 home.focused = *focusable;
+                if home.focused != Focusable::Results {
+                    home.typeahead = None;
+                }
             }
             HomeCommand::FocusNext => {
                 let has_search_results = home.search_results.is_some();
@@ -89,8 +102,95 @@ pub async fn handle_action(
                     apply_feature_step(home, step)?;
                 }
             }
+            HomeCommand::ChoosePackage(command) => {
+                let step = {
+                    let cargo_env = home.cargo_env.read().await;
+                    decide_package_step(command.clone(), cargo_env.project.as_ref())
+                };
+                match step {
+                    PackageStep::Run(command) => return preview_or_run(home, command),
+                    PackageStep::Pick(packages) => {
+                        home.overlay = Some(Overlay::Package(PackagePicker::new(
+                            home.config.clone(),
+                            command.clone(),
+                            packages,
+                        )));
+                    }
+                }
+            }
+            HomeCommand::PreviewDryRun {
+                command,
+                output,
+                failed,
+            } => {
+                home.action_tx
+                    .send(Action::Status(StatusCommand::ResetStatus))?;
+                home.overlay = Some(Overlay::DryRunPreview(DryRunPreview::new(
+                    home.config.clone(),
+                    command.clone(),
+                    output,
+                    *failed,
+                )));
+            }
+            HomeCommand::ConfirmCargo(command) => {
+                let question = match command {
+                    // The dry-run preview asks for confirmation itself.
+                    CargoCommand::Remove { .. } if home.config.config.preview_remove => {
+                        return Ok(Some(Action::Home(HomeCommand::ChoosePackage(
+                            command.clone(),
+                        ))));
+                    }
+                    CargoCommand::Remove { name, .. } => {
+                        format!("Are you sure you want to remove {name}?")
+                    }
+                    CargoCommand::Uninstall(name) => {
+                        format!("Are you sure you want to uninstall {name}?")
+                    }
+                    command => return Ok(Some(Action::Cargo(command.clone()))),
+                };
+                home.overlay = Some(Overlay::Confirm(
+                    Confirm::new(home.config.clone(), &question, true),
+                    Action::Cargo(command.clone()),
+                ));
+            }
+            HomeCommand::RunPreviewed(command) => {
+                return Ok(Some(Action::Cargo(command.clone())));
+            }
+            HomeCommand::CompareFeatures => compare_features(home)?,
+            HomeCommand::ShowDependencies => show_dependencies(home)?,
+            HomeCommand::CheckBinaries => check_binaries(home).await?,
+            HomeCommand::UpdateBinaries => {
+                if home.outdated_binaries.is_empty() {
+                    check_binaries(home).await?;
+                } else {
+                    let names: Vec<String> = home
+                        .outdated_binaries
+                        .iter()
+                        .map(|binary| binary.name.clone())
+                        .collect();
+                    home.overlay = Some(Overlay::Confirm(
+                        Confirm::new(
+                            home.config.clone(),
+                            &format!("Update {}?", names.join(", ")),
+                            true,
+                        ),
+                        Action::Cargo(CargoCommand::UpdateBinaries(names)),
+                    ));
+                }
+            }
+            HomeCommand::ShowInverseTree => {
+                if let Some(cr) = home.get_focused_crate() {
+                    home.action_tx.send(if cr.project_version.is_some() {
+                        Action::Cargo(CargoCommand::Tree(cr.name.clone()))
+                    } else {
+                        Action::Status(StatusCommand::UpdateStatus(
+                            StatusLevel::Info,
+                            format!("{} isn't a dependency of the project", cr.name),
+                        ))
+                    })?;
+                }
+            }
             HomeCommand::OpenReadme => {
-                // TODO setting if open in browser or cli
                 if let Some(url) = home
                     .search_results
                     .as_ref()
@@ -98,13 +198,10 @@ pub async fn handle_action(
                     .and_then(|cr| cr.repository.as_ref())
                     .and_then(|docs| Url::parse(docs).ok())
                 {
-                    open::that(url.to_string())?;
+                    open_link(home, url)?;
                 }
             }
-            HomeCommand::RenderReadme(_) => {
-                // TODO: optionally render the README in-terminal (glow/mdcat) instead of
-                // opening it in the browser; fall back to the browser if neither exists.
-            }
+            HomeCommand::RenderReadme => render_readme(home).await?,
             HomeCommand::OpenDocs => {
                 if let Some(url) = home
                     .search_results
@@ -113,7 +210,7 @@ pub async fn handle_action(
                     .and_then(|cr| cr.documentation.as_ref())
                     .and_then(|docs| Url::parse(docs).ok())
                 {
-                    open::that(url.to_string())?;
+                    open_link(home, url)?;
                 }
             }
             HomeCommand::OpenCratesIo => {
@@ -125,7 +222,7 @@ pub async fn handle_action(
                         Url::parse(format!("https://crates.io/crates/{}", cr.id).as_str()).ok()
                     })
                 {
-                    open::that(url.to_string())?;
+                    open_link(home, url)?;
                 }
             }
             HomeCommand::OpenLibRs => {
@@ -137,22 +234,197 @@ pub async fn handle_action(
                         Url::parse(format!("https://lib.rs/crates/{}", cr.id).as_str()).ok()
                     })
                 {
-                    open::that(url.to_string())?;
+                    open_link(home, url)?;
+                }
+            }
+            HomeCommand::OpenLinksMenu => {
+                if let Some(cr) = home.get_focused_crate() {
+                    home.overlay = Some(Overlay::Links(LinksMenu::new(home.config.clone(), cr)));
+                }
+            }
+            HomeCommand::ShowRelated => {
+                if let Some(cr) = home.get_focused_crate()
+                    && let Some(related) = cr.related.as_deref().filter(|r| !r.is_empty())
+                {
+                    let menu = RelatedMenu::new(home.config.clone(), &cr.name, related);
+                    home.overlay = Some(Overlay::Related(menu));
+                }
+            }
+            HomeCommand::OpenLink(link) => {
+                if let Ok(url) = Url::parse(link) {
+                    open_link(home, url)?;
+                }
+            }
+            HomeCommand::OpenInEditor => open_in_editor(home).await?,
+            HomeCommand::CopyDependencySnippet { with_features } => {
+                copy_dependency_snippet(home, *with_features)?;
+            }
+            HomeCommand::CopyName => {
+                if let Some(name) = home.get_focused_crate().map(|cr| cr.name.clone()) {
+                    copy_to_clipboard(home, &name, format!("Copied {name}"))?;
+                }
+            }
+            HomeCommand::CopyDetailField => {
+                let fields = home.detail_fields();
+                if let Some(field) = fields.get(home.selected_field_index(fields.len())) {
+                    match &field.value {
+                        Some(value) => {
+                            copy_to_clipboard(home, value, format!("Copied {}", field.label))?;
+                        }
+                        None => {
+                            home.action_tx
+                                .send(Action::Status(StatusCommand::UpdateStatus(
+                                    StatusLevel::Info,
+                                    format!("{} isn't known yet", field.label),
+                                )))?;
+                        }
+                    }
+                }
+            }
+            HomeCommand::CopySearchCommand => {
+                let command = cli::search_command(&home.results_term(), &home.scope, &home.sort);
+                copy_to_clipboard(home, &command, format!("Copied `{command}`"))?;
+            }
+            HomeCommand::ExportMarkdown(path) => {
+                if let Some(cr) = home.get_focused_crate() {
+                    let name = cr.name.clone();
+                    let text = detail_fields::markdown(&name, &home.detail_fields());
+                    match path {
+                        Some(path) => {
+                            let (level, message) = match std::fs::write(path, text) {
+                                Ok(()) => (
+                                    StatusLevel::Success,
+                                    format!("Wrote {name}'s details to {path}"),
+                                ),
+                                Err(err) => {
+                                    (StatusLevel::Error, format!("Couldn't write {path}: {err}"))
+                                }
+                            };
+                            home.action_tx
+                                .send(Action::Status(StatusCommand::UpdateStatus(
+                                    level, message,
+                                )))?;
+                        }
+                        None => {
+                            copy_to_clipboard(
+                                home,
+                                &text,
+                                format!("Copied {name}'s details as markdown"),
+                            )?;
+                        }
+                    }
+                }
+            }
+            HomeCommand::CopyCloneCommand => {
+                if let Some(cr) = home.get_focused_crate() {
+                    match cr.clone_command() {
+                        Some(command) => {
+                            let success = format!("Copied {command}");
+                            copy_to_clipboard(home, &command, success)?;
+                        }
+                        None => {
+                            home.action_tx
+                                .send(Action::Status(StatusCommand::UpdateStatus(
+                                    StatusLevel::Info,
+                                    format!("{} has no GitHub or GitLab repository", cr.name),
+                                )))?;
+                        }
+                    }
+                }
+            }
+            HomeCommand::ToggleStaged => {
+                if let Some(cr) = home.get_focused_crate() {
+                    let (name, version) = (cr.name.clone(), cr.version.clone());
+                    let message = if home.staging.toggle(&name, &version) {
+                        format!("Staged {name} ({} staged)", home.staging.len())
+                    } else {
+                        format!("Unstaged {name} ({} staged)", home.staging.len())
+                    };
+                    home.action_tx.send(Action::Status(
+                        StatusCommand::UpdateStatusWithDuration(
+                            StatusLevel::Info,
+                            StatusDuration::Short,
+                            message,
+                        ),
+                    ))?;
+                }
+            }
+            HomeCommand::ExportStaged(path) => {
+                let (level, message) = if home.staging.is_empty() {
+                    (
+                        StatusLevel::Info,
+                        "Nothing staged; press s on a crate to stage it".to_string(),
+                    )
+                } else {
+                    match home.staging.export(Path::new(path)) {
+                        Ok(()) => (
+                            StatusLevel::Success,
+                            format!("Wrote {} cargo add lines to {path}", home.staging.len()),
+                        ),
+                        Err(err) => (StatusLevel::Error, format!("Couldn't write {path}: {err}")),
+                    }
+                };
+                home.action_tx
+                    .send(Action::Status(StatusCommand::UpdateStatus(level, message)))?;
+            }
+            HomeCommand::ToggleLatestVersions => {
+                home.show_latest_versions = !home.show_latest_versions;
+                let message = if home.show_latest_versions {
+                    "Listing the latest versions, pre-releases included"
+                } else {
+                    "Listing the default versions"
+                };
+                home.action_tx
+                    .send(Action::Status(StatusCommand::UpdateStatusWithDuration(
+                        StatusLevel::Info,
+                        StatusDuration::Short,
+                        message.to_string(),
+                    )))?;
+            }
+            HomeCommand::ToggleDetailsZoom => match home.width_before_zoom.take() {
+                Some(width) => home.left_column_width_percent = width,
+                None => {
+                    home.width_before_zoom = Some(home.left_column_width_percent);
+                    home.left_column_width_percent = 0;
+                }
+            },
+            HomeCommand::ToggleDescriptions => {
+                home.show_descriptions = !home.show_descriptions;
+            }
+            HomeCommand::GoToFirstResult => {
+                if home.config.config.vim_keys
+                    && home.focused == Focusable::Results
+                    && home.overlay.is_none()
+                    && home.typeahead.is_none()
+                {
+                    return Ok(Some(Action::Search(SearchCommand::SelectFirst)));
                 }
             }
         },
 
-        Action::Search(command) => return handle_search_command(home, command),
+        Action::SwitchMode(mode) => home.mode = *mode,
+
+        Action::Search(command) => return handle_search_command(home, command).await,
 
-        Action::SearchEvent(event) => return handle_search_event(home, event),
+        Action::SearchEvent(event) => return handle_search_event(home, event).await,
 
         Action::CargoEvent(event) => match event {
             CargoEvent::Refreshed => {
                 // Re-annotate the visible results when the cargo environment changes.
+                let cargo_env = home.cargo_env.read().await;
                 if let Some(search_results) = &mut home.search_results {
-                    let cargo_env = home.cargo_env.read().await;
                     search_results.update_results(&cargo_env);
                 }
+                // Binaries updated (or uninstalled) since the check are no longer outdated.
+                home.outdated_binaries.retain(|binary| {
+                    cargo_env.get_installed_version(&binary.name).as_ref()
+                        == Some(&binary.installed)
+                });
+                drop(cargo_env);
+
+                if std::mem::take(&mut home.is_refreshing) {
+                    finish_refresh(home)?;
+                }
             }
         },
         _ => {}
@@ -160,7 +432,10 @@ pub async fn handle_action(
     Ok(None)
 }
 
-fn handle_search_command(home: &mut Home, command: &SearchCommand) -> AppResult<Option<Action>> {
+async fn handle_search_command(
+    home: &mut Home,
+    command: &SearchCommand,
+) -> AppResult<Option<Action>> {
     match command {
         SearchCommand::Clear => home.reset()?,
         SearchCommand::Run {
@@ -169,74 +444,166 @@ fn handle_search_command(home: &mut Home, command: &SearchCommand) -> AppResult<
             hide_help,
             status,
         } => {
-            let tx = home.action_tx.clone();
-
-            let scope = home.scope.clone();
-            let sort = home.sort.clone();
-
-            let status = status.clone().unwrap_or_else(|| "Searching".into());
-            tx.send(Action::Status(StatusCommand::UpdateStatus(
-                StatusLevel::Progress,
-                status,
-            )))?;
-
-            home.is_searching = true;
+            let options = SearchOptions {
+                term: Some(term.clone()),
+                scope: home.scope.clone(),
+                sort: home.sort.clone(),
+                owner: home.owner.clone(),
+                keyword: home.keyword.clone(),
+                normal_deps_only: home.normal_deps_only,
+                links: home.link_filter,
+                page: Some(*page),
+                per_page: Some(DEFAULT_PER_PAGE),
+                max_pages: Some(home.config.config.max_pages),
+            };
             if *hide_help {
                 home.show_help = false;
             }
-
-            home.crate_search_manager.search(
-                SearchOptions {
-                    term: Some(term.clone()),
-                    scope,
-                    sort,
-                    page: Some(*page),
-                    per_page: Some(DEFAULT_PER_PAGE),
-                },
-                Arc::clone(&home.cargo_env),
-            );
+            let status = status.clone().unwrap_or_else(|| "Searching".into());
+            start_search(home, options, status)?;
 
             return Ok(None);
         }
+        SearchCommand::Refresh if home.failed_search.is_some() => {
+            // After a failure (likely transient, e.g. a timeout), re-issue the exact search.
+            let options = home.failed_search.take().expect("checked by the guard");
+            start_search(home, options, "Retrying".into())?;
+        }
+        SearchCommand::Refresh => {
+            // Re-search only once the environment is re-read, so project/installed results and
+            // tags reflect changes made outside the app. Nothing cached survives an explicit refresh.
+            home.cargo_env.write().await.invalidate();
+            home.is_refreshing = true;
+            home.action_tx
+                .send(Action::Status(StatusCommand::UpdateStatus(
+                    StatusLevel::Progress,
+                    "Refreshing".into(),
+                )))?;
+            home.action_tx.send(Action::Cargo(CargoCommand::Refresh))?;
+        }
         SearchCommand::SortBy(sort) => {
-            home.sort = sort.clone();
+            home.action_tx
+                .send(Action::Home(HomeCommand::Focus(Focusable::Search)))?;
+            sort_by(home, sort.clone())?;
+        }
+        SearchCommand::NextSort | SearchCommand::PrevSort => {
+            let sort = cycle(&home.sort, *command == SearchCommand::NextSort);
+            sort_by(home, sort)?;
+        }
+        SearchCommand::Scope(scope) => {
+            home.action_tx
+                .send(Action::Home(HomeCommand::Focus(Focusable::Search)))?;
+            scope_to(home, scope.clone()).await?;
+        }
+        SearchCommand::NextScope | SearchCommand::PrevScope => {
+            let scope = cycle(&home.scope, *command == SearchCommand::NextScope);
+            scope_to(home, scope).await?;
+        }
+        SearchCommand::ListAll(scope) => {
+            home.scope = scope.clone();
+            home.input.reset();
+            home.action_tx.send(Action::Search(SearchCommand::Run {
+                term: String::new(),
+                page: 1,
+                hide_help: true,
+                status: Some(format!("Listing {scope}")),
+            }))?;
+        }
+        SearchCommand::ListPopular => {
+            home.scope = Scope::Online;
+            home.sort = Sort::Downloads;
+            home.input.reset();
+            // A starting point rather than an answer, so the usage stays up beside it.
+            home.action_tx.send(Action::Search(SearchCommand::Run {
+                term: String::new(),
+                page: 1,
+                hide_help: false,
+                status: Some("Listing popular crates".into()),
+            }))?;
+        }
+        SearchCommand::Owner(owner) => {
+            home.owner = owner.clone();
             home.action_tx
                 .send(Action::Home(HomeCommand::Focus(Focusable::Search)))?;
 
             if home.search_results.is_some() {
+                let status = match owner {
+                    Some(owner) => format!("Crates owned by: {owner}"),
+                    None => "Cleared the owner filter".to_string(),
+                };
                 home.action_tx.send(Action::Search(SearchCommand::Run {
-                    term: home.input.value().into(),
+                    term: home.results_term(),
                     page: 1,
                     hide_help: false,
-                    status: Some(format!("Sorting by: {sort}")),
+                    status: Some(status),
                 }))?;
             }
         }
-        SearchCommand::Scope(scope) => {
-            home.scope = scope.clone();
+        SearchCommand::Keyword(keyword) => {
+            home.keyword = keyword.clone();
             home.action_tx
                 .send(Action::Home(HomeCommand::Focus(Focusable::Search)))?;
 
             if home.search_results.is_some() {
+                let status = match keyword {
+                    Some(keyword) => format!("Crates with keyword: {keyword}"),
+                    None => "Cleared the keyword filter".to_string(),
+                };
+                home.action_tx.send(Action::Search(SearchCommand::Run {
+                    term: home.results_term(),
+                    page: 1,
+                    hide_help: false,
+                    status: Some(status),
+                }))?;
+            }
+        }
+        SearchCommand::ToggleNormalDepsOnly => {
+            home.normal_deps_only = !home.normal_deps_only;
+
+            if home.search_results.is_some() {
+                let status = if home.normal_deps_only {
+                    "Leaving dev and build dependencies out of project results"
+                } else {
+                    "Including dev and build dependencies in project results"
+                };
                 home.action_tx.send(Action::Search(SearchCommand::Run {
-                    term: home.input.value().into(),
+                    term: home.results_term(),
                     page: 1,
                     hide_help: false,
-                    status: Some(format!("Scoped to: {scope}")),
+                    status: Some(status.to_string()),
+                }))?;
+            }
+        }
+        SearchCommand::ToggleLinkFilter(link) => {
+            home.link_filter = match link {
+                Some(link) => home.link_filter.toggled(*link),
+                None => LinkFilter::default(),
+            };
+
+            if home.search_results.is_some() {
+                let status = match home.link_filter.label() {
+                    Some(label) => format!("Only crates with {label}"),
+                    None => "Cleared the link filter".to_string(),
+                };
+                home.action_tx.send(Action::Search(SearchCommand::Run {
+                    term: home.results_term(),
+                    page: 1,
+                    hide_help: false,
+                    status: Some(status),
                 }))?;
             }
         }
         SearchCommand::NavPagesForward(pages) => {
-            home.go_pages_forward(*pages, home.input.value())?;
+            home.go_pages_forward(*pages, &home.results_term())?;
         }
         SearchCommand::NavPagesBack(pages) => {
-            home.go_pages_back(*pages, home.input.value())?;
+            home.go_pages_back(*pages, &home.results_term())?;
         }
         SearchCommand::NavFirstPage => {
-            home.go_to_page(1, home.input.value())?;
+            home.go_to_page(1, &home.results_term())?;
         }
         SearchCommand::NavLastPage => {
-            home.go_to_last_page(home.input.value())?;
+            home.go_to_last_page(&home.results_term())?;
         }
         SearchCommand::SelectIndex(index) => {
             if let Some(results) = home.search_results.as_mut() {
@@ -272,9 +639,40 @@ fn handle_search_command(home: &mut Home, command: &SearchCommand) -> AppResult<
     Ok(None)
 }
 
-fn handle_search_event(home: &mut Home, event: &SearchEvent) -> AppResult<Option<Action>> {
+async fn handle_search_event(home: &mut Home, event: &SearchEvent) -> AppResult<Option<Action>> {
     match event {
-        SearchEvent::Completed(results) => {
+        SearchEvent::Partial {
+            generation,
+            results,
+        } => {
+            if !home.crate_search_manager.is_current(*generation) {
+                return Ok(None);
+            }
+
+            let mut results = results.clone();
+            let found = results.current_page_len();
+            let exact_match_ix = results.crates.iter().position(|c| c.exact_match);
+            results.select_index(exact_match_ix.or(Some(0)));
+
+            let page = results.current_page();
+            home.search_results = Some(results);
+            home.on_selection_changed();
+
+            home.action_tx
+                .send(Action::Status(StatusCommand::UpdateStatus(
+                    StatusLevel::Progress,
+                    format!("Searching page {page} online · {found} local results so far"),
+                )))?;
+        }
+        SearchEvent::Completed {
+            generation,
+            results,
+        } => {
+            // A superseded or cancelled search; the current one (if any) still owns the spinner.
+            if !home.crate_search_manager.is_current(*generation) {
+                return Ok(None);
+            }
+
             let mut results = results.clone();
             home.is_searching = false;
 
@@ -289,32 +687,55 @@ fn handle_search_event(home: &mut Home, event: &SearchEvent) -> AppResult<Option
                 results.select_index(Some(0));
             }
 
+            home.missing_exact_match = home
+                .last_search
+                .as_ref()
+                .and_then(|options| missing_exact_match(options, &results));
             home.search_results = Some(results);
             home.on_selection_changed();
 
-            home.action_tx
-                .send(Action::Status(StatusCommand::UpdateStatusWithDuration(
+            let status = if results_len > 0 {
+                StatusCommand::UpdateStatusWithDuration(
                     StatusLevel::Success,
                     StatusDuration::Short,
-                    if results_len > 0 {
-                        format!("Loaded {results_len} results")
-                    } else {
-                        "No results".to_string()
-                    },
-                )))?;
+                    format!("Loaded {results_len} results"),
+                )
+            } else if home.scope.includes(Scope::Project)
+                && home.cargo_env.read().await.project.is_none()
+            {
+                StatusCommand::UpdateStatus(StatusLevel::Info, NO_PROJECT_FOUND.to_string())
+            } else {
+                StatusCommand::UpdateStatusWithDuration(
+                    StatusLevel::Success,
+                    StatusDuration::Short,
+                    "No results".to_string(),
+                )
+            };
+            home.action_tx.send(Action::Status(status))?;
         }
-        SearchEvent::Failed(err) => {
+        SearchEvent::Failed {
+            generation,
+            message,
+        } => {
+            if !home.crate_search_manager.is_current(*generation) {
+                return Ok(None);
+            }
+
             home.is_searching = false;
+            home.failed_search = home.last_search.clone();
             home.action_tx
                 .send(Action::Status(StatusCommand::UpdateStatus(
                     StatusLevel::Error,
-                    err.clone(),
+                    format!("{message} (Ctrl+r to retry)"),
                 )))
                 .ok();
         }
         SearchEvent::MetadataLoaded { response } => {
+            if home.hydrating.as_deref() == Some(response.crate_data.name.as_str()) {
+                home.hydrating = None;
+            }
             if let Some(results) = home.search_results.as_mut() {
-                results.hydrate_selected(response);
+                results.hydrate_selected(response, home.config.config.show_prereleases);
             }
 
             // Resolve a deferred request only when this load is for the crate it was waiting on.
@@ -345,7 +766,73 @@ fn handle_search_event(home: &mut Home, event: &SearchEvent) -> AppResult<Option
                 }
             }
         }
+        SearchEvent::DownloadsLoaded { name, daily } => {
+            if let Some(results) = home.search_results.as_mut() {
+                results.set_daily_downloads(name, daily);
+            }
+        }
+        SearchEvent::DependenciesLoaded { name, dependencies } => {
+            if let Some(results) = home.search_results.as_mut() {
+                results.set_dependencies(name, dependencies);
+            }
+            if home.awaiting_dependencies.as_ref() == Some(name) {
+                home.awaiting_dependencies = None;
+                home.action_tx
+                    .send(Action::Status(StatusCommand::ResetStatus))?;
+                show_dependencies(home)?;
+            }
+        }
+        SearchEvent::BinariesChecked {
+            outdated,
+            unchecked,
+        } => {
+            home.checking_binaries = false;
+            home.outdated_binaries = outdated.clone();
+            let unchecked = match unchecked {
+                0 => String::new(),
+                n => format!(" ({n} not found on crates.io)"),
+            };
+            let (level, message) = match outdated.as_slice() {
+                [] => (
+                    StatusLevel::Success,
+                    format!("Installed binaries are up to date{unchecked}"),
+                ),
+                outdated => (
+                    StatusLevel::Info,
+                    format!(
+                        "Outdated: {}{unchecked}. Press U to update",
+                        outdated
+                            .iter()
+                            .map(|binary| format!(
+                                "{} {} → {}",
+                                binary.name, binary.installed, binary.latest
+                            ))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                ),
+            };
+            home.action_tx
+                .send(Action::Status(StatusCommand::UpdateStatus(level, message)))?;
+        }
+        SearchEvent::RelatedLoaded { name, related } => {
+            if let Some(results) = home.search_results.as_mut() {
+                results.set_related(name, related);
+            }
+        }
+        SearchEvent::OpenIssuesLoaded { name, open_issues } => {
+            if let Some(results) = home.search_results.as_mut() {
+                results.set_open_issues(name, *open_issues);
+            }
+        }
         SearchEvent::MetadataFailed { name, message } => {
+            if home.hydrating.as_ref() == Some(name) {
+                home.hydrating = None;
+            }
+            if let Some(results) = home.search_results.as_mut() {
+                results.mark_metadata_failed(name);
+            }
+
             // If we were waiting on this crate's features, drop the request and say so.
             // Otherwise, it was a passive prefetch, so report it as a details-loading failure.
             let waiting_on_features = home
@@ -370,6 +857,520 @@ fn handle_search_event(home: &mut Home, event: &SearchEvent) -> AppResult<Option
     Ok(None)
 }
 
+/// Opens `url` in the browser, or shows it in the status bar when `open_links_in_browser` is off.
+fn open_link(home: &Home, url: Url) -> AppResult<()> {
+    if home.config.config.open_links_in_browser {
+        open::that(url.to_string())?;
+    } else {
+        home.action_tx
+            .send(Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Info,
+                url.to_string(),
+            )))?;
+    }
+    Ok(())
+}
+
+/// Runs `command`, unless it's a project add with `preview_add` on, or a remove with
+/// `preview_remove` on: then its `--dry-run` runs first, off the UI thread, and the report comes
+/// back as [`HomeCommand::PreviewDryRun`].
+fn preview_or_run(home: &Home, command: CargoCommand) -> AppResult<Option<Action>> {
+    match &command {
+        CargoCommand::Add { .. } if home.config.config.preview_add => preview_add(home, command),
+        CargoCommand::Remove { .. } if home.config.config.preview_remove => {
+            preview_remove(home, command)
+        }
+        _ => Ok(Some(Action::Cargo(command))),
+    }
+}
+
+fn preview_add(home: &Home, command: CargoCommand) -> AppResult<Option<Action>> {
+    let CargoCommand::Add {
+        name,
+        version,
+        features,
+        no_default_features,
+        optional,
+        package,
+        source,
+    } = &command
+    else {
+        return Ok(Some(Action::Cargo(command)));
+    };
+
+    home.action_tx
+        .send(Action::Status(StatusCommand::UpdateStatus(
+            StatusLevel::Progress,
+            format!("Previewing the add of {name} {}", source.describe(version)),
+        )))?;
+
+    let (name, version, source, features, no_default_features, optional, package) = (
+        name.clone(),
+        version.clone(),
+        source.clone(),
+        features.clone(),
+        *no_default_features,
+        *optional,
+        package.clone(),
+    );
+    let registry = Registry::from_config(&home.config.config);
+    let tx = home.action_tx.clone();
+    tokio::spawn(async move {
+        let dry_run = tokio::task::spawn_blocking({
+            let name = name.clone();
+            move || {
+                cargo::add_dry_run(
+                    &name,
+                    Some(version),
+                    &source,
+                    &features,
+                    no_default_features,
+                    optional,
+                    package.as_deref(),
+                    &registry,
+                )
+            }
+        })
+        .await;
+
+        let action = match dry_run {
+            Ok(Ok(output)) => Action::Home(HomeCommand::PreviewDryRun {
+                command,
+                output,
+                failed: false,
+            }),
+            Ok(Err(err)) => Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Error,
+                format!("Couldn't preview adding {name}: {err:#}"),
+            )),
+            Err(err) => Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Error,
+                format!("Couldn't preview adding {name}: {err}"),
+            )),
+        };
+        tx.send(action).ok();
+    });
+
+    Ok(None)
+}
+
+/// Dry-runs the remove. A failed dry run is previewed too, with cargo's error, so it's clear what
+/// still needs the dependency before anything changes.
+fn preview_remove(home: &Home, command: CargoCommand) -> AppResult<Option<Action>> {
+    let CargoCommand::Remove { name, package } = &command else {
+        return Ok(Some(Action::Cargo(command)));
+    };
+
+    home.action_tx
+        .send(Action::Status(StatusCommand::UpdateStatus(
+            StatusLevel::Progress,
+            format!("Previewing the removal of {name}"),
+        )))?;
+
+    let (name, package) = (name.clone(), package.clone());
+    let tx = home.action_tx.clone();
+    tokio::spawn(async move {
+        let dry_run = tokio::task::spawn_blocking({
+            let name = name.clone();
+            move || cargo::remove_dry_run(&name, package.as_deref())
+        })
+        .await;
+
+        let action = match dry_run {
+            Ok(Ok(output)) => Action::Home(HomeCommand::PreviewDryRun {
+                command,
+                output,
+                failed: false,
+            }),
+            Ok(Err(err)) => match err.downcast_ref::<CargoError>() {
+                Some(CargoError::Failed { stderr, .. }) => {
+                    Action::Home(HomeCommand::PreviewDryRun {
+                        command,
+                        output: stderr.trim().to_string(),
+                        failed: true,
+                    })
+                }
+                None => Action::Status(StatusCommand::UpdateStatus(
+                    StatusLevel::Error,
+                    format!("Couldn't preview removing {name}: {err:#}"),
+                )),
+            },
+            Err(err) => Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Error,
+                format!("Couldn't preview removing {name}: {err}"),
+            )),
+        };
+        tx.send(action).ok();
+    });
+
+    Ok(None)
+}
+
+/// Opens the feature diff for the focused crate, or explains in the status bar why there is none.
+fn compare_features(home: &mut Home) -> AppResult<()> {
+    let Some(cr) = home.get_focused_crate() else {
+        return Ok(());
+    };
+
+    let problem = if cr.project_version.is_none() {
+        Some(format!("{} isn't a dependency of this project", cr.name))
+    } else if cr.is_metadata_loading() {
+        Some(format!("Still loading {}'s versions", cr.name))
+    } else {
+        None
+    };
+    let diff = cr.feature_diff(home.config.config.show_prereleases);
+    let message = match (problem, diff) {
+        (Some(problem), _) => problem,
+        (None, Some(diff)) => {
+            let view = FeatureDiffView::new(home.config.clone(), &cr.name, &diff);
+            home.overlay = Some(Overlay::FeatureDiff(view));
+            return Ok(());
+        }
+        (None, None) => format!("Couldn't find {}'s versions to compare", cr.name),
+    };
+    home.action_tx
+        .send(Action::Status(StatusCommand::UpdateStatusWithDuration(
+            StatusLevel::Info,
+            StatusDuration::Short,
+            message,
+        )))?;
+    Ok(())
+}
+
+/// Lists the installed binaries and starts checking them for updates, unless a check is running.
+async fn check_binaries(home: &mut Home) -> AppResult<()> {
+    let binaries = home.cargo_env.read().await.installed_binaries.clone();
+    let message = if home.checking_binaries {
+        "Already checking installed binaries for updates".to_string()
+    } else if binaries.is_empty() {
+        "No installed binaries to check".to_string()
+    } else {
+        home.checking_binaries = true;
+        home.crate_search_manager.check_binaries(binaries);
+        home.action_tx
+            .send(Action::Search(SearchCommand::ListAll(Scope::Installed)))?;
+        return Ok(());
+    };
+    home.action_tx
+        .send(Action::Status(StatusCommand::UpdateStatus(
+            StatusLevel::Info,
+            message,
+        )))?;
+    Ok(())
+}
+
+/// Lists the focused crate's dependencies, or starts loading them (once its version is known) to be
+/// listed when they land.
+fn show_dependencies(home: &mut Home) -> AppResult<()> {
+    let Some(cr) = home.get_focused_crate() else {
+        return Ok(());
+    };
+
+    if let Some(dependencies) = &cr.dependencies {
+        let list = DependencyList::new(home.config.clone(), &cr.name, &cr.version, dependencies);
+        home.overlay = Some(Overlay::Dependencies(list));
+        return Ok(());
+    }
+
+    let (level, message) = if cr.is_metadata_loading() {
+        (StatusLevel::Info, format!("Still loading {}", cr.name))
+    } else if cr.version.is_empty() {
+        (
+            StatusLevel::Info,
+            format!("{} has no published version", cr.name),
+        )
+    } else {
+        home.crate_search_manager
+            .load_dependencies(&cr.name, &cr.version);
+        let name = cr.name.clone();
+        let message = format!("Loading {name}'s dependencies");
+        home.awaiting_dependencies = Some(name);
+        (StatusLevel::Progress, message)
+    };
+    home.action_tx
+        .send(Action::Status(StatusCommand::UpdateStatus(level, message)))?;
+    Ok(())
+}
+
+/// Changes the sort, re-running the current search with it.
+fn sort_by(home: &mut Home, sort: Sort) -> AppResult<()> {
+    home.sort = sort;
+    if home.search_results.is_some() {
+        home.action_tx.send(Action::Search(SearchCommand::Run {
+            term: home.results_term(),
+            page: 1,
+            hide_help: false,
+            status: Some(format!("Sorting by: {}", home.sort)),
+        }))?;
+    }
+    Ok(())
+}
+
+/// Changes the scope, re-running the current search in it.
+async fn scope_to(home: &mut Home, scope: Scope) -> AppResult<()> {
+    home.scope = scope.clone();
+    // Explain up front why a project-scoped search will come back without local results.
+    let missing_project =
+        scope.includes(Scope::Project) && home.cargo_env.read().await.project.is_none();
+
+    if home.search_results.is_some() {
+        let status = if missing_project {
+            format!("Scoped to: {scope} · {NO_PROJECT_FOUND}")
+        } else {
+            format!("Scoped to: {scope}")
+        };
+        home.action_tx.send(Action::Search(SearchCommand::Run {
+            term: home.results_term(),
+            page: 1,
+            hide_help: false,
+            status: Some(status),
+        }))?;
+    } else if missing_project {
+        home.action_tx
+            .send(Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Info,
+                NO_PROJECT_FOUND.to_string(),
+            )))?;
+    }
+    Ok(())
+}
+
+/// Starts a search with `options`, showing `status` while it runs.
+fn start_search(home: &mut Home, options: SearchOptions, status: String) -> AppResult<()> {
+    home.action_tx
+        .send(Action::Status(StatusCommand::UpdateStatus(
+            StatusLevel::Progress,
+            status,
+        )))?;
+
+    home.is_searching = true;
+    home.failed_search = None;
+    home.missing_exact_match = None;
+    home.last_search = Some(options.clone());
+    home.crate_search_manager
+        .search(options, Arc::clone(&home.cargo_env));
+    Ok(())
+}
+
+/// The term of a first-page online search that found crates but none named exactly that, to point
+/// out it may not exist. crates.io treats `-` and `_` in names alike.
+fn missing_exact_match(options: &SearchOptions, results: &SearchResults) -> Option<String> {
+    let term = options.term.as_deref()?.trim();
+    if term.is_empty()
+        || exact_name(term).is_some()
+        || !options.scope.includes(Scope::Online)
+        || options.page.unwrap_or(1) != 1
+        || results.crates.is_empty()
+    {
+        return None;
+    }
+    let normalize = |name: &str| name.to_lowercase().replace('-', "_");
+    let wanted = normalize(term);
+    let found = results
+        .crates
+        .iter()
+        .any(|cr| cr.exact_match || normalize(&cr.name) == wanted);
+    (!found).then(|| term.to_string())
+}
+
+/// Re-runs the current search on the page being viewed, with the current sort and scope.
+fn finish_refresh(home: &Home) -> AppResult<()> {
+    match &home.search_results {
+        Some(results) => home.action_tx.send(Action::Search(SearchCommand::Run {
+            term: home.results_term(),
+            page: results.current_page().max(1),
+            hide_help: false,
+            status: Some("Refreshing".into()),
+        }))?,
+        None => home
+            .action_tx
+            .send(Action::Status(StatusCommand::ResetStatus))?,
+    }
+    Ok(())
+}
+
+/// Locates the focused crate's source (a workspace member or a resolved dependency) and opens it in
+/// the editor. Resolving the full dependency graph can take a moment, so it runs off the UI thread
+/// and reports back with [`Action::OpenInEditor`] or an error status.
+async fn open_in_editor(home: &Home) -> AppResult<()> {
+    let Some(name) = home.get_focused_crate().map(|cr| cr.name.clone()) else {
+        return Ok(());
+    };
+
+    let Some(manifest_path) = project_manifest_for(home, &name).await else {
+        home.action_tx
+            .send(Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Error,
+                format!("{name} isn't part of this project, so its source isn't available"),
+            )))?;
+        return Ok(());
+    };
+
+    home.action_tx
+        .send(Action::Status(StatusCommand::UpdateStatus(
+            StatusLevel::Progress,
+            format!("Locating the source of {name}"),
+        )))?;
+
+    let tx = home.action_tx.clone();
+    tokio::spawn(async move {
+        let located = tokio::task::spawn_blocking({
+            let name = name.clone();
+            move || cargo::locate_package_dir(&manifest_path, &name)
+        })
+        .await;
+
+        let action = match located {
+            Ok(Ok(Some(dir))) => {
+                tx.send(Action::Status(StatusCommand::ResetStatus)).ok();
+                Action::OpenInEditor(dir)
+            }
+            Ok(Ok(None)) => Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Error,
+                format!("Couldn't locate the source of {name}"),
+            )),
+            Ok(Err(err)) => Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Error,
+                format!("Couldn't locate the source of {name}: {err:#}"),
+            )),
+            Err(err) => Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Error,
+                format!("Couldn't locate the source of {name}: {err}"),
+            )),
+        };
+        tx.send(action).ok();
+    });
+
+    Ok(())
+}
+
+/// Locates the selected project dependency's source off the UI thread and reads its README, which
+/// [`Action::RenderReadme`] then renders in the terminal. A crate that isn't part of the project,
+/// or whose README can't be found, has its repository opened instead.
+async fn render_readme(home: &Home) -> AppResult<()> {
+    let Some((name, repository)) = home
+        .search_results
+        .as_ref()
+        .and_then(|results| results.selected())
+        .map(|cr| (cr.name.clone(), cr.repository.clone()))
+    else {
+        return Ok(());
+    };
+
+    let Some(manifest_path) = project_manifest_for(home, &name).await else {
+        for action in readme_fallback(
+            &name,
+            repository,
+            "it isn't part of this project, so its source isn't available",
+        ) {
+            home.action_tx.send(action)?;
+        }
+        return Ok(());
+    };
+
+    home.action_tx
+        .send(Action::Status(StatusCommand::UpdateStatus(
+            StatusLevel::Progress,
+            format!("Locating the README of {name}"),
+        )))?;
+
+    let tx = home.action_tx.clone();
+    tokio::spawn(async move {
+        let read = tokio::task::spawn_blocking({
+            let name = name.clone();
+            move || -> AppResult<Option<String>> {
+                let Some(dir) = cargo::locate_package_dir(&manifest_path, &name)? else {
+                    return Ok(None);
+                };
+                readme::find(&dir)
+                    .map(|path| std::fs::read_to_string(path).map_err(Into::into))
+                    .transpose()
+            }
+        })
+        .await;
+
+        let actions = match read {
+            Ok(Ok(Some(markdown))) => vec![
+                Action::Status(StatusCommand::ResetStatus),
+                Action::RenderReadme {
+                    name: name.clone(),
+                    markdown,
+                    repository,
+                },
+            ],
+            Ok(Ok(None)) => readme_fallback(&name, repository, "no README was found"),
+            Ok(Err(err)) => readme_fallback(&name, repository, &format!("{err:#}")),
+            Err(err) => readme_fallback(&name, repository, &err.to_string()),
+        };
+        for action in actions {
+            tx.send(action).ok();
+        }
+    });
+
+    Ok(())
+}
+
+/// What to do when `name`'s README can't be rendered for `reason`: open its repository if it has
+/// one, else just report why.
+pub fn readme_fallback(name: &str, repository: Option<String>, reason: &str) -> Vec<Action> {
+    match repository {
+        Some(repository) => vec![
+            Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Info,
+                format!("Couldn't render the README of {name} ({reason}), opening its repository"),
+            )),
+            Action::Home(HomeCommand::OpenLink(repository)),
+        ],
+        None => vec![Action::Status(StatusCommand::UpdateStatus(
+            StatusLevel::Error,
+            format!("Couldn't render the README of {name}: {reason}"),
+        ))],
+    }
+}
+
+/// The manifest of the current project, if `name` is one of its dependencies or workspace members.
+async fn project_manifest_for(home: &Home, name: &str) -> Option<PathBuf> {
+    let cargo_env = home.cargo_env.read().await;
+    cargo_env
+        .project
+        .as_ref()
+        .filter(|project| {
+            project.get_local_version(name).is_some()
+                || project.packages.iter().any(|package| package.name == name)
+        })
+        .map(|project| project.manifest_file_path.clone())
+}
+
+/// Copies the focused crate's `Cargo.toml` dependency line, reporting the outcome in the status bar.
+fn copy_dependency_snippet(home: &mut Home, with_features: bool) -> AppResult<()> {
+    let Some(cr) = home.get_focused_crate() else {
+        return Ok(());
+    };
+    let snippet = cr.dependency_snippet(with_features);
+    let success = if with_features && cr.features.is_none() {
+        format!("Copied {snippet} (features not loaded yet)")
+    } else {
+        format!("Copied {snippet}")
+    };
+    copy_to_clipboard(home, &snippet, success)
+}
+
+/// Copies `text`, showing `success` in the status bar, or the error when the clipboard failed.
+fn copy_to_clipboard(home: &mut Home, text: &str, success: String) -> AppResult<()> {
+    let (level, message) = match home.clipboard.copy(text) {
+        Ok(()) => (StatusLevel::Success, success),
+        Err(err) => (StatusLevel::Error, format!("{err:#}")),
+    };
+    home.action_tx
+        .send(Action::Status(StatusCommand::UpdateStatusWithDuration(
+            level,
+            StatusDuration::Short,
+            message,
+        )))?;
+    Ok(())
+}
+
 /// Acts on a [`FeatureStep`].
 fn apply_feature_step(home: &mut Home, step: FeatureStep) -> AppResult<()> {
     match step {
@@ -377,16 +1378,14 @@ fn apply_feature_step(home: &mut Home, step: FeatureStep) -> AppResult<()> {
             home.overlay = Some(Overlay::Features(*selector));
         }
         FeatureStep::Run(action) => {
-            home.action_tx.send(action)?;
+            home.action_tx.send(route_through_package_step(action))?;
         }
         FeatureStep::AwaitMetadata { intent, name } => {
             home.pending_cargo_request = Some(PendingCargoRequest {
                 intent,
                 crate_name: name.clone(),
             });
-            home.crate_search_manager
-                .start_metadata_load(&name, false)
-                .ok();
+            home.start_hydrating(&name, false);
             home.action_tx
                 .send(Action::Status(StatusCommand::UpdateStatus(
                     StatusLevel::Progress,
@@ -396,3 +1395,502 @@ fn apply_feature_step(home: &mut Home, step: FeatureStep) -> AppResult<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cargo::{CargoEnv, IndexedCrate, InstalledBinary, ManifestSearch};
+    use crate::components::home::command_palette;
+    use crate::config::Config;
+    use crate::search::{Crate, InitialSearch, OutdatedBinary};
+    use pretty_assertions::assert_eq;
+    use std::collections::VecDeque;
+    use std::time::Duration;
+    use tokio::sync::RwLock;
+    use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+    /// A [`Home`] driven the way the app drives it, without a terminal.
+    struct Harness {
+        home: Home,
+        rx: UnboundedReceiver<Action>,
+    }
+
+    impl Harness {
+        /// A home whose installed binaries are `binaries`, searching only those so no search
+        /// goes online.
+        fn new(binaries: &[(&str, &str)]) -> Self {
+            let mut env = CargoEnv::new(None, None, ManifestSearch::default());
+            env.binary_index = binaries
+                .iter()
+                .map(|(name, version)| IndexedCrate {
+                    name: name.to_lowercase(),
+                    result: Crate::from_binary(&InstalledBinary {
+                        name: name.to_string(),
+                        version: version.to_string(),
+                    }),
+                })
+                .collect();
+
+            let (tx, rx) = mpsc::unbounded_channel();
+            let mut home = Home::new(
+                &Config::default(),
+                InitialSearch::default(),
+                Arc::new(RwLock::new(env)),
+                tx,
+            )
+            .unwrap();
+            home.scope = Scope::Installed;
+            Self { home, rx }
+        }
+
+        /// Handles `action` and everything it leads to, whether returned or sent through the
+        /// action channel, until nothing is left. Work spawned off the loop (like a search) isn't
+        /// waited for; see [`Harness::finish_search`].
+        async fn dispatch(&mut self, action: Action) {
+            let mut queue = VecDeque::from([action]);
+            while let Some(action) = queue.pop_front() {
+                if let Some(next) = handle_action(&mut self.home, &action).await.unwrap() {
+                    queue.push_back(next);
+                }
+                while let Ok(sent) = self.rx.try_recv() {
+                    queue.push_back(sent);
+                }
+            }
+        }
+
+        /// Waits for the search in flight to report back, then handles its outcome.
+        async fn finish_search(&mut self) {
+            loop {
+                let action = tokio::time::timeout(Duration::from_secs(5), self.rx.recv())
+                    .await
+                    .expect("the search to finish")
+                    .expect("the action channel to stay open");
+                let done = matches!(
+                    action,
+                    Action::SearchEvent(SearchEvent::Completed { .. } | SearchEvent::Failed { .. })
+                );
+                self.dispatch(action).await;
+                if done {
+                    return;
+                }
+            }
+        }
+
+        async fn search(&mut self, term: &str) {
+            self.dispatch(Action::Search(SearchCommand::Run {
+                term: term.to_string(),
+                page: 1,
+                hide_help: true,
+                status: None,
+            }))
+            .await;
+            self.finish_search().await;
+        }
+
+        fn result_names(&self) -> Option<Vec<&str>> {
+            self.home
+                .search_results
+                .as_ref()
+                .map(|results| results.crates.iter().map(|cr| cr.name.as_str()).collect())
+        }
+    }
+
+    fn online_search(term: &str) -> SearchOptions {
+        SearchOptions {
+            term: Some(term.into()),
+            page: Some(1),
+            scope: Scope::All,
+            ..Default::default()
+        }
+    }
+
+    fn results_named(names: &[&str]) -> SearchResults {
+        let mut results = SearchResults::new(1, DEFAULT_PER_PAGE);
+        results.crates = names
+            .iter()
+            .map(|name| Crate {
+                id: name.to_string(),
+                name: name.to_string(),
+                ..Default::default()
+            })
+            .collect();
+        results
+    }
+
+    #[test]
+    fn missing_exact_match_names_the_term_when_no_crate_matches_it() {
+        let results = results_named(&["serde_json", "serde_yaml"]);
+        assert_eq!(
+            missing_exact_match(&online_search("serde-jsn"), &results).as_deref(),
+            Some("serde-jsn")
+        );
+        assert_eq!(
+            missing_exact_match(&online_search("Serde-Json"), &results),
+            None
+        );
+    }
+
+    #[test]
+    fn missing_exact_match_is_only_for_first_page_online_searches() {
+        let results = results_named(&["ripgrep"]);
+        let local = SearchOptions {
+            scope: Scope::Installed,
+            ..online_search("rip")
+        };
+        let second_page = SearchOptions {
+            page: Some(2),
+            ..online_search("rip")
+        };
+        assert_eq!(missing_exact_match(&local, &results), None);
+        assert_eq!(missing_exact_match(&second_page, &results), None);
+        assert_eq!(
+            missing_exact_match(&online_search("rip"), &results_named(&[])),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn focus_next_skips_the_hidden_help() {
+        let mut harness = Harness::new(&[]);
+        harness.home.show_help = false;
+        harness.home.focused = Focusable::LibRsButton;
+
+        harness.dispatch(Action::Home(HomeCommand::FocusNext)).await;
+        assert_eq!(harness.home.focused, Focusable::Search);
+
+        harness
+            .dispatch(Action::Home(HomeCommand::FocusPrevious))
+            .await;
+        assert_eq!(harness.home.focused, Focusable::LibRsButton);
+    }
+
+    #[tokio::test]
+    async fn focus_next_goes_through_the_help_while_shown() {
+        let mut harness = Harness::new(&[]);
+
+        harness.dispatch(Action::Home(HomeCommand::FocusNext)).await;
+        assert_eq!(harness.home.focused, Focusable::Help);
+
+        harness.dispatch(Action::Home(HomeCommand::FocusNext)).await;
+        assert_eq!(harness.home.focused, Focusable::Search);
+    }
+
+    #[tokio::test]
+    async fn vim_gg_goes_to_the_first_result_only_as_a_pair() {
+        use crate::components::home::key_handler::handle_key;
+        use crossterm::event::{KeyCode, KeyEvent};
+
+        let mut harness = Harness::new(&[("ripgrep", "14.1.0"), ("ripgrep_all", "0.10.6")]);
+        harness.home.config.config.vim_keys = true;
+        harness.search("rip").await;
+        harness
+            .dispatch(Action::Home(HomeCommand::Focus(Focusable::Results)))
+            .await;
+        let press =
+            |home: &mut Home, c| handle_key(home, KeyEvent::from(KeyCode::Char(c))).unwrap();
+
+        assert!(press(&mut harness.home, 'g').is_none());
+        assert!(matches!(
+            press(&mut harness.home, 'g'),
+            Some(Action::Search(SearchCommand::SelectFirst))
+        ));
+
+        // Another key in between starts over.
+        assert!(press(&mut harness.home, 'g').is_none());
+        assert!(matches!(
+            press(&mut harness.home, 'j'),
+            Some(Action::Search(SearchCommand::SelectNext))
+        ));
+        assert!(press(&mut harness.home, 'g').is_none());
+    }
+
+    #[tokio::test]
+    async fn list_wrap_wraps_the_selection_around_the_ends() {
+        use crate::components::home::key_handler::handle_key;
+        use crossterm::event::{KeyCode, KeyEvent};
+
+        let mut harness = Harness::new(&[("ripgrep", "14.1.0"), ("ripgrep_all", "0.10.6")]);
+        harness.search("rip").await;
+        harness
+            .dispatch(Action::Home(HomeCommand::Focus(Focusable::Results)))
+            .await;
+        let press = |home: &mut Home, code| handle_key(home, KeyEvent::from(code)).unwrap();
+
+        assert!(matches!(
+            press(&mut harness.home, KeyCode::Up),
+            Some(Action::Home(HomeCommand::Focus(Focusable::Search)))
+        ));
+
+        harness.home.config.config.list_wrap = true;
+        assert!(matches!(
+            press(&mut harness.home, KeyCode::Up),
+            Some(Action::Search(SearchCommand::SelectLast))
+        ));
+        harness
+            .dispatch(Action::Search(SearchCommand::SelectLast))
+            .await;
+        assert!(matches!(
+            press(&mut harness.home, KeyCode::Down),
+            Some(Action::Search(SearchCommand::SelectFirst))
+        ));
+    }
+
+    #[tokio::test]
+    async fn update_binaries_confirms_the_outdated_ones() {
+        let mut harness = Harness::new(&[("ripgrep", "13.0.0")]);
+        harness
+            .dispatch(Action::SearchEvent(SearchEvent::BinariesChecked {
+                outdated: vec![OutdatedBinary {
+                    name: "ripgrep".into(),
+                    installed: "13.0.0".into(),
+                    latest: "14.1.1".into(),
+                }],
+                unchecked: 0,
+            }))
+            .await;
+        assert_eq!(harness.home.outdated_binaries.len(), 1);
+
+        harness
+            .dispatch(Action::Home(HomeCommand::UpdateBinaries))
+            .await;
+        let Some(Overlay::Confirm(_, Action::Cargo(CargoCommand::UpdateBinaries(names)))) =
+            &harness.home.overlay
+        else {
+            panic!("expected a confirmation to update");
+        };
+        assert_eq!(names, &vec!["ripgrep".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn inverse_tree_is_only_for_project_dependencies() {
+        let mut harness = Harness::new(&[("ripgrep", "14.1.0")]);
+        harness.search("rip").await;
+        harness
+            .dispatch(Action::Home(HomeCommand::Focus(Focusable::Results)))
+            .await;
+        let show = Action::Home(HomeCommand::ShowInverseTree);
+
+        handle_action(&mut harness.home, &show).await.unwrap();
+        assert!(matches!(
+            harness.rx.try_recv(),
+            Ok(Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Info,
+                _
+            )))
+        ));
+
+        harness.home.search_results.as_mut().unwrap().crates[0].project_version =
+            Some("14.1.0".into());
+        handle_action(&mut harness.home, &show).await.unwrap();
+        assert!(matches!(
+            harness.rx.try_recv(),
+            Ok(Action::Cargo(CargoCommand::Tree(name))) if name == "ripgrep"
+        ));
+    }
+
+    /// Presses Enter on a preview of `command`, with both previews on, and follows what that leads
+    /// to. Returns the cargo command that ends up being run, if any.
+    async fn confirm_preview(command: CargoCommand) -> Option<CargoCommand> {
+        use crate::components::home::key_handler::handle_key;
+        use crossterm::event::{KeyCode, KeyEvent};
+
+        let mut harness = Harness::new(&[]);
+        harness.home.config.config.preview_add = true;
+        harness.home.config.config.preview_remove = true;
+        harness.home.overlay = Some(Overlay::DryRunPreview(DryRunPreview::new(
+            harness.home.config.clone(),
+            command,
+            "",
+            false,
+        )));
+
+        let mut next = handle_key(&mut harness.home, KeyEvent::from(KeyCode::Enter)).unwrap();
+        while let Some(action) = next {
+            if let Action::Cargo(command) = action {
+                return Some(command);
+            }
+            next = handle_action(&mut harness.home, &action).await.unwrap();
+        }
+        None
+    }
+
+    #[tokio::test]
+    async fn confirming_an_add_preview_runs_the_add() {
+        let add = CargoCommand::Add {
+            name: "serde".into(),
+            version: "1.0.228".into(),
+            features: Vec::new(),
+            no_default_features: false,
+            optional: false,
+            package: None,
+            source: cargo::DependencySource::Registry,
+        };
+        assert_eq!(confirm_preview(add.clone()).await, Some(add));
+    }
+
+    #[tokio::test]
+    async fn confirming_a_remove_preview_runs_the_remove() {
+        let remove = CargoCommand::Remove {
+            name: "serde".into(),
+            package: None,
+        };
+        assert_eq!(confirm_preview(remove.clone()).await, Some(remove));
+    }
+
+    #[tokio::test]
+    async fn palette_remove_asks_for_confirmation_first() {
+        let mut harness = Harness::new(&[]);
+        let action = command_palette::parse("rm serde").unwrap();
+
+        let next = handle_action(&mut harness.home, &action).await.unwrap();
+
+        assert!(next.is_none());
+        assert!(harness.rx.try_recv().is_err());
+        let Some(Overlay::Confirm(_, Action::Cargo(CargoCommand::Remove { name, .. }))) =
+            &harness.home.overlay
+        else {
+            panic!("expected the remove to wait on a confirmation");
+        };
+        assert_eq!(name, "serde");
+    }
+
+    #[tokio::test]
+    async fn readme_of_a_crate_outside_the_project_opens_its_repository() {
+        let mut harness = Harness::new(&[("ripgrep", "14.1.0")]);
+        harness.search("rip").await;
+        let repository = "https://github.com/BurntSushi/ripgrep".to_string();
+        harness.home.search_results.as_mut().unwrap().crates[0].repository =
+            Some(repository.clone());
+
+        let action = command_palette::parse("readme").unwrap();
+        let next = handle_action(&mut harness.home, &action).await.unwrap();
+
+        assert!(next.is_none());
+        assert!(matches!(harness.rx.try_recv(), Ok(Action::Status(_))));
+        assert!(matches!(
+            harness.rx.try_recv(),
+            Ok(Action::Home(HomeCommand::OpenLink(link))) if link == repository
+        ));
+    }
+
+    #[tokio::test]
+    async fn ctrl_h_in_an_open_prompt_deletes_instead_of_toggling_help() {
+        use crate::app::Mode;
+        use crate::components::Component;
+        use crate::components::home::key_handler::handle_key;
+        use crate::components::ux::Prompt;
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let mut harness = Harness::new(&[]);
+        let config = Config::new().unwrap();
+        harness.home.overlay = Some(Overlay::Owner(Prompt::new(
+            config.clone(),
+            "Owner".into(),
+            "dtolnay",
+        )));
+        let ctrl_h = KeyEvent::new(KeyCode::Char('h'), KeyModifiers::CONTROL);
+        let show_help = harness.home.show_help;
+
+        // The app leaves Home's keymap out, so the key reaches the prompt.
+        assert!(harness.home.wants_raw_keys());
+        assert!(
+            config
+                .keybindings
+                .action_for(Mode::Home, &[ctrl_h], harness.home.wants_raw_keys())
+                .is_none()
+        );
+        assert!(handle_key(&mut harness.home, ctrl_h).unwrap().is_none());
+        assert_eq!(harness.home.show_help, show_help);
+        assert!(matches!(
+            handle_key(&mut harness.home, KeyEvent::from(KeyCode::Enter)).unwrap(),
+            Some(Action::Search(SearchCommand::Owner(Some(owner)))) if owner == "dtolna"
+        ));
+    }
+
+    #[tokio::test]
+    async fn arrow_keys_select_a_details_line_then_step_into_the_buttons() {
+        use crate::components::home::key_handler::handle_key;
+        use crossterm::event::{KeyCode, KeyEvent};
+
+        let mut harness = Harness::new(&[("ripgrep", "14.1.0")]);
+        harness.search("rip").await;
+        harness
+            .dispatch(Action::Home(HomeCommand::Focus(Focusable::Results)))
+            .await;
+        harness.dispatch(Action::Home(HomeCommand::FocusNext)).await;
+        assert_eq!(harness.home.focused, Focusable::Details);
+
+        let mut press = |code| handle_key(&mut harness.home, KeyEvent::from(code)).unwrap();
+        for code in [KeyCode::Up, KeyCode::Down, KeyCode::Down] {
+            assert!(press(code).is_none());
+        }
+        assert!(matches!(
+            press(KeyCode::Enter),
+            Some(Action::Home(HomeCommand::CopyDetailField))
+        ));
+        press(KeyCode::End);
+        let Some(Action::Home(HomeCommand::Focus(button))) = press(KeyCode::Down) else {
+            panic!("Down on the last line should focus a button");
+        };
+        assert_eq!(
+            harness.home.selected_field,
+            harness.home.detail_fields().len() - 1
+        );
+        assert!(harness.home.should_show_button(&button));
+    }
+
+    #[tokio::test]
+    async fn searching_fills_the_results_and_hides_the_help() {
+        let mut harness = Harness::new(&[("ripgrep", "14.1.0"), ("bat", "0.24.0")]);
+
+        harness.search("rip").await;
+
+        assert_eq!(harness.result_names(), Some(vec!["ripgrep"]));
+        assert!(!harness.home.is_searching);
+        assert!(!harness.home.show_help);
+        assert_eq!(
+            harness
+                .home
+                .search_results
+                .as_ref()
+                .and_then(|results| results.selected_index()),
+            Some(0)
+        );
+
+        harness.dispatch(Action::Home(HomeCommand::FocusNext)).await;
+        assert_eq!(harness.home.focused, Focusable::Results);
+    }
+
+    #[test]
+    fn failing_to_render_a_readme_falls_back_to_the_repository() {
+        let repository = "https://github.com/serde-rs/serde".to_string();
+
+        let actions = readme_fallback("serde", Some(repository.clone()), "glow failed");
+
+        assert!(matches!(
+            actions.as_slice(),
+            [
+                Action::Status(StatusCommand::UpdateStatus(StatusLevel::Info, _)),
+                Action::Home(HomeCommand::OpenLink(link)),
+            ] if *link == repository
+        ));
+        assert!(matches!(
+            readme_fallback("serde", None, "glow failed").as_slice(),
+            [Action::Status(StatusCommand::UpdateStatus(StatusLevel::Error, message))]
+                if message == "Couldn't render the README of serde: glow failed"
+        ));
+    }
+
+    #[tokio::test]
+    async fn clear_drops_the_results_and_the_term() {
+        let mut harness = Harness::new(&[("ripgrep", "14.1.0")]);
+        harness.home.input = harness.home.input.clone().with_value("rip".into());
+        harness.search("rip").await;
+
+        harness.dispatch(Action::Search(SearchCommand::Clear)).await;
+
+        assert_eq!(harness.result_names(), None);
+        assert_eq!(harness.home.input.value(), "");
+        assert!(!harness.home.is_searching);
+    }
+}
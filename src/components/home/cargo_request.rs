@@ -1,8 +1,10 @@
 //! The add/install request lifecycle: deciding whether a crate's features must be chosen before
-//! running cargo, and deferring that decision until feature metadata has loaded.
+//! running cargo, deferring that decision until feature metadata has loaded, and picking the
+//! workspace member a project add/remove targets.
 
 use crate::action::Action;
-use crate::cargo::CargoCommand;
+use crate::cargo::{CargoCommand, DependencySource, Project};
+use crate::components::home::HomeCommand;
 use crate::components::home::feature_selector::FeatureSelector;
 use crate::config::Config;
 use crate::search::Crate;
@@ -11,6 +13,8 @@ use crate::search::Crate;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CargoIntent {
     Add,
+    /// Add as an optional dependency, to back a feature.
+    AddOptional,
     Install,
 }
 
@@ -19,6 +23,7 @@ impl CargoIntent {
     pub fn verb(self) -> &'static str {
         match self {
             CargoIntent::Add => "Add",
+            CargoIntent::AddOptional => "Add optional",
             CargoIntent::Install => "Install",
         }
     }
@@ -32,11 +37,14 @@ impl CargoIntent {
         no_default_features: bool,
     ) -> Action {
         let command = match self {
-            CargoIntent::Add => CargoCommand::Add {
+            CargoIntent::Add | CargoIntent::AddOptional => CargoCommand::Add {
                 name,
                 version,
                 features,
                 no_default_features,
+                optional: self == CargoIntent::AddOptional,
+                package: None,
+                source: DependencySource::Registry,
             },
             CargoIntent::Install => CargoCommand::Install {
                 name,
@@ -102,9 +110,72 @@ pub fn decide_feature_step(
     ))))
 }
 
+/// Which workspace member a project add/remove targets.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PackageStep {
+    /// Run the command as is, with its target filled in when only one member qualified.
+    Run(CargoCommand),
+    /// Several members qualify, ask the user which one.
+    Pick(Vec<String>),
+}
+
+/// Sends a project add/remove without a target through [`HomeCommand::ChoosePackage`] first, so
+/// the workspace member can be picked. Anything else passes through unchanged.
+pub fn route_through_package_step(action: Action) -> Action {
+    match action {
+        Action::Cargo(
+            command @ (CargoCommand::Add { package: None, .. }
+            | CargoCommand::Remove { package: None, .. }),
+        ) => Action::Home(HomeCommand::ChoosePackage(command)),
+        action => action,
+    }
+}
+
+/// Decides the [`PackageStep`] for `command`. Single-package projects never prompt and keep
+/// cargo's default target. In a workspace, an add may go to any member while a remove is offered
+/// only the members that depend on the crate.
+pub fn decide_package_step(command: CargoCommand, project: Option<&Project>) -> PackageStep {
+    let Some(project) = project.filter(|project| project.packages.len() > 1) else {
+        return PackageStep::Run(command);
+    };
+
+    let candidates: Vec<String> = match &command {
+        CargoCommand::Add { package: None, .. } => project
+            .packages
+            .iter()
+            .map(|package| package.name.clone())
+            .collect(),
+        CargoCommand::Remove {
+            name,
+            package: None,
+        } => project
+            .packages
+            .iter()
+            .filter(|package| package.dependencies.iter().any(|dep| &dep.name == name))
+            .map(|package| package.name.clone())
+            .collect(),
+        _ => return PackageStep::Run(command),
+    };
+
+    match candidates.as_slice() {
+        [] => PackageStep::Run(command),
+        [only] => {
+            let mut command = command;
+            if let CargoCommand::Add { package, .. } | CargoCommand::Remove { package, .. } =
+                &mut command
+            {
+                *package = Some(only.clone());
+            }
+            PackageStep::Run(command)
+        }
+        _ => PackageStep::Pick(candidates),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cargo::{Dependency, Package};
     use pretty_assertions::assert_eq;
 
     fn crate_with(features: Option<&[&str]>) -> Crate {
@@ -150,6 +221,94 @@ mod tests {
         }
     }
 
+    fn project(packages: &[(&str, &[&str])]) -> Project {
+        Project {
+            manifest_file_path: "Cargo.toml".into(),
+            packages: packages
+                .iter()
+                .map(|(name, deps)| Package {
+                    name: name.to_string(),
+                    version: None,
+                    description: None,
+                    manifest_path: format!("{name}/Cargo.toml").into(),
+                    dependencies: deps
+                        .iter()
+                        .map(|dep| Dependency {
+                            name: dep.to_string(),
+                            req: "^1".into(),
+                            kind: None,
+                            optional: false,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    fn remove(name: &str) -> CargoCommand {
+        CargoCommand::Remove {
+            name: name.into(),
+            package: None,
+        }
+    }
+
+    #[test]
+    fn single_package_projects_skip_the_package_prompt() {
+        let project = project(&[("app", &["serde"])]);
+        assert_eq!(
+            decide_package_step(remove("serde"), Some(&project)),
+            PackageStep::Run(remove("serde"))
+        );
+    }
+
+    #[test]
+    fn workspace_adds_offer_every_member() {
+        let project = project(&[("app", &[]), ("core", &[])]);
+        let add = CargoCommand::Add {
+            name: "serde".into(),
+            version: "1.0.0".into(),
+            features: Vec::new(),
+            no_default_features: false,
+            optional: false,
+            package: None,
+            source: DependencySource::Registry,
+        };
+        assert_eq!(
+            decide_package_step(add, Some(&project)),
+            PackageStep::Pick(vec!["app".into(), "core".into()])
+        );
+    }
+
+    #[test]
+    fn workspace_removes_target_the_members_that_depend_on_the_crate() {
+        let project = project(&[("app", &["serde"]), ("core", &["tokio"])]);
+        assert_eq!(
+            decide_package_step(remove("serde"), Some(&project)),
+            PackageStep::Run(CargoCommand::Remove {
+                name: "serde".into(),
+                package: Some("app".into()),
+            })
+        );
+
+        let project = self::project(&[("app", &["serde"]), ("core", &["serde"])]);
+        assert_eq!(
+            decide_package_step(remove("serde"), Some(&project)),
+            PackageStep::Pick(vec!["app".into(), "core".into()])
+        );
+    }
+
+    #[test]
+    fn untargeted_project_commands_are_routed_through_the_package_step() {
+        assert!(matches!(
+            route_through_package_step(Action::Cargo(remove("serde"))),
+            Action::Home(HomeCommand::ChoosePackage(_))
+        ));
+        assert!(matches!(
+            route_through_package_step(Action::Cargo(CargoCommand::Uninstall("ripgrep".into()))),
+            Action::Cargo(CargoCommand::Uninstall(_))
+        ));
+    }
+
     #[test]
     fn known_features_open_the_picker() {
         let cr = crate_with(Some(&["derive", "std"]));
@@ -0,0 +1,266 @@
+//! The command palette: a prompt opened with `:` that reads typed commands such as `add serde`,
+//! `scope project` or `open docs` and turns them into the same actions the keys send.
+
+use strum::IntoEnumIterator;
+
+use crate::action::Action;
+use crate::cargo::{CargoCommand, DependencySource};
+use crate::components::home::HomeCommand;
+use crate::components::home::cargo_request::CargoIntent;
+use crate::components::home::staging::DEFAULT_EXPORT_PATH;
+use crate::search::{RequiredLink, Scope, SearchCommand, Sort};
+
+/// The commands the palette understands, listed when one isn't recognized.
+const COMMANDS: &str = "add, install, remove, uninstall, search, scope, sort, owner, keyword, filter, open, deps, dev-deps, readme, share, export, markdown, popular, outdated, refresh, log, help, quit";
+
+/// Reads a palette command into the action it stands for, or a message saying what's wrong with
+/// it. `add` and `install` without a name work on the focused crate, picking features as usual.
+pub fn parse(line: &str) -> Result<Action, String> {
+    let line = line.trim();
+    let (command, arg) = line
+        .split_once(char::is_whitespace)
+        .map(|(command, arg)| (command, arg.trim()))
+        .unwrap_or((line, ""));
+
+    let action = match (command.to_lowercase().as_str(), arg) {
+        ("add", arg) => {
+            let (optional, spec) = match arg.strip_prefix("--optional") {
+                Some(spec) if spec.is_empty() || spec.starts_with(char::is_whitespace) => {
+                    (true, spec.trim())
+                }
+                _ => (false, arg),
+            };
+            if spec.is_empty() {
+                let intent = if optional {
+                    CargoIntent::AddOptional
+                } else {
+                    CargoIntent::Add
+                };
+                return Ok(Action::Home(HomeCommand::BeginCargoRequest(intent)));
+            }
+            let (name, version) = split_spec(spec)?;
+            Action::Cargo(CargoCommand::Add {
+                name,
+                version,
+                features: Vec::new(),
+                no_default_features: false,
+                optional,
+                package: None,
+                source: DependencySource::Registry,
+            })
+        }
+        ("install", "") => Action::Home(HomeCommand::BeginCargoRequest(CargoIntent::Install)),
+        ("install", spec) => {
+            let (name, version) = split_spec(spec)?;
+            Action::Cargo(CargoCommand::Install {
+                name,
+                version,
+                features: Vec::new(),
+                no_default_features: false,
+            })
+        }
+        // Confirmed first, like the `r` and `u` keys.
+        ("remove" | "rm", name) if !name.is_empty() => {
+            Action::Home(HomeCommand::ConfirmCargo(CargoCommand::Remove {
+                name: name.to_string(),
+                package: None,
+            }))
+        }
+        ("uninstall", name) if !name.is_empty() => Action::Home(HomeCommand::ConfirmCargo(
+            CargoCommand::Uninstall(name.to_string()),
+        )),
+        ("search" | "s", term) => Action::Search(SearchCommand::Run {
+            term: term.to_string(),
+            page: 1,
+            hide_help: true,
+            status: None,
+        }),
+        ("scope", name) => Action::Search(SearchCommand::Scope(pick::<Scope>("scope", name)?)),
+        ("sort", name) => Action::Search(SearchCommand::SortBy(pick::<Sort>("sort", name)?)),
+        ("owner", owner) => Action::Search(SearchCommand::Owner(
+            Some(owner.to_string()).filter(|o| !o.is_empty()),
+        )),
+        ("markdown" | "md", path) => Action::Home(HomeCommand::ExportMarkdown(
+            Some(path.to_string()).filter(|p| !p.is_empty()),
+        )),
+        ("dev-deps", "") => Action::Search(SearchCommand::ToggleNormalDepsOnly),
+        ("readme", "") => Action::Home(HomeCommand::RenderReadme),
+        ("share", "") => Action::Home(HomeCommand::CopySearchCommand),
+        ("outdated", "") => Action::Home(HomeCommand::CheckBinaries),
+        ("keyword", keyword) => Action::Search(SearchCommand::Keyword(
+            Some(keyword.to_lowercase()).filter(|k| !k.is_empty()),
+        )),
+        ("filter", link) => Action::Search(SearchCommand::ToggleLinkFilter(
+            match link.to_lowercase().as_str() {
+                "" | "none" | "off" => None,
+                "docs" => Some(RequiredLink::Docs),
+                "repo" | "repository" => Some(RequiredLink::Repo),
+                _ => return Err("Filter by what? Try docs, repo or none".into()),
+            },
+        )),
+        ("open", link) => Action::Home(match link.to_lowercase().as_str() {
+            "" => HomeCommand::OpenLinksMenu,
+            "docs" => HomeCommand::OpenDocs,
+            "repo" | "repository" | "readme" => HomeCommand::OpenReadme,
+            "crates.io" | "cratesio" => HomeCommand::OpenCratesIo,
+            "lib.rs" | "librs" => HomeCommand::OpenLibRs,
+            _ => return Err("Open what? Try docs, repo, crates.io or lib.rs".into()),
+        }),
+        ("export", path) => Action::Home(HomeCommand::ExportStaged(
+            Some(path)
+                .filter(|p| !p.is_empty())
+                .unwrap_or(DEFAULT_EXPORT_PATH)
+                .to_string(),
+        )),
+        ("deps" | "dependencies", "") => Action::Home(HomeCommand::ShowDependencies),
+        ("popular", "") => Action::Search(SearchCommand::ListPopular),
+        ("refresh", "") => Action::Search(SearchCommand::Refresh),
+        ("log", "") => Action::OpenLog,
+        ("help", "") => Action::Home(HomeCommand::ToggleHelp),
+        ("quit" | "q", "") => Action::Quit,
+        ("remove" | "rm" | "uninstall", _) => return Err(format!("{command} needs a crate name")),
+        ("", _) => return Err(format!("Commands: {COMMANDS}")),
+        _ => return Err(format!("Unknown command `{line}`. Commands: {COMMANDS}")),
+    };
+    Ok(action)
+}
+
+/// Splits `name@version`; the version is left empty (the latest) when not given.
+fn split_spec(spec: &str) -> Result<(String, String), String> {
+    let (name, version) = spec.split_once('@').unwrap_or((spec, ""));
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(format!("`{spec}` isn't a crate name"));
+    }
+    Ok((name.to_string(), version.trim().to_string()))
+}
+
+/// The variant of `T` whose name matches `name`, ignoring case, spaces, `-` and `_`, so both
+/// `recent-downloads` and `RecentDownloads` pick [`Sort::RecentDownloads`].
+fn pick<T: IntoEnumIterator + std::fmt::Display>(what: &str, name: &str) -> Result<T, String> {
+    let wanted = normalize(name);
+    let mut names = Vec::new();
+    for variant in T::iter() {
+        let variant_name = variant.to_string();
+        if normalize(&variant_name) == wanted {
+            return Ok(variant);
+        }
+        names.push(variant_name.to_lowercase());
+    }
+    Err(format!("Unknown {what} `{name}`. Try {}", names.join(", ")))
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn add_takes_an_optional_version() {
+        let Ok(Action::Cargo(CargoCommand::Add { name, version, .. })) = parse("add serde@1.0")
+        else {
+            panic!("expected an add");
+        };
+        assert_eq!((name.as_str(), version.as_str()), ("serde", "1.0"));
+
+        let Ok(Action::Cargo(CargoCommand::Add { version, .. })) = parse(" add  tokio ") else {
+            panic!("expected an add");
+        };
+        assert_eq!(version, "");
+    }
+
+    #[test]
+    fn add_can_be_optional() {
+        let Ok(Action::Cargo(CargoCommand::Add { name, optional, .. })) =
+            parse("add --optional serde")
+        else {
+            panic!("expected an add");
+        };
+        assert_eq!((name.as_str(), optional), ("serde", true));
+        assert!(matches!(
+            parse("add --optional"),
+            Ok(Action::Home(HomeCommand::BeginCargoRequest(
+                CargoIntent::AddOptional
+            )))
+        ));
+    }
+
+    #[test]
+    fn add_without_a_name_targets_the_focused_crate() {
+        assert!(matches!(
+            parse("add"),
+            Ok(Action::Home(HomeCommand::BeginCargoRequest(
+                CargoIntent::Add
+            )))
+        ));
+    }
+
+    #[test]
+    fn scope_and_sort_match_loosely() {
+        assert!(matches!(
+            parse("scope project"),
+            Ok(Action::Search(SearchCommand::Scope(Scope::Project)))
+        ));
+        assert!(matches!(
+            parse("sort recent-downloads"),
+            Ok(Action::Search(SearchCommand::SortBy(Sort::RecentDownloads)))
+        ));
+        assert!(parse("sort stars").unwrap_err().contains("relevance"));
+    }
+
+    #[test]
+    fn open_picks_the_link() {
+        assert!(matches!(
+            parse("open docs"),
+            Ok(Action::Home(HomeCommand::OpenDocs))
+        ));
+        assert!(parse("open wiki").is_err());
+    }
+
+    #[test]
+    fn filter_toggles_a_link_or_clears() {
+        assert!(matches!(
+            parse("filter repo"),
+            Ok(Action::Search(SearchCommand::ToggleLinkFilter(Some(
+                RequiredLink::Repo
+            ))))
+        ));
+        assert!(matches!(
+            parse("filter"),
+            Ok(Action::Search(SearchCommand::ToggleLinkFilter(None)))
+        ));
+    }
+
+    #[test]
+    fn keyword_sets_or_clears_the_filter() {
+        assert!(matches!(
+            parse("keyword CLI"),
+            Ok(Action::Search(SearchCommand::Keyword(Some(keyword)))) if keyword == "cli"
+        ));
+        assert!(matches!(
+            parse("keyword"),
+            Ok(Action::Search(SearchCommand::Keyword(None)))
+        ));
+    }
+
+    #[test]
+    fn unknown_or_incomplete_commands_are_errors() {
+        assert!(
+            parse("frobnicate")
+                .unwrap_err()
+                .starts_with("Unknown command")
+        );
+        assert_eq!(parse("remove").unwrap_err(), "remove needs a crate name");
+        assert!(matches!(
+            parse("uninstall ripgrep"),
+            Ok(Action::Home(HomeCommand::ConfirmCargo(CargoCommand::Uninstall(name)))) if name == "ripgrep"
+        ));
+        assert!(parse("").is_err());
+    }
+}
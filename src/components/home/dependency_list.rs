@@ -0,0 +1,136 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
+
+use crate::action::Action;
+use crate::components::ux::{KeyOutcome, Popup};
+use crate::config::Config;
+use crate::search::CrateDependency;
+
+/// A read-only popup listing a crate version's direct dependencies, one `name req (kind)` per line.
+pub struct DependencyList {
+    config: Config,
+    title: String,
+    lines: Vec<Line<'static>>,
+    scroll: usize,
+}
+
+impl DependencyList {
+    pub fn new(
+        config: Config,
+        crate_name: &str,
+        version: &str,
+        dependencies: &[CrateDependency],
+    ) -> Self {
+        Self {
+            config,
+            title: format!(" {crate_name} {version} — dependencies "),
+            lines: dependency_lines(dependencies),
+            scroll: 0,
+        }
+    }
+
+    /// Closes on Enter or Esc; there is nothing to submit.
+    pub fn handle_key(&mut self, key: KeyEvent) -> KeyOutcome<Action> {
+        let last = self.lines.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter => return KeyOutcome::Cancelled,
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => self.scroll = (self.scroll + 1).min(last),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            KeyCode::PageDown => self.scroll = (self.scroll + 10).min(last),
+            _ => {}
+        }
+        KeyOutcome::Pending
+    }
+
+    pub fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        let widest = self
+            .lines
+            .iter()
+            .map(Line::width)
+            .chain([self.title.chars().count()])
+            .max()
+            .unwrap_or_default();
+        let width = u16::try_from(widest + 4)
+            .unwrap_or(u16::MAX)
+            .clamp(40, 80)
+            .min(area.width);
+        let inner_height = u16::try_from(self.lines.len())
+            .unwrap_or(u16::MAX)
+            .clamp(1, area.height.saturating_sub(4).max(1));
+
+        let inner = Popup::new(width, inner_height + 2)
+            .title(self.title.clone())
+            .footer(" ↑↓ scroll · Esc close ")
+            .border_style(self.config.theme.accent)
+            .render(frame, area);
+
+        let scroll = u16::try_from(self.scroll).unwrap_or(u16::MAX);
+        frame.render_widget(
+            Paragraph::new(self.lines.clone()).scroll((scroll, 0)),
+            inner,
+        );
+    }
+}
+
+fn dependency_lines(dependencies: &[CrateDependency]) -> Vec<Line<'static>> {
+    if dependencies.is_empty() {
+        return vec![Line::from("No dependencies")];
+    }
+
+    dependencies
+        .iter()
+        .map(|dep| {
+            let mut line = Line::from(vec![
+                dep.name.clone().bold(),
+                format!(" {} ({})", dep.req, dep.kind).into(),
+            ]);
+            if dep.optional {
+                line.push_span(" optional".dim());
+            }
+            if dep.kind != "normal" {
+                line = line.dim();
+            }
+            line
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn dep(name: &str, req: &str, kind: &str, optional: bool) -> CrateDependency {
+        CrateDependency {
+            name: name.into(),
+            req: req.into(),
+            kind: kind.into(),
+            optional,
+        }
+    }
+
+    #[test]
+    fn lists_each_dependency_with_its_requirement_and_kind() {
+        let lines = dependency_lines(&[
+            dep("serde_derive", "^1.0", "normal", true),
+            dep("serde_json", "^1.0", "dev", false),
+        ]);
+        assert_eq!(
+            lines.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            vec![
+                "serde_derive ^1.0 (normal) optional",
+                "serde_json ^1.0 (dev)"
+            ]
+        );
+    }
+
+    #[test]
+    fn says_so_when_there_are_none() {
+        assert_eq!(dependency_lines(&[])[0].to_string(), "No dependencies");
+    }
+}
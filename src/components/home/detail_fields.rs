@@ -0,0 +1,198 @@
+//! The fields of the details pane as plain values, one per rendered line, so the line selected
+//! with the arrow keys can be copied.
+
+use crate::search::Crate;
+use crate::util::{format_number, format_size};
+
+/// The label of the related crates line, which looks up one of them rather than being copied.
+pub const RELATED: &str = "Related";
+
+/// A line of the details pane: its label and the value copied for it, `None` while it's unknown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetailField {
+    pub label: &'static str,
+    pub value: Option<String>,
+}
+
+/// The details of `cr` in the order they're drawn, including only the optional lines (project and
+/// installed versions, open issues, badges, related crates) that are drawn for it.
+pub fn detail_fields(cr: &Crate, show_prereleases: bool, date_format: &str) -> Vec<DetailField> {
+    let field = |label, value: Option<String>| DetailField { label, value };
+
+    let mut fields = vec![
+        field("Stable Version", Some(cr.version.clone())),
+        field(
+            "Latest Version",
+            cr.latest_version(show_prereleases).map(str::to_string),
+        ),
+    ];
+    if let Some(version) = &cr.project_version {
+        fields.push(field("Project Version", Some(version.clone())));
+    }
+    if let Some(version) = &cr.installed_version {
+        fields.push(field("Installed Version", Some(version.clone())));
+    }
+    fields.extend([
+        field("Description", cr.description.clone()),
+        field("Home Page", cr.homepage.clone()),
+        field("Documentation", cr.documentation.clone()),
+        field("Repository", cr.repository.clone()),
+        field(
+            "crates.io",
+            Some(format!("https://crates.io/crates/{}", cr.id)),
+        ),
+        field("Downloads", cr.downloads.map(|d| format_number(Some(d)))),
+        field(
+            "Recent Downloads",
+            cr.recent_downloads.map(|d| format_number(Some(d))),
+        ),
+        field("Size", cr.crate_size.map(format_size)),
+    ]);
+    if let Some(open_issues) = cr.open_issues {
+        fields.push(field(
+            "Open Issues/PRs",
+            Some(format_number(Some(open_issues))),
+        ));
+    }
+    fields.extend([
+        field("Features", cr.features.as_ref().map(|f| f.join(", "))),
+        field("Categories", cr.categories.as_ref().map(|c| c.join(", "))),
+    ]);
+    if !cr.badges.is_empty() {
+        fields.push(field("Badges", Some(cr.badges.join(", "))));
+    }
+    fields.extend([
+        field(
+            "Created",
+            cr.created_at.map(|v| v.format(date_format).to_string()),
+        ),
+        field(
+            "Updated",
+            cr.updated_at.map(|v| v.format(date_format).to_string()),
+        ),
+    ]);
+    if let Some(related) = cr.related.as_ref().filter(|r| !r.is_empty()) {
+        fields.push(field(RELATED, Some(related.join(", "))));
+    }
+    fields
+}
+
+/// `fields` as a markdown block for sharing: a heading linking `name` to crates.io, the description
+/// and a list of the other known fields.
+pub fn markdown(name: &str, fields: &[DetailField]) -> String {
+    let value = |label| {
+        fields
+            .iter()
+            .find(|field| field.label == label)
+            .and_then(|field| field.value.as_deref())
+    };
+
+    let mut lines = vec![match value("crates.io") {
+        Some(url) => format!("## [{name}]({url})"),
+        None => format!("## {name}"),
+    }];
+    if let Some(description) = value("Description") {
+        lines.extend([String::new(), description.trim().to_string()]);
+    }
+    lines.push(String::new());
+    lines.extend(
+        fields
+            .iter()
+            .filter(|field| !matches!(field.label, "Description" | "crates.io"))
+            .filter_map(|field| {
+                let value = field.value.as_deref()?;
+                Some(format!("- **{}:** {value}", field.label))
+            }),
+    );
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn labels(cr: &Crate) -> Vec<&'static str> {
+        detail_fields(cr, false, "%Y-%m-%d")
+            .into_iter()
+            .map(|field| field.label)
+            .collect()
+    }
+
+    #[test]
+    fn optional_lines_are_listed_only_when_drawn() {
+        let cr = Crate {
+            id: "serde".into(),
+            ..Default::default()
+        };
+        assert_eq!(labels(&cr).len(), 14);
+        assert!(!labels(&cr).contains(&"Project Version"));
+
+        let cr = Crate {
+            project_version: Some("1.0".into()),
+            open_issues: Some(3),
+            badges: vec!["maintained".into()],
+            related: Some(vec!["serde_json".into()]),
+            ..cr
+        };
+        let labels = labels(&cr);
+        assert_eq!(labels.len(), 18);
+        assert_eq!(labels[2], "Project Version");
+        assert_eq!(labels.last(), Some(&RELATED));
+    }
+
+    #[test]
+    fn values_are_plain_text() {
+        let cr = Crate {
+            id: "serde".into(),
+            repository: Some("https://github.com/serde-rs/serde".into()),
+            features: Some(vec!["derive".into(), "std".into()]),
+            ..Default::default()
+        };
+        let value = |label| {
+            detail_fields(&cr, false, "%Y-%m-%d")
+                .into_iter()
+                .find(|field| field.label == label)
+                .and_then(|field| field.value)
+        };
+        assert_eq!(
+            value("Repository").as_deref(),
+            Some("https://github.com/serde-rs/serde")
+        );
+        assert_eq!(
+            value("crates.io").as_deref(),
+            Some("https://crates.io/crates/serde")
+        );
+        assert_eq!(value("Features").as_deref(), Some("derive, std"));
+        assert_eq!(value("Updated"), None);
+    }
+
+    #[test]
+    fn markdown_lists_the_known_fields_under_a_linked_heading() {
+        let cr = Crate {
+            id: "serde".into(),
+            version: "1.0.210".into(),
+            description: Some("A serialization framework".into()),
+            repository: Some("https://github.com/serde-rs/serde".into()),
+            ..Default::default()
+        };
+        let fields: Vec<_> = detail_fields(&cr, false, "%Y-%m-%d")
+            .into_iter()
+            .filter(|field| {
+                matches!(
+                    field.label,
+                    "Stable Version" | "Description" | "Repository" | "crates.io" | "Downloads"
+                )
+            })
+            .collect();
+        assert_eq!(
+            markdown("serde", &fields),
+            "## [serde](https://crates.io/crates/serde)\n\
+             \n\
+             A serialization framework\n\
+             \n\
+             - **Stable Version:** 1.0.210\n\
+             - **Repository:** https://github.com/serde-rs/serde\n"
+        );
+    }
+}
@@ -3,27 +3,46 @@ use ratatui::{
     Frame,
     layout::{Constraint, Flex, Layout, Rect},
     style::{Color, Style, Styled, Stylize},
-    text::{Line, Text},
-    widgets::{Block, Borders, List, ListItem, Padding, Paragraph, Wrap},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, Padding, Paragraph, Sparkline, Wrap},
 };
 
-use crate::components::home::Home;
+use crate::action::Action;
 use crate::components::home::focusable::Focusable;
 use crate::components::home::overlay::Overlay;
-use crate::components::ux::{Button, GRAY, ORANGE, PURPLE, State, YELLOW};
+use crate::components::home::{Home, HomeCommand};
+use crate::components::ux::{Button, GRAY, Icon, ORANGE, PURPLE, State, YELLOW};
+use crate::config::Theme;
 use crate::errors::AppResult;
-use crate::search::Crate;
-use crate::util::{format_number, get_relative_time};
+use crate::search::{Crate, Popularity, ResultRow};
+use crate::util::{
+    elide, format_number, format_size, format_version, format_version_req, get_relative_time,
+};
+
+/// Below this width the two columns get too cramped to read, so only one is shown at a time.
+const MIN_TWO_COLUMN_WIDTH: u16 = 60;
 
 pub fn render(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResult<()> {
-    let [left_col_area, right_col_area] = Layout::horizontal([
-        Constraint::Percentage(home.left_column_width_percent),
-        Constraint::Percentage(100 - home.left_column_width_percent),
-    ])
-    .areas(area);
+    if home.left_column_width_percent == 0 {
+        // Zoomed (or resized all the way down): the details have the whole screen.
+        render_right(home, frame, area)?;
+    } else if area.width < MIN_TWO_COLUMN_WIDTH {
+        // Stacked: the details (or help) take over the screen while they have focus.
+        if home.is_details_focused() || home.focused == Focusable::Help {
+            render_right(home, frame, area)?;
+        } else {
+            render_left(home, frame, area)?;
+        }
+    } else {
+        let [left_col_area, right_col_area] = Layout::horizontal([
+            Constraint::Percentage(home.left_column_width_percent),
+            Constraint::Percentage(100 - home.left_column_width_percent),
+        ])
+        .areas(area);
 
-    render_left(home, frame, left_col_area)?;
-    render_right(home, frame, right_col_area)?;
+        render_left(home, frame, left_col_area)?;
+        render_right(home, frame, right_col_area)?;
+    }
 
     // Draw overlay last so the modal sits on top of everything.
     if let Some(overlay) = home.overlay.as_mut() {
@@ -97,6 +116,7 @@ fn render_search(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResult<()
 }
 
 fn render_results(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResult<()> {
+    let ascii = home.config.config.ascii_icons;
     let dropdown_title = |label: String, active: bool| {
         let style = if active {
             home.config.theme.title
@@ -116,102 +136,214 @@ fn render_results(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResult<(
             home.scope.to_string(),
             matches!(home.overlay, Some(Overlay::Scope(_))),
         ))
+        .title(match &home.owner {
+            Some(owner) => Line::from(
+                Icon::Owner
+                    .label(owner, ascii)
+                    .set_style(home.config.theme.title),
+            )
+            .right_aligned(),
+            None => Line::default(),
+        })
+        .title(match &home.keyword {
+            Some(keyword) => Line::from(
+                Icon::Keyword
+                    .label(keyword, ascii)
+                    .set_style(home.config.theme.title),
+            )
+            .right_aligned(),
+            None => Line::default(),
+        })
+        .title(match home.staging.len() {
+            0 => Line::default(),
+            staged => Line::from(
+                Icon::Package
+                    .label(format!("{staged} staged"), ascii)
+                    .set_style(home.config.theme.title),
+            )
+            .right_aligned(),
+        })
+        .title(if home.normal_deps_only {
+            Line::from(
+                Icon::Project
+                    .label("no dev/build deps", ascii)
+                    .set_style(home.config.theme.title),
+            )
+            .right_aligned()
+        } else {
+            Line::default()
+        })
+        .title(match home.link_filter.label() {
+            Some(label) => Line::from(
+                Icon::Link
+                    .label(label, ascii)
+                    .set_style(home.config.theme.title),
+            )
+            .right_aligned(),
+            None => Line::default(),
+        })
         .title(dropdown_title(
             home.sort.to_string(),
             matches!(home.overlay, Some(Overlay::Sort(_))),
         ));
 
     if let Some(results) = home.search_results.as_mut() {
-        let selected_index = results.selected_index();
-        let correction = 2;
+        let correction: usize = 2;
 
         let list_items: Vec<ListItem> = results
-            .crates
-            .iter()
-            .map(|cr| {
-                let tag = if cr.project_version.is_some() {
-                    "+ "
+            .rows()
+            .into_iter()
+            .map(|row| {
+                let cr = match row {
+                    ResultRow::Crate(index) => &results.crates[index],
+                    ResultRow::Header(source) => {
+                        return ListItem::new(Line::from(format!("── {source} ")).bold().dim());
+                    }
+                };
+                let markers = &home.config.config.markers;
+                let tag = markers.tag(if cr.project_version.is_some() {
+                    Some(&markers.project)
                 } else if cr.installed_version.is_some() {
-                    "i "
+                    Some(&markers.installed)
                 } else {
-                    "  "
-                };
+                    None
+                });
+                let tag_width = tag.chars().count();
 
                 let name = &cr.name;
-                let mut version = cr.version.to_string();
+                let semver = home.config.config.list_semver_versions;
+                let resolved = |version: &str| {
+                    if semver {
+                        format_version(version)
+                    } else {
+                        version.to_string()
+                    }
+                };
+                let mut version = if home.show_latest_versions {
+                    resolved(cr.max_version.as_deref().unwrap_or(&cr.version))
+                } else {
+                    resolved(&cr.version)
+                };
 
-                // If metadata is not loaded, version might be the project or installed version
-                // and not the latest version. In that case, we don't want to manipulate the
-                // displayed version string
-                if cr.is_metadata_loaded() {
+                let update_marker = if markers.update.is_empty() {
+                    String::new()
+                } else {
+                    format!(" {}", markers.update)
+                };
+                let outdated_binary = home
+                    .outdated_binaries
+                    .iter()
+                    .find(|binary| binary.name == cr.name);
+
+                // An outdated binary's latest version is known from the check, loaded or not.
+                // Otherwise, if metadata is not loaded, version might be the project or installed
+                // version and not the latest version. In that case, we don't want to manipulate
+                // the displayed version string
+                if cr.project_version.is_none()
+                    && let Some(binary) = outdated_binary
+                {
+                    version = format!(
+                        "{} ({}){update_marker}",
+                        resolved(&binary.latest),
+                        resolved(&binary.installed)
+                    );
+                } else if cr.is_metadata_loaded() {
                     if let Some(project_version) = &cr.project_version {
-                        version = format!("{version} ({project_version})");
+                        let marker = match cr.update_available() {
+                            Some(_) => update_marker.as_str(),
+                            None => "",
+                        };
+                        let project_version = if semver {
+                            format_version_req(project_version)
+                        } else {
+                            project_version.clone()
+                        };
+                        version = format!("{version} ({project_version}){marker}");
                     } else if let Some(installed_version) = &cr.installed_version {
-                        version = format!("{version} ({installed_version})");
+                        version = format!("{version} ({})", resolved(installed_version));
                     }
                 }
 
-                let mut white_space = area.width as i32
-                    - name.len() as i32
-                    - tag.len() as i32
-                    - version.len() as i32
-                    - correction;
-                if white_space < 1 {
-                    white_space = 1;
+                // Shorten the name rather than let the row overflow and wrap its version around.
+                let available = usize::from(area.width).saturating_sub(tag_width + correction);
+                let version_len = version.chars().count();
+                let name = elide(name, available.saturating_sub(version_len + 1).max(1));
+                let white_space = available
+                    .saturating_sub(name.chars().count() + version_len)
+                    .max(1);
+
+                let details = format!("{}{}{}", name, " ".repeat(white_space), version);
+
+                let mut style = result_style(cr, &home.config.theme);
+                if home.config.config.color_by_popularity {
+                    style = match cr.popularity() {
+                        Some(Popularity::Obscure) => style.dim(),
+                        Some(Popularity::Popular) => style.bold(),
+                        Some(Popularity::Moderate) | None => style,
+                    };
                 }
 
-                let details = format!("{}{}{}", name, " ".repeat(white_space as usize), version);
-
-                let style = if cr.project_version.is_some() {
-                    Style::default().fg(Color::LightCyan)
-                } else if cr.installed_version.is_some() {
-                    Style::default().fg(Color::LightMagenta)
-                } else {
-                    Style::default()
-                };
+                let row = Line::from(vec![tag.bold(), details.into()]).set_style(style);
+                if !home.show_descriptions {
+                    return ListItem::new(row);
+                }
 
-                ListItem::new(Line::from(vec![tag.bold(), details.into()]).set_style(style))
+                // Local crates have no description until hydrated; keep their second line blank.
+                let description = cr
+                    .description
+                    .as_deref()
+                    .map(|d| elide(d.trim(), available))
+                    .unwrap_or_default();
+                let indent = " ".repeat(tag_width);
+                ListItem::new(vec![
+                    row,
+                    Line::from(format!("{indent}{description}")).dim(),
+                ])
             })
             .collect();
 
-        let items_in_prev_pages = results.items_before_current_page();
-        let selected_item_num = selected_index.map_or(0, |ix| ix + 1);
-        let selected_item_num_in_total = items_in_prev_pages + selected_item_num;
+        let counter = match results.selected_position() {
+            Some(position) => format!(" {position}/{} ", results.total_count),
+            None => format!(" {} ", results.total_count),
+        };
         let selected = results.selected();
 
+        let block = if home.show_latest_versions {
+            block.title_bottom(Line::from(" latest versions ").left_aligned())
+        } else {
+            block
+        };
+        let block = match &home.missing_exact_match {
+            Some(term) => block.title_bottom(
+                Line::from(format!(" no exact match for '{term}' "))
+                    .dim()
+                    .left_aligned(),
+            ),
+            None => block,
+        };
+        let block = match &home.typeahead {
+            Some(typeahead) => block.title_bottom(
+                Line::from(format!(" /{}▏", typeahead.prefix()))
+                    .left_aligned()
+                    .style(home.config.theme.accent_active),
+            ),
+            None => block,
+        };
+
         let list = List::new(list_items)
             .block(
-                block
-                    .title(format!(
-                        " {}/{} ",
-                        selected_item_num_in_total, results.total_count
+                block.title(counter).title_bottom(
+                    Line::from(format!(
+                        " Page {}/{}{} ",
+                        results.current_page(),
+                        results.page_count(),
+                        if results.is_capped() { " (capped)" } else { "" },
                     ))
-                    .title_bottom(
-                        Line::from(format!(
-                            " Page {}/{} ",
-                            results.current_page(),
-                            results.page_count(),
-                        ))
-                        .right_aligned(),
-                    ),
+                    .right_aligned(),
+                ),
             )
             // Selected row highlight style
-            .highlight_style(if selected.is_some_and(|s| s.project_version.is_some()) {
-                Style::default()
-                    .bold()
-                    .bg(Color::LightCyan)
-                    .fg(Color::Black)
-            } else if selected.is_some_and(|s| s.installed_version.is_some()) {
-                Style::default()
-                    .bold()
-                    .bg(Color::LightMagenta)
-                    .fg(Color::Black)
-            } else {
-                Style::default()
-                    .bold()
-                    .bg(home.config.theme.accent.fg.unwrap_or(Color::Yellow))
-                    .fg(Color::Black)
-            });
+            .highlight_style(highlight_style(selected, &home.config.theme));
 
         frame.render_stateful_widget(list, area, &mut results.list_state);
     } else {
@@ -221,16 +353,48 @@ fn render_results(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResult<(
     Ok(())
 }
 
+/// The style of a row in the results list: project dependencies and installed binaries are told
+/// apart by color, or by bold/underline in the monochrome theme.
+fn result_style(cr: &Crate, theme: &Theme) -> Style {
+    match (cr.project_version.is_some(), cr.installed_version.is_some()) {
+        (true, _) if theme.monochrome => Style::default().bold(),
+        (true, _) => Style::default().fg(Color::LightCyan),
+        (false, true) if theme.monochrome => Style::default().underlined(),
+        (false, true) => Style::default().fg(Color::LightMagenta),
+        (false, false) => Style::default(),
+    }
+}
+
+/// The style of the selected row in the results list. The monochrome theme reverses the row
+/// instead of filling it with a color.
+fn highlight_style(selected: Option<&Crate>, theme: &Theme) -> Style {
+    if theme.monochrome {
+        return selected
+            .map_or_else(Style::default, |cr| result_style(cr, theme))
+            .bold()
+            .reversed();
+    }
+
+    if selected.is_some_and(|s| s.project_version.is_some()) {
+        theme.project_selected
+    } else if selected.is_some_and(|s| s.installed_version.is_some()) {
+        theme.installed_selected
+    } else {
+        theme.selected
+    }
+}
+
 fn render_right(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResult<()> {
-    if home.show_help || home.search_results.is_none() {
+    if home.show_help {
         render_help(home, frame, area)?;
         return Ok(());
     }
 
-    let selected_crate = {
-        let search_results = home.search_results.as_ref().unwrap();
-        search_results.selected()
+    let Some(search_results) = home.search_results.as_ref() else {
+        render_help_hidden(home, frame, area)?;
+        return Ok(());
     };
+    let selected_crate = search_results.selected();
 
     if let Some(cr) = selected_crate {
         render_crate_details(home, cr, frame, area)?;
@@ -248,14 +412,18 @@ fn render_help(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResult<()>
 
     const PAD: usize = 20;
 
-    let text = Text::from(vec![
+    let markers = &home.config.config.markers;
+    let mut lines = vec![
         Line::from(vec![
             format!("{:<PAD$}", "SYMBOLS:").set_style(header_style),
-            "+ ".light_cyan().bold(),
+            format!("{} ", markers.project).light_cyan().bold(),
             "added".set_style(desc_style),
             "   ".into(),
-            "i ".light_magenta().bold(),
+            format!("{} ", markers.installed).light_magenta().bold(),
             "installed".set_style(desc_style),
+            "   ".into(),
+            format!("{} ", markers.update).bold(),
+            "update available".set_style(desc_style),
         ]),
         Line::default(),
         Line::from(vec!["SEARCH".set_style(header_style)]),
@@ -263,6 +431,14 @@ fn render_help(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResult<()>
             format!("{:<PAD$}", "Enter:").set_style(prop_style),
             "Run search".set_style(desc_style),
         ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "Ctrl + u/w:").set_style(prop_style),
+            "Clear the search box / delete a word".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "=name:").set_style(prop_style),
+            "Look up exactly that crate".set_style(desc_style),
+        ]),
         Line::from(vec![
             format!("{:<PAD$}", "Ctrl + s:").set_style(prop_style),
             "Sort".set_style(desc_style),
@@ -271,6 +447,14 @@ fn render_help(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResult<()>
             format!("{:<PAD$}", "Ctrl + a:").set_style(prop_style),
             "Search scope".set_style(desc_style),
         ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "[, ]:").set_style(prop_style),
+            "Previous/next sort (outside the search box)".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "{, }:").set_style(prop_style),
+            "Previous/next scope (outside the search box)".set_style(desc_style),
+        ]),
         Line::default(),
         Line::from(vec!["NAVIGATION".set_style(header_style)]),
         Line::from(vec![
@@ -286,12 +470,20 @@ fn render_help(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResult<()>
             "Change column width".set_style(desc_style),
         ]),
         Line::from(vec![
-            format!("{:<PAD$}", "Ctrl + h:").set_style(prop_style),
-            "Toggle this help screen".set_style(desc_style),
+            format!("{:<PAD$}", "Ctrl + o:").set_style(prop_style),
+            "Filter by crates.io owner".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "Ctrl + k:").set_style(prop_style),
+            "Filter by keyword (Esc clears)".set_style(desc_style),
         ]),
         Line::from(vec![
-            format!("{:<PAD$}", "Ctrl + c:").set_style(prop_style),
-            "Quit".set_style(desc_style),
+            format!("{:<PAD$}", "U:").set_style(prop_style),
+            "Check installed binaries for updates, then update".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "::").set_style(prop_style),
+            "Command palette (e.g. add serde, sort name)".set_style(desc_style),
         ]),
         Line::default(),
         Line::from(vec!["RESULTS".set_style(header_style)]),
@@ -299,19 +491,95 @@ fn render_help(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResult<()>
             format!("{:<PAD$}", "a, r:").set_style(prop_style),
             "Add (pick features) / remove from project".set_style(desc_style),
         ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "A:").set_style(prop_style),
+            "Add from its git repository".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "O:").set_style(prop_style),
+            "Add as an optional dependency".set_style(desc_style),
+        ]),
         Line::from(vec![
             format!("{:<PAD$}", "i, u:").set_style(prop_style),
             "Install (pick features) / uninstall binary".set_style(desc_style),
         ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "e:").set_style(prop_style),
+            "Open dependency source in $EDITOR".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "c:").set_style(prop_style),
+            "Compare features with the latest version".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "D:").set_style(prop_style),
+            "List its dependencies".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "w:").set_style(prop_style),
+            "Show why it's a project dependency".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "y, Y:").set_style(prop_style),
+            "Copy Cargo.toml line / with default features".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "n:").set_style(prop_style),
+            "Copy crate name".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "s:").set_style(prop_style),
+            "Stage/unstage crate for export".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "S:").set_style(prop_style),
+            "Export staged crates as cargo add lines".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "C:").set_style(prop_style),
+            "Copy git clone command for its repository".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "M:").set_style(prop_style),
+            "Copy its details as markdown".set_style(desc_style),
+        ]),
         Line::from(vec![
             format!("{:<PAD$}", "Ctrl + d:").set_style(prop_style),
             "Open docs".set_style(desc_style),
         ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "o:").set_style(prop_style),
+            "Pick one of the crate's links to open".set_style(desc_style),
+        ]),
         Line::default(),
         Line::from(vec![
             format!("{:<PAD$}", "Up, Down:").set_style(prop_style),
             "Select crate in list".set_style(desc_style),
         ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "/:").set_style(prop_style),
+            "Jump to crate by typing its name".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "z:").set_style(prop_style),
+            "Expand details to full width / restore".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "d:").set_style(prop_style),
+            "Show/hide descriptions in the list".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "v:").set_style(prop_style),
+            "List latest versions (incl. pre-releases)".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "Enter:").set_style(prop_style),
+            "Focus the details buttons".set_style(desc_style),
+        ]),
+        Line::from(vec![
+            format!("{:<PAD$}", "Tab:").set_style(prop_style),
+            "Select a details line; Enter copies its value".set_style(desc_style),
+        ]),
         Line::from(vec![
             format!("{:<PAD$}", "Left, Right:").set_style(prop_style),
             "Go previous/next page".set_style(desc_style),
@@ -324,10 +592,49 @@ fn render_help(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResult<()>
             format!("{:<PAD$}", "Ctrl + Home/End:").set_style(prop_style),
             "Go to first/last page".set_style(desc_style),
         ]),
-    ]);
+    ];
+
+    if home.config.config.vim_keys {
+        lines.extend([
+            Line::default(),
+            Line::from(vec!["VIM KEYS".set_style(header_style)]),
+            Line::from(vec![
+                format!("{:<PAD$}", "j, k:").set_style(prop_style),
+                "Select crate in list".set_style(desc_style),
+            ]),
+            Line::from(vec![
+                format!("{:<PAD$}", "G:").set_style(prop_style),
+                "Go to last crate in page".set_style(desc_style),
+            ]),
+            Line::from(vec![
+                format!("{:<PAD$}", "Ctrl + d/u:").set_style(prop_style),
+                "Move half a page down/up".set_style(desc_style),
+            ]),
+        ]);
+    }
+
+    // The configurable bindings come from the config itself, so customized keys show up here.
+    let mut category = "";
+    for bound in home.config.keybindings.reference(home.mode) {
+        if bound.category != category {
+            category = bound.category;
+            lines.extend([
+                Line::default(),
+                Line::from(vec![
+                    format!("{} KEYBINDINGS", category.to_uppercase()).set_style(header_style),
+                ]),
+            ]);
+        }
+        lines.push(Line::from(vec![
+            format!("{:<PAD$}", format!("{}:", bound.keys.join(", "))).set_style(prop_style),
+            bound.action.set_style(desc_style),
+        ]));
+    }
+
+    let text = Text::from(lines);
 
     let block = Block::default()
-        .title(" 📖 Help ")
+        .title(Icon::Help.label("Help", home.config.config.ascii_icons))
         .title_style(home.config.theme.title)
         .padding(Padding::uniform(1))
         .borders(Borders::ALL)
@@ -359,8 +666,23 @@ fn render_help(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResult<()>
 fn render_crate_details(home: &Home, cr: &Crate, frame: &mut Frame, area: Rect) -> AppResult<()> {
     let details_focused = home.is_details_focused();
 
+    // Search results arrive with partial data; the rest hydrates in the background.
+    let loading = home.is_loading_details();
+    let mut title = vec![
+        Icon::Details
+            .label(&cr.name, home.config.config.ascii_icons)
+            .into(),
+    ];
+    if loading {
+        let throbber = throbber_widgets_tui::Throbber::default()
+            .throbber_set(throbber_widgets_tui::BRAILLE_EIGHT)
+            .use_type(throbber_widgets_tui::WhichUse::Spin);
+        title.push(throbber.to_symbol_span(&home.spinner_state));
+        title.push(" ".into());
+    }
+
     let main_block = Block::default()
-        .title(format!(" 🧐 {} ", cr.name))
+        .title(Line::from(title))
         .title_style(home.config.theme.title)
         .padding(Padding::horizontal(1))
         .borders(Borders::ALL)
@@ -379,6 +701,17 @@ fn render_crate_details(home: &Home, cr: &Crate, frame: &mut Frame, area: Rect)
     }
     .bold();
 
+    // Long URLs are elided to one row to keep the layout steady, and shown in full (wrapped) once
+    // the details pane is focused.
+    let url_width = usize::from(main_block.inner(area).width).saturating_sub(left_column_width);
+    let url = |value: Option<&str>| -> Span<'static> {
+        match value {
+            Some(value) if details_focused => value.to_string().into(),
+            Some(value) => elide(value, url_width).into(),
+            None => placeholder(loading),
+        }
+    };
+
     let mut text = Text::default();
 
     text.lines.extend(vec![
@@ -388,17 +721,23 @@ fn render_crate_details(home: &Home, cr: &Crate, frame: &mut Frame, area: Rect)
         ]),
         Line::from(vec![
             format!("{:<left_column_width$}", "Latest Version:").set_style(prop_style),
-            cr.max_version.as_deref().unwrap_or_default().into(),
+            cr.latest_version(home.config.config.show_prereleases)
+                .unwrap_or_default()
+                .into(),
         ]),
     ]);
 
     if let Some(project_version) = &cr.project_version {
-        text.lines.push(Line::from(vec![
+        let mut line = Line::from(vec![
             format!("{:<left_column_width$}", "Project Version:")
                 .light_cyan()
                 .bold(),
             project_version.as_str().bold(),
-        ]));
+        ]);
+        if let Some(latest) = cr.update_available() {
+            line.push_span(format!(" (update available: {latest})").set_style(prop_style));
+        }
+        text.lines.push(line);
     }
 
     if let Some(installed_version) = &cr.installed_version {
@@ -413,69 +752,120 @@ fn render_crate_details(home: &Home, cr: &Crate, frame: &mut Frame, area: Rect)
     text.lines.extend(vec![
         Line::from(vec![
             format!("{:<left_column_width$}", "Description:").set_style(prop_style),
-            cr.description.as_deref().unwrap_or_default().bold(),
+            cr.description
+                .as_deref()
+                .map_or_else(|| placeholder(loading), |d| d.to_string().bold()),
         ]),
         Line::from(vec![
             format!("{:<left_column_width$}", "Home Page:").set_style(prop_style),
-            cr.homepage.as_deref().unwrap_or_default().into(),
+            url(cr.homepage.as_deref()),
         ]),
         Line::from(vec![
             format!("{:<left_column_width$}", "Documentation:").set_style(prop_style),
-            cr.documentation.as_deref().unwrap_or_default().into(),
+            url(cr.documentation.as_deref()),
         ]),
         Line::from(vec![
             format!("{:<left_column_width$}", "Repository:").set_style(prop_style),
-            cr.repository.as_deref().unwrap_or_default().into(),
+            url(cr.repository.as_deref()),
         ]),
         Line::from(vec![
             format!("{:<left_column_width$}", "crates.io:").set_style(prop_style),
-            format!("https://crates.io/crates/{}", cr.id).into(),
+            url(Some(&format!("https://crates.io/crates/{}", cr.id))),
         ]),
         Line::from(vec![
             format!("{:<left_column_width$}", "Downloads:").set_style(prop_style),
-            format_number(cr.downloads).into(),
-        ]),
-        Line::from(vec![
-            format!("{:<left_column_width$}", "Recent Downloads:").set_style(prop_style),
-            format_number(cr.recent_downloads).into(),
+            cr.downloads
+                .map_or_else(|| placeholder(loading), |d| format_number(Some(d)).into()),
         ]),
-        render_features(cr, prop_style, left_column_width),
+    ]);
+
+    let recent_downloads = match cr.recent_downloads {
+        Some(_) => format_number(cr.recent_downloads),
+        None => placeholder(loading).content.into_owned(),
+    };
+    let recent_downloads_line = text.lines.len();
+    text.lines.push(Line::from(vec![
+        format!("{:<left_column_width$}", "Recent Downloads:").set_style(prop_style),
+        recent_downloads.as_str().into(),
+    ]));
+
+    text.lines.push(Line::from(vec![
+        format!("{:<left_column_width$}", "Size:").set_style(prop_style),
+        cr.crate_size
+            .map_or_else(|| placeholder(loading), |size| format_size(size).into()),
+    ]));
+    if let Some(open_issues) = cr.open_issues {
+        text.lines.push(Line::from(vec![
+            format!("{:<left_column_width$}", "Open Issues/PRs:").set_style(prop_style),
+            format_number(Some(open_issues)).into(),
+        ]));
+    }
+
+    text.lines.extend(vec![
+        render_features(cr, prop_style, left_column_width, loading),
         Line::from(vec![
             format!("{:<left_column_width$}", "Categories:").set_style(prop_style),
             cr.categories
                 .as_ref()
-                .map(|v| v.join(", "))
-                .unwrap_or("Loading...".into())
-                .into(),
+                .map_or_else(|| placeholder(loading), |v| v.join(", ").into()),
         ]),
+    ]);
+
+    if !cr.badges.is_empty() {
+        let mut line =
+            Line::from(format!("{:<left_column_width$}", "Badges:").set_style(prop_style));
+        for (ix, badge) in cr.badges.iter().enumerate() {
+            if ix > 0 {
+                line.push_span(" ");
+            }
+            line.push_span(format!("[{badge}]").bold());
+        }
+        text.lines.push(line);
+    }
+
+    let date_format = home.config.config.date_format.as_str();
+    text.lines.extend(vec![
         Line::from(vec![
             format!("{:<left_column_width$}", "Created:").set_style(prop_style),
             match cr.created_at.as_ref() {
-                None => "".into(),
-                Some(v) => v.format("%d/%m/%Y %H:%M:%S (UTC)").to_string().into(),
+                None => placeholder(loading),
+                Some(v) => v.format(date_format).to_string().into(),
             },
         ]),
         Line::from(vec![
             format!("{:<left_column_width$}", "Updated:").set_style(prop_style),
             match cr.updated_at.as_ref() {
-                None => "".into(),
+                None => placeholder(loading),
                 Some(v) => {
                     let updated_relative = match cr.updated_at {
                         None => "".into(),
                         Some(v) => get_relative_time(v, Utc::now()),
                     };
 
-                    format!(
-                        "{} ({})",
-                        v.format("%d/%m/%Y %H:%M:%S (UTC)"),
-                        updated_relative
-                    )
-                    .into()
+                    format!("{} ({})", v.format(date_format), updated_relative).into()
                 }
             },
         ]),
     ]);
 
+    if let Some(related) = cr.related.as_ref().filter(|r| !r.is_empty()) {
+        text.lines.push(Line::from(vec![
+            format!("{:<left_column_width$}", "Related:").set_style(prop_style),
+            related.join(", ").into(),
+        ]));
+    }
+
+    // Each line is one of `Home::detail_fields`, in the same order, so the selected one lines up.
+    if home.focused == Focusable::Details {
+        let selected = home.selected_field_index(text.lines.len());
+        let style = highlight_style(None, &home.config.theme);
+        text.lines[selected] = text.lines[selected].clone().patch_style(style);
+    }
+
+    // Wrapped height of everything above the "Recent Downloads" row, i.e. the row it lands on.
+    let recent_downloads_row =
+        Paragraph::new(text.lines[..recent_downloads_line].to_vec()).wrap(Wrap { trim: false });
+
     let details_paragraph = Paragraph::new(text).wrap(Wrap { trim: false });
 
     frame.render_widget(&main_block, area);
@@ -489,8 +879,33 @@ fn render_crate_details(home: &Home, cr: &Crate, frame: &mut Frame, area: Rect)
     ])
     .areas(main_block.inner(area));
 
+    let recent_downloads_row = recent_downloads_row.line_count(details_area.width) as u16;
     frame.render_widget(details_paragraph, details_area);
 
+    if let Some(daily) = cr.daily_downloads.as_deref() {
+        let offset = (left_column_width + recent_downloads.len() + 2) as u16;
+        let sparkline_area = Rect {
+            x: details_area.x + offset,
+            y: details_area.y + recent_downloads_row,
+            width: details_area.width.saturating_sub(offset).min(30),
+            height: 1,
+        };
+        if sparkline_area.width > 0 && recent_downloads_row < details_area.height {
+            // Bucket the days so the whole window fits, one bar per column.
+            let days_per_bar = daily.len().div_ceil(sparkline_area.width as usize).max(1);
+            let bars: Vec<u64> = daily
+                .chunks(days_per_bar)
+                .map(|days| days.iter().sum())
+                .collect();
+            frame.render_widget(
+                Sparkline::default()
+                    .data(&bars)
+                    .style(home.config.theme.accent),
+                sparkline_area,
+            );
+        }
+    }
+
     let buttons_row_layout = Layout::horizontal([
         Constraint::Length(left_column_width as u16),
         Constraint::Length(12),
@@ -507,6 +922,7 @@ fn render_crate_details(home: &Home, cr: &Crate, frame: &mut Frame, area: Rect)
         frame.render_widget(
             Button::new("Docs")
                 .theme(ORANGE)
+                .monochrome(home.config.theme.monochrome)
                 .state(match home.focused == Focusable::DocsButton {
                     true => State::Selected,
                     _ => State::Normal,
@@ -517,12 +933,13 @@ fn render_crate_details(home: &Home, cr: &Crate, frame: &mut Frame, area: Rect)
 
     if home.should_show_repo_button() {
         frame.render_widget(
-            Button::new("Repository").theme(GRAY).state(
-                match home.focused == Focusable::RepositoryButton {
+            Button::new("Repository")
+                .theme(GRAY)
+                .monochrome(home.config.theme.monochrome)
+                .state(match home.focused == Focusable::RepositoryButton {
                     true => State::Selected,
                     _ => State::Normal,
-                },
-            ),
+                }),
             button_areas.remove(0),
         );
     }
@@ -532,24 +949,26 @@ fn render_crate_details(home: &Home, cr: &Crate, frame: &mut Frame, area: Rect)
 
     if home.should_show_cratesio_button() {
         frame.render_widget(
-            Button::new("crates.io").theme(YELLOW).state(
-                match home.focused == Focusable::CratesIoButton {
+            Button::new("crates.io")
+                .theme(YELLOW)
+                .monochrome(home.config.theme.monochrome)
+                .state(match home.focused == Focusable::CratesIoButton {
                     true => State::Selected,
                     _ => State::Normal,
-                },
-            ),
+                }),
             button1_area,
         );
     }
 
     if home.should_show_librs_button() {
         frame.render_widget(
-            Button::new("lib.rs").theme(PURPLE).state(
-                match home.focused == Focusable::LibRsButton {
+            Button::new("lib.rs")
+                .theme(PURPLE)
+                .monochrome(home.config.theme.monochrome)
+                .state(match home.focused == Focusable::LibRsButton {
                     true => State::Selected,
                     _ => State::Normal,
-                },
-            ),
+                }),
             button2_area,
         );
     }
@@ -557,10 +976,25 @@ fn render_crate_details(home: &Home, cr: &Crate, frame: &mut Frame, area: Rect)
     Ok(())
 }
 
-fn render_features(cr: &Crate, label_style: Style, label_width: usize) -> Line<'static> {
+/// The value shown for a detail the crate doesn't have: a loading hint while its metadata is
+/// still being fetched, otherwise nothing.
+fn placeholder(loading: bool) -> Span<'static> {
+    if loading {
+        "loading metadata…".dim()
+    } else {
+        Span::default()
+    }
+}
+
+fn render_features(
+    cr: &Crate,
+    label_style: Style,
+    label_width: usize,
+    loading: bool,
+) -> Line<'static> {
     let mut spans = vec![format!("{:<label_width$}", "Features:").set_style(label_style)];
     match cr.features.as_ref() {
-        None => spans.push("Loading...".into()),
+        None => spans.push(placeholder(loading)),
         Some(features) if features.is_empty() => spans.push("(none)".dim()),
         Some(features) => {
             for (i, name) in features.iter().enumerate() {
@@ -598,6 +1032,35 @@ fn render_no_results(home: &mut Home, frame: &mut Frame, area: Rect) -> AppResul
     Ok(())
 }
 
+/// What the right pane shows before any search once help is hidden.
+fn render_help_hidden(home: &Home, frame: &mut Frame, area: Rect) -> AppResult<()> {
+    let main_block = Block::default()
+        .padding(Padding::uniform(1))
+        .borders(Borders::ALL);
+
+    // Names the key help is bound to, or the palette command when it isn't bound at all.
+    let keys = home
+        .config
+        .keybindings
+        .keys_for(home.mode, &Action::Home(HomeCommand::ToggleHelp));
+    let keys = if keys.is_empty() {
+        ": help".to_string()
+    } else {
+        keys.join(" or ")
+    };
+    let text = Text::raw(format!("{keys} to show help"));
+    let centered = center(
+        main_block.inner(area),
+        Constraint::Length(text.width() as u16),
+        Constraint::Length(1),
+    )?;
+
+    frame.render_widget(main_block, area);
+    frame.render_widget(text, centered);
+
+    Ok(())
+}
+
 fn center(area: Rect, horizontal: Constraint, vertical: Constraint) -> AppResult<Rect> {
     let [area] = Layout::horizontal([horizontal])
         .flex(Flex::Center)
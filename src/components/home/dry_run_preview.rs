@@ -0,0 +1,183 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::widgets::Paragraph;
+
+use crate::action::Action;
+use crate::cargo::CargoCommand;
+use crate::components::home::HomeCommand;
+use crate::components::ux::{KeyOutcome, Popup};
+use crate::config::Config;
+
+/// What `cargo add --dry-run` or `cargo remove --dry-run` reported, shown for the user to confirm
+/// or cancel. When the dry run failed, cargo's error is shown instead and there is nothing to
+/// confirm.
+pub struct DryRunPreview {
+    config: Config,
+    command: CargoCommand,
+    failed: bool,
+    lines: Vec<String>,
+    scroll: usize,
+}
+
+impl DryRunPreview {
+    /// Builds a preview that runs `command` (an `Add` or `Remove`) when confirmed, unless the dry
+    /// run `failed`.
+    pub fn new(config: Config, command: CargoCommand, output: &str, failed: bool) -> Self {
+        let mut lines: Vec<String> = output.lines().map(str::to_string).collect();
+        if lines.is_empty() {
+            lines.push("cargo reported no changes".to_string());
+        }
+        if failed {
+            lines.insert(0, "cargo would fail:".to_string());
+        }
+
+        Self {
+            config,
+            command,
+            failed,
+            lines,
+            scroll: 0,
+        }
+    }
+
+    fn title(&self) -> String {
+        match &self.command {
+            CargoCommand::Add {
+                name,
+                package: Some(package),
+                ..
+            } => format!(" Add {name} to {package}? "),
+            CargoCommand::Add { name, .. } => format!(" Add {name}? "),
+            CargoCommand::Remove {
+                name,
+                package: Some(package),
+            } => format!(" Remove {name} from {package}? "),
+            CargoCommand::Remove { name, .. } => format!(" Remove {name}? "),
+            _ => " Preview ".to_string(),
+        }
+    }
+
+    fn footer(&self) -> &'static str {
+        match &self.command {
+            _ if self.failed => " Enter/Esc close ",
+            CargoCommand::Remove { .. } => " Enter remove · Esc cancel ",
+            _ => " Enter add · Esc cancel ",
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> KeyOutcome<Action> {
+        match key.code {
+            KeyCode::Esc => return KeyOutcome::Cancelled,
+            KeyCode::Enter if self.failed => return KeyOutcome::Cancelled,
+            // Already past the package step and the dry run, so it must not go through them again.
+            KeyCode::Enter => {
+                return KeyOutcome::Submitted(Action::Home(HomeCommand::RunPreviewed(
+                    self.command.clone(),
+                )));
+            }
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => {
+                self.scroll = (self.scroll + 1).min(self.lines.len().saturating_sub(1));
+            }
+            _ => {}
+        }
+        KeyOutcome::Pending
+    }
+
+    pub fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        let widest = self
+            .lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or_default();
+        let width = u16::try_from(widest + 4)
+            .unwrap_or(u16::MAX)
+            .clamp(40, 90)
+            .min(area.width);
+        let inner_height = u16::try_from(self.lines.len())
+            .unwrap_or(u16::MAX)
+            .clamp(1, area.height.saturating_sub(4).max(1));
+
+        let inner = Popup::new(width, inner_height + 2)
+            .title(self.title())
+            .footer(self.footer())
+            .border_style(self.config.theme.accent)
+            .render(frame, area);
+
+        let scroll = u16::try_from(self.scroll).unwrap_or(u16::MAX);
+        frame.render_widget(
+            Paragraph::new(self.lines.join("\n")).scroll((scroll, 0)),
+            inner,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cargo::DependencySource;
+    use crossterm::event::KeyModifiers;
+    use pretty_assertions::assert_eq;
+
+    fn press(preview: &mut DryRunPreview, code: KeyCode) -> KeyOutcome<Action> {
+        preview.handle_key(KeyEvent::new(code, KeyModifiers::empty()))
+    }
+
+    fn add_serde() -> CargoCommand {
+        CargoCommand::Add {
+            name: "serde".into(),
+            version: "1.0.228".into(),
+            features: vec!["derive".into()],
+            no_default_features: false,
+            optional: false,
+            package: None,
+            source: DependencySource::Registry,
+        }
+    }
+
+    #[test]
+    fn enter_runs_the_previewed_add() {
+        let mut preview = DryRunPreview::new(
+            Config::default(),
+            add_serde(),
+            "Adding serde v1.0.228 to dependencies",
+            false,
+        );
+
+        match press(&mut preview, KeyCode::Enter) {
+            KeyOutcome::Submitted(Action::Home(HomeCommand::RunPreviewed(command))) => {
+                assert_eq!(command, add_serde())
+            }
+            other => panic!("expected the Add command, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn esc_cancels() {
+        let mut preview = DryRunPreview::new(Config::default(), add_serde(), "", false);
+        assert!(matches!(
+            press(&mut preview, KeyCode::Esc),
+            KeyOutcome::Cancelled
+        ));
+    }
+
+    #[test]
+    fn a_failed_dry_run_only_closes() {
+        let remove = CargoCommand::Remove {
+            name: "serde".into(),
+            package: None,
+        };
+        let mut preview = DryRunPreview::new(
+            Config::default(),
+            remove,
+            "error: the dependency `serde` is used by feature `json`",
+            true,
+        );
+        assert!(matches!(
+            press(&mut preview, KeyCode::Enter),
+            KeyOutcome::Cancelled
+        ));
+    }
+}
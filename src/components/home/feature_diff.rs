@@ -0,0 +1,131 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::Paragraph;
+
+use crate::action::Action;
+use crate::components::ux::{KeyOutcome, Popup};
+use crate::config::Config;
+use crate::search::FeatureDiff;
+
+/// A read-only popup listing how a dependency's features change when upgrading it.
+pub struct FeatureDiffView {
+    config: Config,
+    title: String,
+    lines: Vec<Line<'static>>,
+    scroll: usize,
+}
+
+impl FeatureDiffView {
+    pub fn new(config: Config, crate_name: &str, diff: &FeatureDiff) -> Self {
+        Self {
+            config,
+            title: format!(" {crate_name} {} → {} ", diff.from, diff.to),
+            lines: diff_lines(diff),
+            scroll: 0,
+        }
+    }
+
+    /// Closes on Enter or Esc; there is nothing to submit.
+    pub fn handle_key(&mut self, key: KeyEvent) -> KeyOutcome<Action> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter => return KeyOutcome::Cancelled,
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => {
+                self.scroll = (self.scroll + 1).min(self.lines.len().saturating_sub(1));
+            }
+            _ => {}
+        }
+        KeyOutcome::Pending
+    }
+
+    pub fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        let widest = self
+            .lines
+            .iter()
+            .map(Line::width)
+            .chain([self.title.chars().count()])
+            .max()
+            .unwrap_or_default();
+        let width = u16::try_from(widest + 4)
+            .unwrap_or(u16::MAX)
+            .clamp(40, 80)
+            .min(area.width);
+        let inner_height = u16::try_from(self.lines.len())
+            .unwrap_or(u16::MAX)
+            .clamp(1, area.height.saturating_sub(4).max(1));
+
+        let inner = Popup::new(width, inner_height + 2)
+            .title(self.title.clone())
+            .footer(" Esc close ")
+            .border_style(self.config.theme.accent)
+            .render(frame, area);
+
+        let scroll = u16::try_from(self.scroll).unwrap_or(u16::MAX);
+        frame.render_widget(
+            Paragraph::new(self.lines.clone()).scroll((scroll, 0)),
+            inner,
+        );
+    }
+}
+
+fn diff_lines(diff: &FeatureDiff) -> Vec<Line<'static>> {
+    if diff.is_empty() {
+        return vec![Line::from("No feature changes")];
+    }
+
+    let mut lines = Vec::new();
+    lines.extend(diff.added.iter().map(|f| format!("+ {f}").green().into()));
+    lines.extend(diff.removed.iter().map(|f| format!("- {f}").red().into()));
+    if !diff.defaults_added.is_empty() || !diff.defaults_removed.is_empty() {
+        if !lines.is_empty() {
+            lines.push(Line::default());
+        }
+        lines.push(Line::from("Default features:").bold());
+        lines.extend(
+            diff.defaults_added
+                .iter()
+                .map(|f| format!("+ {f}").green().into()),
+        );
+        lines.extend(
+            diff.defaults_removed
+                .iter()
+                .map(|f| format!("- {f}").red().into()),
+        );
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn text(lines: &[Line]) -> Vec<String> {
+        lines.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn lists_feature_and_default_changes() {
+        let diff = FeatureDiff {
+            added: vec!["serde".into()],
+            removed: vec!["alloc".into()],
+            defaults_added: vec!["serde".into()],
+            ..Default::default()
+        };
+        assert_eq!(
+            text(&diff_lines(&diff)),
+            vec!["+ serde", "- alloc", "", "Default features:", "+ serde"]
+        );
+    }
+
+    #[test]
+    fn says_so_when_nothing_changed() {
+        assert_eq!(
+            text(&diff_lines(&FeatureDiff::default())),
+            vec!["No feature changes"]
+        );
+    }
+}
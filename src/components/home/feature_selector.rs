@@ -6,6 +6,7 @@ use ratatui::text::Line;
 
 use crate::action::Action;
 use crate::components::home::cargo_request::CargoIntent;
+use crate::components::status_bar::{StatusCommand, StatusLevel};
 use crate::components::ux::{KeyOutcome, MultiSelect, MultiSelectItem};
 use crate::config::Config;
 
@@ -15,6 +16,8 @@ pub struct FeatureSelector {
     crate_name: String,
     version: String,
     intent: CargoIntent,
+    /// Every feature the crate declares; selections are checked against it before running cargo.
+    features: Vec<String>,
     default_features: Vec<String>,
     selector: MultiSelect<String>,
 }
@@ -48,6 +51,7 @@ impl FeatureSelector {
             crate_name: crate_name.clone(),
             version,
             intent,
+            features: features.to_vec(),
             default_features: default_features.to_vec(),
             selector: MultiSelect::new(config, format!(" {verb} {crate_name} — features "), items),
         }
@@ -61,12 +65,29 @@ impl FeatureSelector {
         }
     }
 
-    /// Builds the cargo command for the chosen feature set.
+    /// Builds the cargo command for the chosen feature set, or an error status when a selection
+    /// isn't one of the crate's known features.
     ///
     /// When every default feature is still checked, cargo enables them implicitly, so only the
     /// extra (non-default) selections are passed. If the user unchecked any default, the defaults
     /// are turned off (`--no-default-features`) and the full kept set is passed explicitly.
     fn command(&self, checked: &[String]) -> Action {
+        let unknown: Vec<&str> = checked
+            .iter()
+            .filter(|name| !self.features.contains(name))
+            .map(String::as_str)
+            .collect();
+        if !unknown.is_empty() {
+            return Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Error,
+                format!(
+                    "Unknown feature(s) for {}: {}",
+                    self.crate_name,
+                    unknown.join(", ")
+                ),
+            ));
+        }
+
         let no_default_features = self.default_features.iter().any(|d| !checked.contains(d));
 
         let features: Vec<String> = checked
@@ -94,6 +115,7 @@ mod tests {
     use crate::action::Action;
     use crate::cargo::CargoCommand;
     use crate::components::home::cargo_request::CargoIntent;
+    use crate::components::status_bar::{StatusCommand, StatusLevel};
     use crate::components::ux::KeyOutcome;
     use crate::config::Config;
     use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
@@ -176,4 +198,15 @@ mod tests {
         assert_eq!(features, vec!["a".to_string()]);
         assert!(!no_default_features);
     }
+
+    #[test]
+    fn unknown_features_are_rejected_with_an_error_status() {
+        let sel = selector(&["derive", "std"], &["std"]);
+        match sel.command(&["std".to_string(), "serde/std".to_string()]) {
+            Action::Status(StatusCommand::UpdateStatus(StatusLevel::Error, message)) => {
+                assert_eq!(message, "Unknown feature(s) for demo: serde/std");
+            }
+            other => panic!("expected an error status, got {other:?}"),
+        }
+    }
 }
@@ -10,6 +10,8 @@ pub enum Focusable {
     #[default]
     Search,
     Results,
+    /// The lines of the details pane, one of which is selected to be copied.
+    Details,
     DocsButton,
     RepositoryButton,
     CratesIoButton,
@@ -47,4 +49,34 @@ mod tests {
         assert_eq!(Focusable::Search.prev(), Focusable::Help);
         assert_eq!(Focusable::Help.prev(), Focusable::LibRsButton);
     }
+
+    fn all() -> impl Iterator<Item = Focusable> {
+        (0..Focusable::COUNT).map(|ix| Focusable::from_repr(ix).unwrap())
+    }
+
+    #[test]
+    fn next_then_prev_round_trips_for_every_variant() {
+        for focusable in all() {
+            assert_eq!(focusable.next().prev(), focusable);
+            assert_eq!(focusable.prev().next(), focusable);
+        }
+    }
+
+    #[test]
+    fn cycling_visits_every_variant_once_before_wrapping() {
+        let mut visited = vec![Focusable::Help];
+        let mut current = Focusable::Help.next();
+        for _ in 1..Focusable::COUNT {
+            visited.push(current);
+            current = current.next();
+        }
+        assert_eq!(visited, all().collect::<Vec<_>>());
+        assert_eq!(current, Focusable::Help);
+
+        let mut backwards = Focusable::Help;
+        for _ in 0..Focusable::COUNT {
+            backwards = backwards.prev();
+        }
+        assert_eq!(backwards, Focusable::Help);
+    }
 }
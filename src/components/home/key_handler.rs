@@ -1,12 +1,17 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::time::Instant;
 use tui_input::backend::crossterm::EventHandler;
 
 use crate::action::Action;
-use crate::cargo::CargoCommand;
-use crate::components::home::cargo_request::CargoIntent;
+use crate::cargo::{CargoCommand, DependencySource};
+use crate::components::home::cargo_request::{CargoIntent, route_through_package_step};
+use crate::components::home::detail_fields::RELATED;
 use crate::components::home::overlay::Overlay;
+use crate::components::home::staging::DEFAULT_EXPORT_PATH;
+use crate::components::home::typeahead::Typeahead;
 use crate::components::home::{Focusable, Home, HomeCommand};
-use crate::components::ux::{Confirm, Dropdown, KeyOutcome};
+use crate::components::status_bar::{StatusCommand, StatusLevel};
+use crate::components::ux::{Confirm, Dropdown, KeyOutcome, Prompt};
 use crate::errors::AppResult;
 use crate::search::SearchCommand;
 
@@ -15,6 +20,13 @@ pub fn handle_key(home: &mut Home, key: KeyEvent) -> AppResult<Option<Action>> {
         return handle_overlay_key(home, key);
     }
 
+    if home.focused == Focusable::Results
+        && !home.is_details_focused()
+        && let Some(action) = handle_typeahead_key(home, key)
+    {
+        return Ok(action);
+    }
+
     if let Some(action) = handle_global_shortcuts(home, key)? {
         return Ok(Some(action));
     }
@@ -35,17 +47,19 @@ pub fn handle_key(home: &mut Home, key: KeyEvent) -> AppResult<Option<Action>> {
 
 fn handle_global_shortcuts(home: &mut Home, key: KeyEvent) -> AppResult<Option<Action>> {
     let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    // With vim keys, Ctrl+d in the results list scrolls instead of opening the docs.
+    let vim_results = home.config.config.vim_keys && home.focused == Focusable::Results;
 
-    if home.get_focused_crate().is_some() && ctrl && key.code == KeyCode::Char('d') {
+    if home.get_focused_crate().is_some() && ctrl && key.code == KeyCode::Char('d') && !vim_results
+    {
         return Ok(Some(Action::Home(HomeCommand::OpenDocs)));
     }
 
     match key.code {
-        KeyCode::Char('h') if ctrl && home.search_results.is_some() => {
-            return Ok(Some(Action::Home(HomeCommand::ToggleHelp)));
-        }
         KeyCode::Esc => {
-            return if home.focused == Focusable::Search {
+            return if home.focused == Focusable::Search && home.keyword.is_some() {
+                Ok(Some(Action::Search(SearchCommand::Keyword(None))))
+            } else if home.focused == Focusable::Search {
                 Ok(Some(Action::Search(SearchCommand::Clear)))
             } else {
                 Ok(Some(Action::Home(HomeCommand::Focus(Focusable::Search))))
@@ -59,6 +73,42 @@ fn handle_global_shortcuts(home: &mut Home, key: KeyEvent) -> AppResult<Option<A
             open_scope_overlay(home);
             return Ok(None);
         }
+        // Typed into the search box when it's focused.
+        KeyCode::Char(c @ ('[' | ']' | '{' | '}')) if home.focused != Focusable::Search => {
+            return Ok(Some(Action::Search(match c {
+                ']' => SearchCommand::NextSort,
+                '[' => SearchCommand::PrevSort,
+                '}' => SearchCommand::NextScope,
+                _ => SearchCommand::PrevScope,
+            })));
+        }
+        KeyCode::Char(':') if home.focused != Focusable::Search => {
+            home.overlay = Some(Overlay::Command(Prompt::new(
+                home.config.clone(),
+                "Command".into(),
+                "",
+            )));
+            return Ok(None);
+        }
+        KeyCode::Char('U') if home.focused != Focusable::Search => {
+            return Ok(Some(Action::Home(HomeCommand::UpdateBinaries)));
+        }
+        KeyCode::Char('o') if ctrl => {
+            home.overlay = Some(Overlay::Owner(Prompt::new(
+                home.config.clone(),
+                "Crates owned by (crates.io user)".into(),
+                home.owner.as_deref().unwrap_or_default(),
+            )));
+            return Ok(None);
+        }
+        KeyCode::Char('k') if ctrl => {
+            home.overlay = Some(Overlay::Keyword(Prompt::new(
+                home.config.clone(),
+                "Crates with keyword".into(),
+                home.keyword.as_deref().unwrap_or_default(),
+            )));
+            return Ok(None);
+        }
         KeyCode::Char('/') => {
             return Ok(Some(Action::Home(HomeCommand::Focus(Focusable::Search))));
         }
@@ -77,7 +127,22 @@ fn handle_global_shortcuts(home: &mut Home, key: KeyEvent) -> AppResult<Option<A
                     status: None,
                 })));
             }
-            Focusable::Results => {}
+            Focusable::Results => {
+                if let Some(button) = first_button(home) {
+                    return Ok(Some(Action::Home(HomeCommand::Focus(button))));
+                }
+            }
+            Focusable::Details => {
+                let fields = home.detail_fields();
+                let selected = fields.get(home.selected_field_index(fields.len()));
+                return Ok(Some(Action::Home(
+                    if selected.is_some_and(|field| field.label == RELATED) {
+                        HomeCommand::ShowRelated
+                    } else {
+                        HomeCommand::CopyDetailField
+                    },
+                )));
+            }
             Focusable::DocsButton => {
                 return Ok(Some(Action::Home(HomeCommand::OpenDocs)));
             }
@@ -92,32 +157,63 @@ fn handle_global_shortcuts(home: &mut Home, key: KeyEvent) -> AppResult<Option<A
             }
             _ => {}
         },
-        KeyCode::Up if home.focused == Focusable::Help => {
-            if home.vertical_help_scroll > 0 {
-                home.vertical_help_scroll -= 1;
-            }
+        KeyCode::Up if home.focused == Focusable::Help && home.vertical_help_scroll > 0 => {
+            home.vertical_help_scroll -= 1;
         }
-        KeyCode::Down if home.focused == Focusable::Help => {
-            if home.vertical_help_scroll < home.max_help_scroll {
-                home.vertical_help_scroll += 1;
-            }
+        KeyCode::Down
+            if home.focused == Focusable::Help
+                && home.vertical_help_scroll < home.max_help_scroll =>
+        {
+            home.vertical_help_scroll += 1;
         }
         KeyCode::Left if ctrl && home.left_column_width_percent >= 10 => {
             home.left_column_width_percent -= 10;
+            home.width_before_zoom = None;
             return Ok(None);
         }
         KeyCode::Right if ctrl && home.left_column_width_percent <= 90 => {
             home.left_column_width_percent += 10;
+            home.width_before_zoom = None;
+            return Ok(None);
+        }
+        KeyCode::Char('c') if !ctrl && home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::CompareFeatures)));
+        }
+        KeyCode::Char('D') if home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::ShowDependencies)));
+        }
+        KeyCode::Char('w') if !ctrl && home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::ShowInverseTree)));
+        }
+        KeyCode::Char('z') if home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::ToggleDetailsZoom)));
+        }
+        KeyCode::Char('A') if home.get_focused_crate().is_some() => {
+            open_git_add_overlay(home);
             return Ok(None);
         }
-        KeyCode::Char('a') => {
-            if home.get_focused_crate().is_some() {
-                return Ok(Some(Action::Home(HomeCommand::BeginCargoRequest(
-                    CargoIntent::Add,
+        KeyCode::Char('O') if home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::BeginCargoRequest(
+                CargoIntent::AddOptional,
+            ))));
+        }
+        KeyCode::Char('a') if home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::BeginCargoRequest(
+                CargoIntent::Add,
+            ))));
+        }
+        KeyCode::Char('r') if !ctrl => {
+            // The dry-run preview asks for confirmation itself.
+            if home.config.config.preview_remove
+                && let Some(selected) = home.get_focused_crate()
+            {
+                return Ok(Some(route_through_package_step(Action::Cargo(
+                    CargoCommand::Remove {
+                        name: selected.name.clone(),
+                        package: None,
+                    },
                 ))));
             }
-        }
-        KeyCode::Char('r') => {
             if let Some(selected) = home.get_focused_crate() {
                 home.overlay = Some(Overlay::Confirm(
                     Confirm::new(
@@ -129,18 +225,58 @@ fn handle_global_shortcuts(home: &mut Home, key: KeyEvent) -> AppResult<Option<A
                         .as_str(),
                         true,
                     ),
-                    Action::Cargo(CargoCommand::Remove(selected.name.clone())),
+                    Action::Cargo(CargoCommand::Remove {
+                        name: selected.name.clone(),
+                        package: None,
+                    }),
                 ));
             }
         }
-        KeyCode::Char('i') => {
-            if home.get_focused_crate().is_some() {
-                return Ok(Some(Action::Home(HomeCommand::BeginCargoRequest(
-                    CargoIntent::Install,
-                ))));
-            }
+        KeyCode::Char('i') if home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::BeginCargoRequest(
+                CargoIntent::Install,
+            ))));
+        }
+        KeyCode::Char('e') if home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::OpenInEditor)));
+        }
+        KeyCode::Char('R') if home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::RenderReadme)));
+        }
+        KeyCode::Char('y') if home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::CopyDependencySnippet {
+                with_features: false,
+            })));
         }
-        KeyCode::Char('u') => {
+        KeyCode::Char('Y') if home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::CopyDependencySnippet {
+                with_features: true,
+            })));
+        }
+        KeyCode::Char('o') if !ctrl && home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::OpenLinksMenu)));
+        }
+        KeyCode::Char('M') if home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::ExportMarkdown(None))));
+        }
+        KeyCode::Char('C') if home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::CopyCloneCommand)));
+        }
+        KeyCode::Char('s') if !ctrl && home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::ToggleStaged)));
+        }
+        KeyCode::Char('S') if home.get_focused_crate().is_some() => {
+            home.overlay = Some(Overlay::Export(Prompt::new(
+                home.config.clone(),
+                "Export staged crates to".into(),
+                DEFAULT_EXPORT_PATH,
+            )));
+            return Ok(None);
+        }
+        KeyCode::Char('n') if !ctrl && home.get_focused_crate().is_some() => {
+            return Ok(Some(Action::Home(HomeCommand::CopyName)));
+        }
+        KeyCode::Char('u') if !(ctrl && vim_results) => {
             if let Some(selected) = home.get_focused_crate() {
                 home.overlay = Some(Overlay::Confirm(
                     Confirm::new(
@@ -162,6 +298,43 @@ fn handle_global_shortcuts(home: &mut Home, key: KeyEvent) -> AppResult<Option<A
     Ok(None)
 }
 
+/// Handles `/` in the results list and every key while typeahead is on. Returns `None` for keys
+/// that should go through the usual handling, which for anything but a letter also ends typeahead.
+fn handle_typeahead_key(home: &mut Home, key: KeyEvent) -> Option<Option<Action>> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let Some(typeahead) = home.typeahead.as_mut() else {
+        if key.code == KeyCode::Char('/') && home.search_results.is_some() {
+            home.typeahead = Some(Typeahead::default());
+            return Some(None);
+        }
+        return None;
+    };
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Enter => {
+            home.typeahead = None;
+            Some(None)
+        }
+        KeyCode::Backspace => {
+            typeahead.pop(Instant::now());
+            Some(None)
+        }
+        KeyCode::Char(c) if !ctrl => {
+            typeahead.push(c, Instant::now());
+            let results = home.search_results.as_ref()?;
+            let start = results.selected_index().unwrap_or(0);
+            let action = typeahead
+                .find(&results.crates, start)
+                .map(|ix| Action::Search(SearchCommand::SelectIndex(Some(ix))));
+            Some(action)
+        }
+        _ => {
+            home.typeahead = None;
+            None
+        }
+    }
+}
+
 /// Routes a key to the active overlay and applies its outcome.
 fn handle_overlay_key(home: &mut Home, key: KeyEvent) -> AppResult<Option<Action>> {
     let outcome = match home.overlay.as_mut() {
@@ -177,11 +350,49 @@ fn handle_overlay_key(home: &mut Home, key: KeyEvent) -> AppResult<Option<Action
         }
         KeyOutcome::Submitted(action) => {
             home.overlay = None;
-            Ok(Some(action))
+            Ok(Some(route_through_package_step(action)))
         }
     }
 }
 
+/// Asks for the branch or commit to add the focused crate at from its repository, through
+/// `cargo add --git`. Useful for trying fixes that aren't released yet.
+fn open_git_add_overlay(home: &mut Home) {
+    let Some(cr) = home.get_focused_crate() else {
+        return;
+    };
+    let Some(url) = cr.repository.clone() else {
+        home.action_tx
+            .send(Action::Status(StatusCommand::UpdateStatus(
+                StatusLevel::Info,
+                format!("{} has no repository to add from", cr.name),
+            )))
+            .ok();
+        return;
+    };
+
+    let command = CargoCommand::Add {
+        name: cr.name.clone(),
+        version: cr.version.clone(),
+        features: Vec::new(),
+        no_default_features: false,
+        optional: false,
+        package: None,
+        source: DependencySource::Git {
+            url,
+            reference: None,
+        },
+    };
+    home.overlay = Some(Overlay::GitReference(
+        Prompt::new(
+            home.config.clone(),
+            format!("Add {} from git at branch or commit", cr.name),
+            "",
+        ),
+        command,
+    ));
+}
+
 /// Opens the sort dropdown, initialized to the current sort.
 fn open_sort_overlay(home: &mut Home) {
     home.overlay = Some(Overlay::Sort(Dropdown::new(
@@ -201,12 +412,16 @@ fn open_scope_overlay(home: &mut Home) {
 }
 
 fn handle_search_focus(home: &mut Home, key: KeyEvent) -> AppResult<Option<Action>> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
     match key.code {
         KeyCode::Down => {
             if home.search_results.is_some() {
                 return Ok(Some(Action::Home(HomeCommand::Focus(Focusable::Results))));
             }
         }
+        // Clears the box only; the results stay until the next search runs. (The input handles
+        // `Ctrl + w` to delete a word itself.)
+        KeyCode::Char('u') if ctrl => home.input.reset(),
         _ => {
             // Send to input box
             home.input.handle_event(&crossterm::event::Event::Key(key));
@@ -216,6 +431,16 @@ fn handle_search_focus(home: &mut Home, key: KeyEvent) -> AppResult<Option<Actio
 }
 
 fn handle_results_focus(home: &mut Home, key: KeyEvent) -> AppResult<Option<Action>> {
+    if home.config.config.vim_keys
+        && home
+            .search_results
+            .as_ref()
+            .is_some_and(|results| !results.crates.is_empty())
+        && let Some(action) = handle_vim_key(home, key)
+    {
+        return Ok(action);
+    }
+
     if let Some(results) = &home.search_results {
         if results.crates.is_empty() {
             return Ok(None);
@@ -225,24 +450,20 @@ fn handle_results_focus(home: &mut Home, key: KeyEvent) -> AppResult<Option<Acti
 
         match key.code {
             // List navigation
-            KeyCode::Up => {
-                if let Some(selected_ix) = results.selected_index()
-                    && selected_ix == 0
-                {
-                    return Ok(Some(Action::Home(HomeCommand::Focus(Focusable::Search))));
-                }
-
-                return Ok(Some(Action::Search(SearchCommand::SelectPrev)));
-            }
-            KeyCode::Down => {
-                return Ok(Some(Action::Search(SearchCommand::SelectNext)));
-            }
+            KeyCode::Up => return Ok(Some(select_prev(home))),
+            KeyCode::Down => return Ok(Some(select_next(home))),
             KeyCode::Home if !ctrl => {
                 return Ok(Some(Action::Search(SearchCommand::SelectFirst)));
             }
             KeyCode::End if !ctrl => {
                 return Ok(Some(Action::Search(SearchCommand::SelectLast)));
             }
+            KeyCode::Char('d') if !ctrl => {
+                return Ok(Some(Action::Home(HomeCommand::ToggleDescriptions)));
+            }
+            KeyCode::Char('v') if !ctrl => {
+                return Ok(Some(Action::Home(HomeCommand::ToggleLatestVersions)));
+            }
             // Page navigation
             KeyCode::Left if !ctrl && results.has_prev_page() => {
                 return Ok(Some(Action::Search(SearchCommand::NavPagesBack(1))));
@@ -250,6 +471,12 @@ fn handle_results_focus(home: &mut Home, key: KeyEvent) -> AppResult<Option<Acti
             KeyCode::Right if !ctrl && results.has_next_page() => {
                 return Ok(Some(Action::Search(SearchCommand::NavPagesForward(1))));
             }
+            // On the last page there's no page to turn, so step into the details buttons.
+            KeyCode::Right if !ctrl => {
+                return Ok(
+                    first_button(home).map(|button| Action::Home(HomeCommand::Focus(button)))
+                );
+            }
             KeyCode::Home if ctrl => {
                 return Ok(Some(Action::Search(SearchCommand::NavFirstPage)));
             }
@@ -263,16 +490,81 @@ fn handle_results_focus(home: &mut Home, key: KeyEvent) -> AppResult<Option<Acti
     Ok(None)
 }
 
+/// Up in the results list: the previous crate, or from the first one the search box, or with
+/// `list_wrap` the last crate.
+fn select_prev(home: &Home) -> Action {
+    let at_first = home
+        .search_results
+        .as_ref()
+        .and_then(|results| results.selected_index())
+        == Some(0);
+    match (at_first, home.config.config.list_wrap) {
+        (true, true) => Action::Search(SearchCommand::SelectLast),
+        (true, false) => Action::Home(HomeCommand::Focus(Focusable::Search)),
+        (false, _) => Action::Search(SearchCommand::SelectPrev),
+    }
+}
+
+/// Down in the results list: the next crate, or with `list_wrap` from the last one the first.
+fn select_next(home: &Home) -> Action {
+    let at_last = home.search_results.as_ref().is_some_and(|results| {
+        results.selected_index() == Some(results.crates.len().saturating_sub(1))
+    });
+    if at_last && home.config.config.list_wrap {
+        Action::Search(SearchCommand::SelectFirst)
+    } else {
+        Action::Search(SearchCommand::SelectNext)
+    }
+}
+
+/// Vim-style navigation in the results list, used when `vim_keys` is on. Returns `None` for keys
+/// that should go through the usual handling. `gg` is tracked here rather than bound in the
+/// keymap, which would take the second `g` from the search box and prompts too.
+fn handle_vim_key(home: &mut Home, key: KeyEvent) -> Option<Option<Action>> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let pending_g = std::mem::take(&mut home.pending_g);
+    let results = home.search_results.as_ref()?;
+    let selected = results.selected_index().unwrap_or(0);
+    let half_page = (results.current_page_len() / 2).max(1);
+
+    let action = match key.code {
+        KeyCode::Char('g') if !ctrl && pending_g => Action::Search(SearchCommand::SelectFirst),
+        KeyCode::Char('g') if !ctrl => {
+            home.pending_g = true;
+            return Some(None);
+        }
+        KeyCode::Char('j') if !ctrl => select_next(home),
+        KeyCode::Char('k') if !ctrl => select_prev(home),
+        KeyCode::Char('G') => Action::Search(SearchCommand::SelectLast),
+        KeyCode::Char('d') if ctrl => {
+            Action::Search(SearchCommand::SelectIndex(Some(selected + half_page)))
+        }
+        KeyCode::Char('u') if ctrl => Action::Search(SearchCommand::SelectIndex(Some(
+            selected.saturating_sub(half_page),
+        ))),
+        _ => return None,
+    };
+    Some(Some(action))
+}
+
 fn handle_details_focus(home: &mut Home, key: KeyEvent) -> AppResult<Option<Action>> {
     let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
     if ctrl {
         return Ok(None);
     }
 
+    if home.focused == Focusable::Details {
+        return Ok(handle_fields_key(home, key));
+    }
+
     let focused = &home.focused;
 
     let next = match key.code {
-        KeyCode::Up => buttons_move_vertical(home, focused, -1),
+        // Above the buttons are the details lines, entered from the last one.
+        KeyCode::Up => buttons_move_vertical(home, focused, -1).or_else(|| {
+            home.selected_field = home.detail_fields().len().saturating_sub(1);
+            Some(Focusable::Details)
+        }),
         KeyCode::Down => buttons_move_vertical(home, focused, 1),
         KeyCode::Left => buttons_move_horizontal(home, focused, -1),
         KeyCode::Right => buttons_move_horizontal(home, focused, 1),
@@ -286,6 +578,23 @@ fn handle_details_focus(home: &mut Home, key: KeyEvent) -> AppResult<Option<Acti
     Ok(None)
 }
 
+/// Moves the selection through the details lines; past the last one are the buttons.
+fn handle_fields_key(home: &mut Home, key: KeyEvent) -> Option<Action> {
+    let count = home.detail_fields().len();
+    let selected = home.selected_field_index(count);
+    match key.code {
+        KeyCode::Up => home.selected_field = selected.saturating_sub(1),
+        KeyCode::Down if selected + 1 < count => home.selected_field = selected + 1,
+        KeyCode::Down => {
+            return first_button(home).map(|button| Action::Home(HomeCommand::Focus(button)));
+        }
+        KeyCode::Home => home.selected_field = 0,
+        KeyCode::End => home.selected_field = count.saturating_sub(1),
+        _ => {}
+    }
+    None
+}
+
 // Used for focus positioning for buttons in the details pane/box
 fn button_rows(home: &Home) -> Vec<Vec<Focusable>> {
     let top = [Focusable::DocsButton, Focusable::RepositoryButton]
@@ -301,6 +610,12 @@ fn button_rows(home: &Home) -> Vec<Vec<Focusable>> {
     vec![top, bottom]
 }
 
+/// The top-left details button shown for the selected crate, where focus lands when stepping
+/// into the details pane from the results list.
+fn first_button(home: &Home) -> Option<Focusable> {
+    button_rows(home).into_iter().flatten().next()
+}
+
 fn buttons_find_pos(rows: &[Vec<Focusable>], f: &Focusable) -> Option<(usize, usize)> {
     for (row_idx, row) in rows.iter().enumerate() {
         if let Some(col_idx) = row.iter().position(|x| x == f) {
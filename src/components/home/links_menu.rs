@@ -0,0 +1,103 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::Line;
+use ratatui::widgets::{List, ListItem, ListState};
+
+use crate::action::Action;
+use crate::components::home::HomeCommand;
+use crate::components::ux::{KeyOutcome, Popup};
+use crate::config::Config;
+use crate::search::Crate;
+
+/// A list of every link of a crate (see [`Crate::links`]), opening the chosen one.
+pub struct LinksMenu {
+    config: Config,
+    title: String,
+    links: Vec<(&'static str, String)>,
+    state: ListState,
+}
+
+impl LinksMenu {
+    pub fn new(config: Config, cr: &Crate) -> Self {
+        Self {
+            config,
+            title: format!(" Open {} ", cr.name),
+            links: cr.links(),
+            state: ListState::default().with_selected(Some(0)),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> KeyOutcome<Action> {
+        match key.code {
+            KeyCode::Esc => return KeyOutcome::Cancelled,
+            KeyCode::Enter => {
+                return match self.state.selected().and_then(|ix| self.links.get(ix)) {
+                    Some((_, url)) => {
+                        KeyOutcome::Submitted(Action::Home(HomeCommand::OpenLink(url.clone())))
+                    }
+                    None => KeyOutcome::Cancelled,
+                };
+            }
+            KeyCode::Up => {
+                let prev = self.state.selected().map_or(0, |i| i.saturating_sub(1));
+                self.state.select(Some(prev));
+            }
+            KeyCode::Down => {
+                let last = self.links.len().saturating_sub(1);
+                let next = self.state.selected().map_or(0, |i| (i + 1).min(last));
+                self.state.select(Some(next));
+            }
+            _ => {}
+        }
+        KeyOutcome::Pending
+    }
+
+    pub fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = Popup::new(60.min(area.width), self.links.len() as u16 + 2)
+            .title(self.title.clone())
+            .footer(" Enter open · Esc cancel ")
+            .border_style(self.config.theme.accent)
+            .render(frame, area);
+
+        let list = List::new(
+            self.links
+                .iter()
+                .map(|(label, url)| ListItem::new(Line::from(format!("{label:<12}{url}")))),
+        )
+        .highlight_style(self.config.theme.accent.bold())
+        .highlight_symbol("▶ ");
+
+        frame.render_stateful_widget(list, inner, &mut self.state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use pretty_assertions::assert_eq;
+
+    fn press(menu: &mut LinksMenu, code: KeyCode) -> KeyOutcome<Action> {
+        menu.handle_key(KeyEvent::new(code, KeyModifiers::empty()))
+    }
+
+    #[test]
+    fn enter_opens_the_highlighted_link() {
+        let cr = Crate {
+            id: "serde".into(),
+            name: "serde".into(),
+            documentation: Some("https://docs.rs/serde".into()),
+            ..Default::default()
+        };
+        let mut menu = LinksMenu::new(Config::default(), &cr);
+
+        press(&mut menu, KeyCode::Down);
+        match press(&mut menu, KeyCode::Enter) {
+            KeyOutcome::Submitted(Action::Home(HomeCommand::OpenLink(url))) => {
+                assert_eq!(url, "https://crates.io/crates/serde")
+            }
+            other => panic!("expected a link to open, got {other:?}"),
+        }
+    }
+}
@@ -3,11 +3,21 @@
 
 pub mod action_handler;
 pub mod cargo_request;
+pub mod command_palette;
+pub mod dependency_list;
+pub mod detail_fields;
 pub mod draw;
+pub mod dry_run_preview;
+pub mod feature_diff;
 pub mod feature_selector;
 pub mod focusable;
 pub mod key_handler;
+pub mod links_menu;
 pub mod overlay;
+pub mod package_picker;
+pub mod related_menu;
+pub mod staging;
+pub mod typeahead;
 
 use super::{Component, StatusCommand};
 
@@ -21,15 +31,22 @@ use tokio::sync::RwLock;
 use tokio::sync::mpsc::UnboundedSender;
 use tui_input::Input;
 
-use crate::cargo::CargoEnv;
+use crate::cargo::{CargoCommand, CargoEnv};
+use crate::clipboard::Clipboard;
 use crate::components::home::cargo_request::{CargoIntent, PendingCargoRequest};
+use crate::components::home::detail_fields::{DetailField, detail_fields};
 use crate::components::home::focusable::Focusable;
 use crate::components::home::overlay::Overlay;
+use crate::components::home::staging::Staging;
+use crate::components::home::typeahead::Typeahead;
 use crate::components::home::{
     action_handler::handle_action, draw::render, key_handler::handle_key,
 };
 use crate::errors::AppResult;
-use crate::search::{Crate, CrateSearchManager, Scope, SearchCommand, SearchResults, Sort};
+use crate::search::{
+    Crate, CrateSearchManager, InitialSearch, LinkFilter, OutdatedBinary, Scope, SearchCommand,
+    SearchOptions, SearchResults, Sort,
+};
 use crate::tui::Tui;
 use crate::{action::Action, app::Mode, config::Config};
 
@@ -43,67 +60,218 @@ pub enum HomeCommand {
     /// Begin an add/install for the focused crate.
     #[serde(skip)]
     BeginCargoRequest(CargoIntent),
+    /// Run a project add/remove, first asking which workspace member it targets when several
+    /// qualify.
+    #[serde(skip)]
+    ChoosePackage(CargoCommand),
+    /// Show what the `--dry-run` of `command` (an add or remove) reported, asking to confirm it.
+    /// When the dry run `failed`, `output` is cargo's error and there's nothing to confirm.
+    #[serde(skip)]
+    PreviewDryRun {
+        command: CargoCommand,
+        output: String,
+        failed: bool,
+    },
+    /// Ask before running a remove or uninstall given by name, as the `r` and `u` keys do for the
+    /// focused crate. A remove with `preview_remove` on is previewed instead.
+    #[serde(skip)]
+    ConfirmCargo(CargoCommand),
+    /// Run a previewed add/remove as confirmed, without asking for its package or previewing it
+    /// again.
+    #[serde(skip)]
+    RunPreviewed(CargoCommand),
 
     OpenDocs,
     OpenReadme,
-    RenderReadme(String),
+    /// Render the selected project dependency's README in the terminal, or open its repository
+    /// when that isn't possible.
+    RenderReadme,
     OpenCratesIo,
     OpenLibRs,
+    /// List every link of the focused crate to pick one to open.
+    OpenLinksMenu,
+    /// Open this URL, as picked from the links menu.
+    #[serde(skip)]
+    OpenLink(String),
+    /// Open the focused project dependency's source directory in `$EDITOR`.
+    OpenInEditor,
+    /// Copy the focused crate's `Cargo.toml` dependency line; `with_features` spells out its
+    /// default features.
+    CopyDependencySnippet {
+        with_features: bool,
+    },
+    /// Copy the focused crate's bare name.
+    CopyName,
+    /// Copy a `git clone` command for the focused crate's repository.
+    CopyCloneCommand,
+    /// Copy the value of the details line selected in [`Focusable::Details`].
+    CopyDetailField,
+    /// Format the focused crate's details as markdown and copy them, or write them to this path.
+    ExportMarkdown(Option<String>),
+    /// Copy the `cargo seek` command that starts the current search, with its scope and sort.
+    CopySearchCommand,
+    /// List the focused crate's related crates to pick one to look up.
+    ShowRelated,
+    /// Stage the focused crate for export at its shown version, or unstage it.
+    ToggleStaged,
+    /// Write a `cargo add` line for each staged crate to a shell script at this path.
+    #[serde(skip)]
+    ExportStaged(String),
+    /// Flip the results list between each crate's version and its newest one, pre-releases
+    /// included.
+    ToggleLatestVersions,
+    /// List the focused crate's direct dependencies, loading them first if need be.
+    ShowDependencies,
+    /// Look up the latest release of every installed binary and mark the outdated ones.
+    CheckBinaries,
+    /// Offer to update the binaries the last check found outdated, or check them first.
+    UpdateBinaries,
+    /// Show what pulls the focused project dependency in (`cargo tree -i`) in the pager.
+    ShowInverseTree,
+    /// Show how the focused dependency's features change between the project's version and the
+    /// latest one.
+    CompareFeatures,
+    /// Expand the details pane to the full width, or restore the columns.
+    ToggleDetailsZoom,
+    /// Show or hide each result's description under its name.
+    ToggleDescriptions,
+    /// Select the first crate in the page, for binding in the keymap. Like the vim keys' `gg`, it
+    /// only takes effect with `vim_keys` on and the results list focused.
+    GoToFirstResult,
 }
 
 /// The home (main) component.
 pub struct Home {
     config: Config,
+    mode: Mode,
     cargo_env: Arc<RwLock<CargoEnv>>,
     crate_search_manager: CrateSearchManager,
     left_column_width_percent: u16,
+    /// The column width to go back to when the details pane is zoomed to full width.
+    width_before_zoom: Option<u16>,
     show_help: bool,
     focused: Focusable,
     input: Input,
     sort: Sort,
     scope: Scope,
+    /// The crates.io username online results are filtered to, if any.
+    owner: Option<String>,
+    /// The crates.io keyword online results are filtered to, if any.
+    keyword: Option<String>,
+    /// Project results leave out dev and build dependencies.
+    normal_deps_only: bool,
+    /// The links online results must have.
+    link_filter: LinkFilter,
+    /// Crates staged with `s`, exported with `S`. Kept for the session, across searches.
+    staging: Staging,
     overlay: Option<Overlay>,
+    /// Set while the results list is in typeahead mode (entered with `/`).
+    typeahead: Option<Typeahead>,
+    /// With `vim_keys`, the results list got a `g` and the next one goes to the first result.
+    pending_g: bool,
     pending_cargo_request: Option<PendingCargoRequest>,
+    /// A [`SearchCommand::Refresh`] is waiting on the cargo environment to be re-read.
+    is_refreshing: bool,
     is_searching: bool,
+    /// The crate whose metadata is being fetched, from when the load is started until it lands or
+    /// fails. Animates the details header.
+    hydrating: Option<String>,
+    /// Set while the installed binaries are being checked for updates.
+    checking_binaries: bool,
+    /// The installed binaries the last check found a newer release of, until they're updated.
+    outdated_binaries: Vec<OutdatedBinary>,
+    /// The crate whose dependencies are loading to be shown, until they land.
+    awaiting_dependencies: Option<String>,
+    /// The term of the last search when it found crates online but none by exactly that name.
+    missing_exact_match: Option<String>,
+    /// The options of the search in flight, kept so a failure can be retried as-is.
+    last_search: Option<SearchOptions>,
+    /// The last search, when it failed; `Ctrl+r` re-issues it instead of refreshing.
+    failed_search: Option<SearchOptions>,
+    /// The results list shows each crate's newest version (`max_version`) rather than `version`.
+    /// Kept for the session, across searches.
+    show_latest_versions: bool,
+    /// Each result also shows the crate's description on a second line. Starts from
+    /// `list_show_description` and can be toggled at runtime.
+    show_descriptions: bool,
     search_results: Option<SearchResults>,
     spinner_state: throbber_widgets_tui::ThrobberState,
     action_tx: UnboundedSender<Action>,
+    clipboard: Clipboard,
     vertical_help_scroll: usize,
     max_help_scroll: usize,
+    /// The details line selected while [`Focusable::Details`] is focused, as an index into
+    /// [`Home::detail_fields`]. Clamped where it's used, as the focused crate may have fewer lines.
+    selected_field: usize,
 }
 
 impl Home {
     pub fn new(
-        initial_search_term: Option<String>,
+        config: &Config,
+        initial_search: InitialSearch,
         cargo_env: Arc<RwLock<CargoEnv>>,
         action_tx: UnboundedSender<Action>,
     ) -> AppResult<Self> {
-        let input = Input::default().with_value(initial_search_term.unwrap_or_default());
+        let input = Input::default().with_value(initial_search.term.unwrap_or_default());
+        let scope = initial_search
+            .scope
+            .unwrap_or_else(|| config.config.start_scope(!input.value().is_empty()));
 
         Ok(Self {
+            mode: Mode::default(),
             cargo_env,
             left_column_width_percent: 40,
+            width_before_zoom: None,
             show_help: true,
             focused: Focusable::default(),
             input,
-            sort: Sort::default(),
-            scope: Scope::default(),
+            sort: initial_search
+                .sort
+                .unwrap_or_else(|| config.config.default_sort.clone()),
+            scope,
+            owner: None,
+            keyword: None,
+            normal_deps_only: false,
+            link_filter: LinkFilter::default(),
+            staging: Staging::default(),
             overlay: None,
+            typeahead: None,
+            pending_g: false,
             pending_cargo_request: None,
+            is_refreshing: false,
             search_results: None,
-            crate_search_manager: CrateSearchManager::new(action_tx.clone())?,
+            crate_search_manager: CrateSearchManager::new(action_tx.clone(), &config.config)?,
             is_searching: false,
+            hydrating: None,
+            checking_binaries: false,
+            outdated_binaries: Vec::new(),
+            awaiting_dependencies: None,
+            missing_exact_match: None,
+            last_search: None,
+            failed_search: None,
+            show_latest_versions: false,
+            show_descriptions: config.config.list_show_description,
             spinner_state: throbber_widgets_tui::ThrobberState::default(),
             action_tx,
-            config: Config::default(),
+            clipboard: Clipboard::default(),
+            config: config.clone(),
             vertical_help_scroll: 0,
             max_help_scroll: 0,
+            selected_field: 0,
         })
     }
 
     fn reset(&mut self) -> AppResult<()> {
+        // Drop any in-flight search so it can't repopulate the cleared results or keep spinning.
+        self.crate_search_manager.cancel();
+        self.is_searching = false;
+        self.hydrating = None;
+        self.missing_exact_match = None;
+        self.awaiting_dependencies = None;
+        self.failed_search = None;
         self.input.reset();
         self.search_results = None;
+        self.typeahead = None;
         self.pending_cargo_request = None;
         self.action_tx
             .send(Action::Status(StatusCommand::ResetStatus))?;
@@ -160,8 +328,19 @@ impl Home {
         Ok(())
     }
 
+    /// The term the listed results were searched for. It differs from the search box once the box
+    /// is cleared (`Ctrl + u`) to type a new query, and paging or re-sorting must keep to the
+    /// results on screen.
+    fn results_term(&self) -> String {
+        self.last_search
+            .as_ref()
+            .and_then(|search| search.term.clone())
+            .unwrap_or_else(|| self.input.value().to_string())
+    }
+
     pub fn is_details_focused(&self) -> bool {
-        self.focused == Focusable::DocsButton
+        self.focused == Focusable::Details
+            || self.focused == Focusable::DocsButton
             || self.focused == Focusable::RepositoryButton
             || self.focused == Focusable::CratesIoButton
             || self.focused == Focusable::LibRsButton
@@ -171,6 +350,22 @@ impl Home {
         self.focused == Focusable::Results || self.is_details_focused()
     }
 
+    /// The details pane lines of the focused crate, as copied from [`Focusable::Details`].
+    fn detail_fields(&self) -> Vec<DetailField> {
+        self.get_focused_crate().map_or_else(Vec::new, |cr| {
+            detail_fields(
+                cr,
+                self.config.config.show_prereleases,
+                &self.config.config.date_format,
+            )
+        })
+    }
+
+    /// Index of the selected details line, kept within the focused crate's lines.
+    fn selected_field_index(&self, field_count: usize) -> usize {
+        self.selected_field.min(field_count.saturating_sub(1))
+    }
+
     fn get_focused_crate(&self) -> Option<&Crate> {
         if self.is_results_or_details_focused()
             && let Some(search_results) = self.search_results.as_ref()
@@ -182,6 +377,29 @@ impl Home {
         }
     }
 
+    /// Whether the selected crate's details are being fetched, which animates the details header.
+    fn is_loading_details(&self) -> bool {
+        let Some(hydrating) = self.hydrating.as_deref() else {
+            return false;
+        };
+        self.search_results
+            .as_ref()
+            .and_then(|results| results.selected())
+            .is_some_and(|cr| cr.name == hydrating && cr.is_metadata_loading())
+    }
+
+    /// Starts fetching the metadata of the crate named `name` (after a short delay with
+    /// `debounce`), superseding any load in flight.
+    fn start_hydrating(&mut self, name: &str, debounce: bool) {
+        if self
+            .crate_search_manager
+            .start_metadata_load(name, debounce)
+            .is_ok()
+        {
+            self.hydrating = Some(name.to_string());
+        }
+    }
+
     /// Reacts to the selected crate changing. Drops a deferred cargo request once the selection
     /// leaves the crate it was waiting on, and prefetches metadata for the newly selected crate
     /// when its features aren't known yet.
@@ -206,9 +424,7 @@ impl Home {
         if let Some((name, needs_metadata)) = selected
             && needs_metadata
         {
-            self.crate_search_manager
-                .start_metadata_load(&name, true)
-                .ok();
+            self.start_hydrating(&name, true);
         }
     }
 
@@ -265,6 +481,9 @@ impl Home {
 #[async_trait]
 impl Component for Home {
     fn register_config_handler(&mut self, config: Config) -> AppResult<()> {
+        if config.config.list_show_description != self.config.config.list_show_description {
+            self.show_descriptions = config.config.list_show_description;
+        }
         self.config = config;
         Ok(())
     }
@@ -282,17 +501,29 @@ impl Component for Home {
                     status: None,
                 }))
                 .ok();
+        } else if self.config.config.show_popular_on_start {
+            self.action_tx
+                .send(Action::Search(SearchCommand::ListPopular))
+                .ok();
         }
 
         Ok(())
     }
 
+    fn wants_raw_keys(&self) -> bool {
+        self.mode == Mode::Home && self.overlay.is_some()
+    }
+
     fn handle_key_event(&mut self, key: KeyEvent) -> AppResult<Option<Action>> {
+        if self.mode != Mode::Home {
+            return Ok(None);
+        }
         handle_key(self, key)
     }
 
     async fn update(&mut self, action: &Action, tui: &mut Tui) -> AppResult<Option<Action>> {
-        handle_action(self, action, tui).await
+        let _ = tui;
+        handle_action(self, action).await
     }
 
     fn draw(&mut self, mode: &Mode, frame: &mut Frame, area: Rect) -> AppResult<()> {
@@ -3,15 +3,40 @@ use ratatui::Frame;
 use ratatui::layout::Rect;
 
 use crate::action::Action;
+use crate::cargo::{CargoCommand, DependencySource, GitReference};
+use crate::components::home::HomeCommand;
+use crate::components::home::command_palette;
+use crate::components::home::dependency_list::DependencyList;
+use crate::components::home::dry_run_preview::DryRunPreview;
+use crate::components::home::feature_diff::FeatureDiffView;
 use crate::components::home::feature_selector::FeatureSelector;
-use crate::components::ux::{Confirm, Dropdown, KeyOutcome};
+use crate::components::home::links_menu::LinksMenu;
+use crate::components::home::package_picker::PackagePicker;
+use crate::components::home::related_menu::RelatedMenu;
+use crate::components::home::staging::DEFAULT_EXPORT_PATH;
+use crate::components::status_bar::{StatusCommand, StatusLevel};
+use crate::components::ux::{Confirm, Dropdown, KeyOutcome, Prompt};
 use crate::search::{Scope, SearchCommand, Sort};
 
 /// The one popup that can be open at a time over [`Home`](super::Home).
 pub enum Overlay {
     Sort(Dropdown<Sort>),
     Scope(Dropdown<Scope>),
+    Owner(Prompt),
+    Keyword(Prompt),
+    /// The command palette (`:`).
+    Command(Prompt),
+    /// Asks where to export the staged crates.
+    Export(Prompt),
+    /// Asks which branch or commit to add `command` (a git `Add`) at.
+    GitReference(Prompt, CargoCommand),
     Features(FeatureSelector),
+    Package(PackagePicker),
+    Links(LinksMenu),
+    Related(RelatedMenu),
+    DryRunPreview(DryRunPreview),
+    FeatureDiff(FeatureDiffView),
+    Dependencies(DependencyList),
     Confirm(Confirm, Action),
 }
 
@@ -25,7 +50,43 @@ impl Overlay {
             Overlay::Scope(dropdown) => dropdown
                 .handle_key(key)
                 .map(|scope| Action::Search(SearchCommand::Scope(scope))),
+            Overlay::Owner(prompt) => prompt.handle_key(key).map(|owner| {
+                Action::Search(SearchCommand::Owner(Some(owner).filter(|o| !o.is_empty())))
+            }),
+            Overlay::Keyword(prompt) => prompt.handle_key(key).map(|keyword| {
+                Action::Search(SearchCommand::Keyword(
+                    Some(keyword.trim().to_lowercase()).filter(|k| !k.is_empty()),
+                ))
+            }),
+            Overlay::Command(prompt) => prompt.handle_key(key).map(|line| {
+                command_palette::parse(&line).unwrap_or_else(|message| {
+                    Action::Status(StatusCommand::UpdateStatus(StatusLevel::Error, message))
+                })
+            }),
+            Overlay::Export(prompt) => prompt.handle_key(key).map(|path| {
+                let path = Some(path.trim().to_string()).filter(|p| !p.is_empty());
+                Action::Home(HomeCommand::ExportStaged(
+                    path.unwrap_or_else(|| DEFAULT_EXPORT_PATH.into()),
+                ))
+            }),
+            Overlay::GitReference(prompt, command) => prompt.handle_key(key).map(|reference| {
+                let mut command = command.clone();
+                if let CargoCommand::Add {
+                    source: DependencySource::Git { reference: r, .. },
+                    ..
+                } = &mut command
+                {
+                    *r = GitReference::parse(&reference);
+                }
+                Action::Cargo(command)
+            }),
             Overlay::Features(features) => features.handle_key(key),
+            Overlay::Package(picker) => picker.handle_key(key),
+            Overlay::Links(menu) => menu.handle_key(key),
+            Overlay::Related(menu) => menu.handle_key(key),
+            Overlay::DryRunPreview(preview) => preview.handle_key(key),
+            Overlay::FeatureDiff(diff) => diff.handle_key(key),
+            Overlay::Dependencies(list) => list.handle_key(key),
             Overlay::Confirm(affirm, action) => affirm.handle_key(key).map(|()| action.clone()),
         }
     }
@@ -34,7 +95,18 @@ impl Overlay {
         match self {
             Overlay::Sort(dropdown) => dropdown.draw(frame, area),
             Overlay::Scope(dropdown) => dropdown.draw(frame, area),
+            Overlay::Owner(prompt)
+            | Overlay::Keyword(prompt)
+            | Overlay::Command(prompt)
+            | Overlay::Export(prompt)
+            | Overlay::GitReference(prompt, _) => prompt.draw(frame, area),
             Overlay::Features(features) => features.draw(frame, area),
+            Overlay::Package(picker) => picker.draw(frame, area),
+            Overlay::Links(menu) => menu.draw(frame, area),
+            Overlay::Related(menu) => menu.draw(frame, area),
+            Overlay::DryRunPreview(preview) => preview.draw(frame, area),
+            Overlay::FeatureDiff(diff) => diff.draw(frame, area),
+            Overlay::Dependencies(list) => list.draw(frame, area),
             Overlay::Confirm(confirm, _) => confirm.draw(frame, area),
         }
     }
@@ -0,0 +1,132 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::widgets::{List, ListItem, ListState};
+
+use crate::action::Action;
+use crate::cargo::CargoCommand;
+use crate::components::home::HomeCommand;
+use crate::components::ux::{KeyOutcome, Popup};
+use crate::config::Config;
+
+/// A list of workspace members for the user to pick the target of an add/remove from.
+pub struct PackagePicker {
+    config: Config,
+    command: CargoCommand,
+    packages: Vec<String>,
+    state: ListState,
+}
+
+impl PackagePicker {
+    /// Builds a picker that completes `command` (an `Add` or `Remove`) with the chosen member.
+    pub fn new(config: Config, command: CargoCommand, packages: Vec<String>) -> Self {
+        Self {
+            config,
+            command,
+            packages,
+            state: ListState::default().with_selected(Some(0)),
+        }
+    }
+
+    fn title(&self) -> String {
+        match &self.command {
+            CargoCommand::Add { name, .. } => format!(" Add {name} to "),
+            CargoCommand::Remove { name, .. } => format!(" Remove {name} from "),
+            _ => " Package ".to_string(),
+        }
+    }
+
+    /// The command with its target set to the highlighted member, sent back through
+    /// [`HomeCommand::ChoosePackage`] so a targeted add still gets previewed.
+    fn command(&self) -> Action {
+        let chosen = self
+            .state
+            .selected()
+            .and_then(|ix| self.packages.get(ix))
+            .cloned();
+
+        let mut command = self.command.clone();
+        if let CargoCommand::Add { package, .. } | CargoCommand::Remove { package, .. } =
+            &mut command
+        {
+            *package = chosen;
+        }
+        Action::Home(HomeCommand::ChoosePackage(command))
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> KeyOutcome<Action> {
+        match key.code {
+            KeyCode::Esc => return KeyOutcome::Cancelled,
+            KeyCode::Enter => return KeyOutcome::Submitted(self.command()),
+            KeyCode::Up => {
+                let prev = self.state.selected().map_or(0, |i| i.saturating_sub(1));
+                self.state.select(Some(prev));
+            }
+            KeyCode::Down => {
+                let last = self.packages.len().saturating_sub(1);
+                let next = self.state.selected().map_or(0, |i| (i + 1).min(last));
+                self.state.select(Some(next));
+            }
+            _ => {}
+        }
+        KeyOutcome::Pending
+    }
+
+    pub fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        let inner_height =
+            (self.packages.len() as u16).clamp(1, area.height.saturating_sub(4).max(1));
+
+        let inner = Popup::new(45.min(area.width), inner_height + 2)
+            .title(self.title())
+            .footer(" Enter confirm · Esc cancel ")
+            .border_style(self.config.theme.accent)
+            .render(frame, area);
+
+        let list = List::new(
+            self.packages
+                .iter()
+                .map(|package| ListItem::new(package.as_str())),
+        )
+        .highlight_style(self.config.theme.accent.bold())
+        .highlight_symbol("▶ ");
+
+        frame.render_stateful_widget(list, inner, &mut self.state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use pretty_assertions::assert_eq;
+
+    fn press(picker: &mut PackagePicker, code: KeyCode) -> KeyOutcome<Action> {
+        picker.handle_key(KeyEvent::new(code, KeyModifiers::empty()))
+    }
+
+    #[test]
+    fn enter_targets_the_highlighted_member() {
+        let command = CargoCommand::Remove {
+            name: "serde".into(),
+            package: None,
+        };
+        let mut picker = PackagePicker::new(
+            Config::default(),
+            command,
+            vec!["app".into(), "core".into()],
+        );
+
+        press(&mut picker, KeyCode::Down);
+        press(&mut picker, KeyCode::Down);
+        match press(&mut picker, KeyCode::Enter) {
+            KeyOutcome::Submitted(Action::Home(HomeCommand::ChoosePackage(command))) => assert_eq!(
+                command,
+                CargoCommand::Remove {
+                    name: "serde".into(),
+                    package: Some("core".into()),
+                }
+            ),
+            other => panic!("expected a targeted Remove, got {other:?}"),
+        }
+    }
+}
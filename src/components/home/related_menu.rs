@@ -0,0 +1,102 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::text::Line;
+use ratatui::widgets::{List, ListItem, ListState};
+
+use crate::action::Action;
+use crate::components::ux::{KeyOutcome, Popup};
+use crate::config::Config;
+use crate::search::SearchCommand;
+
+/// A list of the crates related to a crate (see [`Crate::related`](crate::search::Crate)),
+/// looking up the chosen one.
+pub struct RelatedMenu {
+    config: Config,
+    title: String,
+    related: Vec<String>,
+    state: ListState,
+}
+
+impl RelatedMenu {
+    pub fn new(config: Config, crate_name: &str, related: &[String]) -> Self {
+        Self {
+            config,
+            title: format!(" Related to {crate_name} "),
+            related: related.to_vec(),
+            state: ListState::default().with_selected(Some(0)),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> KeyOutcome<Action> {
+        match key.code {
+            KeyCode::Esc => return KeyOutcome::Cancelled,
+            KeyCode::Enter => {
+                return match self.state.selected().and_then(|ix| self.related.get(ix)) {
+                    Some(name) => KeyOutcome::Submitted(Action::Search(SearchCommand::Run {
+                        term: format!("={name}"),
+                        page: 1,
+                        hide_help: true,
+                        status: None,
+                    })),
+                    None => KeyOutcome::Cancelled,
+                };
+            }
+            KeyCode::Up => {
+                let prev = self.state.selected().map_or(0, |i| i.saturating_sub(1));
+                self.state.select(Some(prev));
+            }
+            KeyCode::Down => {
+                let last = self.related.len().saturating_sub(1);
+                let next = self.state.selected().map_or(0, |i| (i + 1).min(last));
+                self.state.select(Some(next));
+            }
+            _ => {}
+        }
+        KeyOutcome::Pending
+    }
+
+    pub fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = Popup::new(40.min(area.width), self.related.len() as u16 + 2)
+            .title(self.title.clone())
+            .footer(" Enter search · Esc cancel ")
+            .border_style(self.config.theme.accent)
+            .render(frame, area);
+
+        let list = List::new(
+            self.related
+                .iter()
+                .map(|name| ListItem::new(Line::from(name.as_str()))),
+        )
+        .highlight_style(self.config.theme.accent.bold())
+        .highlight_symbol("▶ ");
+
+        frame.render_stateful_widget(list, inner, &mut self.state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use pretty_assertions::assert_eq;
+
+    fn press(menu: &mut RelatedMenu, code: KeyCode) -> KeyOutcome<Action> {
+        menu.handle_key(KeyEvent::new(code, KeyModifiers::empty()))
+    }
+
+    #[test]
+    fn enter_looks_up_the_highlighted_crate() {
+        let related = vec!["tokio".to_string(), "async-std".to_string()];
+        let mut menu = RelatedMenu::new(Config::default(), "smol", &related);
+
+        press(&mut menu, KeyCode::Down);
+        press(&mut menu, KeyCode::Down);
+        match press(&mut menu, KeyCode::Enter) {
+            KeyOutcome::Submitted(Action::Search(SearchCommand::Run { term, .. })) => {
+                assert_eq!(term, "=async-std")
+            }
+            other => panic!("expected a search, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Crates staged with `s` to be exported together as a script of `cargo add` lines, e.g. to set up
+//! the same dependencies elsewhere.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Where staged crates are exported when no other path is given.
+pub const DEFAULT_EXPORT_PATH: &str = "cargo-add.sh";
+
+/// A crate staged for adding, at the version it was shown at when staged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StagedCrate {
+    pub name: String,
+    pub version: String,
+}
+
+/// The staged crates, in the order they were staged.
+#[derive(Debug, Default)]
+pub struct Staging {
+    crates: Vec<StagedCrate>,
+}
+
+impl Staging {
+    /// Stages `name` at `version`, or unstages it when it already is. Returns whether it's now
+    /// staged.
+    pub fn toggle(&mut self, name: &str, version: &str) -> bool {
+        if let Some(ix) = self.crates.iter().position(|cr| cr.name == name) {
+            self.crates.remove(ix);
+            return false;
+        }
+        self.crates.push(StagedCrate {
+            name: name.to_string(),
+            version: version.to_string(),
+        });
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.crates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.crates.is_empty()
+    }
+
+    /// A shell script running `cargo add` for each staged crate, pinned to its staged version.
+    pub fn script(&self) -> String {
+        let mut script = String::from("#!/bin/sh\n# Exported from cargo-seek\nset -e\n\n");
+        for cr in &self.crates {
+            if cr.version.is_empty() {
+                script.push_str(&format!("cargo add {}\n", cr.name));
+            } else {
+                script.push_str(&format!("cargo add {}@{}\n", cr.name, cr.version));
+            }
+        }
+        script
+    }
+
+    /// Writes [`Staging::script`] to `path`, made executable where that applies.
+    pub fn export(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.script())?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(path, fs::Permissions::from_mode(0o755))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn toggling_stages_and_unstages() {
+        let mut staging = Staging::default();
+        assert!(staging.toggle("serde", "1.0.210"));
+        assert!(staging.toggle("tokio", "1.40.0"));
+        assert!(!staging.toggle("serde", "1.0.210"));
+        assert_eq!(staging.len(), 1);
+    }
+
+    #[test]
+    fn script_pins_each_staged_version() {
+        let mut staging = Staging::default();
+        staging.toggle("serde", "1.0.210");
+        staging.toggle("anyhow", "");
+        assert_eq!(
+            staging.script(),
+            "#!/bin/sh\n# Exported from cargo-seek\nset -e\n\ncargo add serde@1.0.210\ncargo add anyhow\n"
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Typeahead in the results list: entered with `/` while the list is focused, typed letters then
+//! jump to the next crate whose name starts with them instead of triggering the single-letter
+//! actions.
+
+use std::time::{Duration, Instant};
+
+use crate::search::Crate;
+
+/// How long the list may sit idle before the next letter starts a new prefix.
+const RESET_AFTER: Duration = Duration::from_millis(1000);
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Typeahead {
+    prefix: String,
+    last_key: Option<Instant>,
+}
+
+impl Typeahead {
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Appends `c` to the prefix typed at `now`, starting over if the list was left idle.
+    pub fn push(&mut self, c: char, now: Instant) {
+        if self
+            .last_key
+            .is_some_and(|last| now.duration_since(last) > RESET_AFTER)
+        {
+            self.prefix.clear();
+        }
+        self.prefix.push(c);
+        self.last_key = Some(now);
+    }
+
+    pub fn pop(&mut self, now: Instant) {
+        self.prefix.pop();
+        self.last_key = Some(now);
+    }
+
+    /// The index of the first crate from `start` onwards (wrapping around) whose name starts with
+    /// the prefix, ignoring case and treating `-` and `_` alike, as crates.io does.
+    pub fn find(&self, crates: &[Crate], start: usize) -> Option<usize> {
+        if self.prefix.is_empty() || crates.is_empty() {
+            return None;
+        }
+        let prefix = normalize(&self.prefix);
+        let start = start.min(crates.len() - 1);
+        (start..crates.len())
+            .chain(0..start)
+            .find(|&ix| normalize(&crates[ix].name).starts_with(&prefix))
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.to_lowercase().replace('-', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn crates(names: &[&str]) -> Vec<Crate> {
+        names
+            .iter()
+            .map(|name| Crate {
+                name: name.to_string(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    fn typed(text: &str, now: Instant) -> Typeahead {
+        let mut typeahead = Typeahead::default();
+        text.chars().for_each(|c| typeahead.push(c, now));
+        typeahead
+    }
+
+    #[test]
+    fn find_starts_at_the_selection_and_wraps_around() {
+        let crates = crates(&["serde", "tokio", "serde_json", "Tracing"]);
+        let now = Instant::now();
+        assert_eq!(typed("se", now).find(&crates, 1), Some(2));
+        assert_eq!(typed("se", now).find(&crates, 3), Some(0));
+        assert_eq!(typed("tr", now).find(&crates, 0), Some(3));
+        assert_eq!(typed("serde-j", now).find(&crates, 0), Some(2));
+        assert_eq!(typed("x", now).find(&crates, 0), None);
+    }
+
+    #[test]
+    fn the_prefix_resets_after_an_idle_pause() {
+        let now = Instant::now();
+        let mut typeahead = typed("se", now);
+        typeahead.push('r', now + Duration::from_millis(500));
+        assert_eq!(typeahead.prefix(), "ser");
+        typeahead.push('t', now + Duration::from_secs(3));
+        assert_eq!(typeahead.prefix(), "t");
+    }
+}
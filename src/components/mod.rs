@@ -6,6 +6,7 @@
 pub mod app_id;
 pub mod fps;
 pub mod home;
+pub mod settings;
 pub mod status_bar;
 pub mod ux;
 
@@ -129,6 +130,12 @@ pub trait Component: Send + Sync {
     /// * `Result<()>` - An Ok result or an error.
     fn draw(&mut self, mode: &Mode, frame: &mut Frame, area: Rect) -> AppResult<()>;
 
+    /// Whether the component wants the keys its mode's keybindings would take, e.g. while it has a
+    /// prompt open. App-wide keybindings still apply.
+    fn wants_raw_keys(&self) -> bool {
+        false
+    }
+
     /// Where this component should be drawn. Defaults to the main content area.
     fn placement(&self) -> Placement {
         Placement::Main
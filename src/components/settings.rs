@@ -0,0 +1,395 @@
+//! The settings screen — toggles a handful of config values at runtime. Changes are broadcast as
+//! [`Action::ConfigChanged`] so every component picks them up; nothing is written to disk.
+//!
+//! It also shows the effective (merged) config read-only, and can open the config file in the
+//! editor, reloading it afterwards (see [`Action::EditConfig`]).
+
+use async_trait::async_trait;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph};
+use strum::{EnumCount, FromRepr};
+
+use crate::action::Action;
+use crate::app::Mode;
+use crate::components::Component;
+use crate::components::status_bar::{StatusCommand, StatusLevel};
+use crate::components::ux::Icon;
+use crate::config::{Config, get_config_file, theme_names};
+use crate::errors::AppResult;
+use crate::tui::Tui;
+
+/// Accent colors offered by the settings screen, as `(name, accent, accent_active)`.
+const ACCENT_COLORS: [(&str, Color, Color); 6] = [
+    ("yellow", Color::Indexed(3), Color::LightYellow),
+    ("cyan", Color::Indexed(6), Color::LightCyan),
+    ("green", Color::Indexed(2), Color::LightGreen),
+    ("magenta", Color::Indexed(5), Color::LightMagenta),
+    ("blue", Color::Indexed(4), Color::LightBlue),
+    ("red", Color::Indexed(1), Color::LightRed),
+];
+
+/// One editable row on the settings screen, in display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumCount, FromRepr)]
+#[repr(usize)]
+enum Setting {
+    OpenLinksInBrowser,
+    Theme,
+    AccentColor,
+}
+
+/// The settings component, drawn in place of [`Home`](super::home::Home) in [`Mode::Settings`].
+pub struct Settings {
+    config: Config,
+    mode: Mode,
+    state: ListState,
+    /// The themes to switch between, re-read each time the screen opens so new theme files show up.
+    themes: Vec<String>,
+    /// The effective config is shown instead of the settings list.
+    show_effective_config: bool,
+    config_scroll: u16,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Settings {
+    pub fn new() -> Self {
+        Self {
+            config: Config::default(),
+            mode: Mode::default(),
+            state: ListState::default().with_selected(Some(0)),
+            themes: theme_names(),
+            show_effective_config: false,
+            config_scroll: 0,
+        }
+    }
+
+    fn selected(&self) -> Setting {
+        self.state
+            .selected()
+            .and_then(Setting::from_repr)
+            .unwrap_or(Setting::OpenLinksInBrowser)
+    }
+
+    fn select(&mut self, delta: isize) {
+        let ix = self.state.selected().unwrap_or(0) as isize + delta;
+        self.state
+            .select(Some(ix.clamp(0, Setting::COUNT as isize - 1) as usize));
+    }
+
+    /// Index into [`ACCENT_COLORS`] of the current accent, or `None` for a custom color from the
+    /// config file.
+    fn accent_index(&self) -> Option<usize> {
+        ACCENT_COLORS
+            .iter()
+            .position(|(_, accent, _)| self.config.theme.accent.fg == Some(*accent))
+    }
+
+    /// Changes the selected setting, stepping by `delta` where the setting has more than two values.
+    /// Returns the updated config to broadcast.
+    fn change(&mut self, delta: isize) -> Action {
+        let mut config = self.config.clone();
+        match self.selected() {
+            Setting::OpenLinksInBrowser => {
+                config.config.open_links_in_browser = !config.config.open_links_in_browser;
+            }
+            Setting::Theme => {
+                let len = self.themes.len() as isize;
+                let next = match self.themes.iter().position(|t| *t == config.config.theme) {
+                    Some(ix) => (ix as isize + delta).rem_euclid(len) as usize,
+                    None => 0,
+                };
+                if let Err(err) = config.set_theme(&self.themes[next]) {
+                    return Action::Status(StatusCommand::UpdateStatus(
+                        StatusLevel::Error,
+                        format!("Couldn't load theme {:?}: {err}", self.themes[next]),
+                    ));
+                }
+            }
+            Setting::AccentColor => {
+                let len = ACCENT_COLORS.len() as isize;
+                let next = match self.accent_index() {
+                    Some(ix) => (ix as isize + delta).rem_euclid(len) as usize,
+                    None => 0,
+                };
+                let (_, accent, accent_active) = ACCENT_COLORS[next];
+                config.theme.accent = config.theme.accent.fg(accent);
+                config.theme.accent_active = config.theme.accent_active.fg(accent_active);
+            }
+        }
+        Action::ConfigChanged(Box::new(config))
+    }
+
+    fn handle_effective_config_key(&mut self, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('c') => self.show_effective_config = false,
+            KeyCode::Char('e') => return Some(Action::EditConfig),
+            KeyCode::Up => self.config_scroll = self.config_scroll.saturating_sub(1),
+            KeyCode::Down => self.config_scroll = self.config_scroll.saturating_add(1),
+            KeyCode::PageUp => self.config_scroll = self.config_scroll.saturating_sub(10),
+            KeyCode::PageDown => self.config_scroll = self.config_scroll.saturating_add(10),
+            KeyCode::Home => self.config_scroll = 0,
+            _ => {}
+        }
+        None
+    }
+
+    fn draw_effective_config(&mut self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .title(Icon::Settings.label("Effective config", self.config.config.ascii_icons))
+            .title_style(self.config.theme.title)
+            .title_bottom(Line::from(" ↑ ↓ scroll · e edit file · Esc back ").centered())
+            .padding(Padding::horizontal(1))
+            .borders(Borders::ALL)
+            .border_style(self.config.theme.accent_active);
+
+        let mut lines = vec![
+            Line::from(vec![
+                "Config file: ".into(),
+                Span::styled(
+                    get_config_file().display().to_string(),
+                    self.config.theme.accent,
+                ),
+            ]),
+            Line::default(),
+        ];
+        lines.extend(
+            self.config
+                .to_effective_json()
+                .lines()
+                .map(|line| Line::from(line.to_string())),
+        );
+
+        let visible = block.inner(area).height as usize;
+        let max_scroll = lines.len().saturating_sub(visible) as u16;
+        self.config_scroll = self.config_scroll.min(max_scroll);
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .scroll((self.config_scroll, 0)),
+            area,
+        );
+    }
+
+    fn row(&self, setting: Setting) -> Line<'static> {
+        const PAD: usize = 28;
+        let (label, value) = match setting {
+            Setting::OpenLinksInBrowser => (
+                "Open links in browser",
+                if self.config.config.open_links_in_browser {
+                    "[x]".to_string()
+                } else {
+                    "[ ]".to_string()
+                },
+            ),
+            Setting::Theme => ("Theme", format!("◀ {} ▶", self.config.config.theme)),
+            Setting::AccentColor => (
+                "Accent color",
+                format!(
+                    "◀ {} ▶",
+                    self.accent_index()
+                        .map_or("custom", |ix| ACCENT_COLORS[ix].0)
+                ),
+            ),
+        };
+        Line::from(vec![
+            format!("{label:<PAD$}").into(),
+            Span::styled(value, self.config.theme.accent),
+        ])
+    }
+}
+
+#[async_trait]
+impl Component for Settings {
+    fn register_config_handler(&mut self, config: Config) -> AppResult<()> {
+        self.config = config;
+        Ok(())
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent) -> AppResult<Option<Action>> {
+        if self.mode != Mode::Settings {
+            return Ok(None);
+        }
+
+        if self.show_effective_config {
+            return Ok(self.handle_effective_config_key(key));
+        }
+
+        let action = match key.code {
+            KeyCode::Esc => Some(Action::SwitchMode(Mode::Home)),
+            KeyCode::Char('c') => {
+                self.show_effective_config = true;
+                self.config_scroll = 0;
+                None
+            }
+            KeyCode::Char('e') => Some(Action::EditConfig),
+            KeyCode::Up => {
+                self.select(-1);
+                None
+            }
+            KeyCode::Down => {
+                self.select(1);
+                None
+            }
+            KeyCode::Left => Some(self.change(-1)),
+            KeyCode::Right | KeyCode::Enter | KeyCode::Char(' ') => Some(self.change(1)),
+            _ => None,
+        };
+        Ok(action)
+    }
+
+    async fn update(&mut self, action: &Action, tui: &mut Tui) -> AppResult<Option<Action>> {
+        let _ = tui;
+        if let Action::SwitchMode(mode) = action {
+            self.mode = *mode;
+            if self.mode == Mode::Settings {
+                self.themes = theme_names();
+            }
+        }
+        Ok(None)
+    }
+
+    fn draw(&mut self, mode: &Mode, frame: &mut Frame, area: Rect) -> AppResult<()> {
+        if *mode != Mode::Settings {
+            return Ok(());
+        }
+        if self.show_effective_config {
+            self.draw_effective_config(frame, area);
+            return Ok(());
+        }
+
+        let block = Block::default()
+            .title(Icon::Settings.label("Settings", self.config.config.ascii_icons))
+            .title_style(self.config.theme.title)
+            .title_bottom(
+                Line::from(
+                    " ↑ ↓ select · Space/← → change · c show config · e edit file · Esc back ",
+                )
+                .centered(),
+            )
+            .padding(Padding::uniform(1))
+            .borders(Borders::ALL)
+            .border_style(self.config.theme.accent_active);
+
+        let items: Vec<ListItem> = (0..Setting::COUNT)
+            .filter_map(Setting::from_repr)
+            .map(|setting| ListItem::new(self.row(setting)))
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().bold())
+            .highlight_symbol("▶ ");
+
+        frame.render_stateful_widget(list, area, &mut self.state);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use pretty_assertions::assert_eq;
+
+    fn settings() -> Settings {
+        let mut settings = Settings::new();
+        settings.mode = Mode::Settings;
+        settings
+    }
+
+    fn press(settings: &mut Settings, code: KeyCode) -> Option<Action> {
+        settings
+            .handle_key_event(KeyEvent::new(code, KeyModifiers::empty()))
+            .unwrap()
+    }
+
+    fn changed(action: Option<Action>) -> Config {
+        match action {
+            Some(Action::ConfigChanged(config)) => *config,
+            other => panic!("expected ConfigChanged, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn keys_are_ignored_outside_settings_mode() {
+        let mut settings = Settings::new();
+        assert!(press(&mut settings, KeyCode::Enter).is_none());
+    }
+
+    #[test]
+    fn toggles_open_links_in_browser() {
+        let mut settings = settings();
+        let config = changed(press(&mut settings, KeyCode::Char(' ')));
+        assert!(!config.config.open_links_in_browser);
+    }
+
+    #[test]
+    fn cycles_the_theme_both_ways() {
+        let mut settings = settings();
+        settings.themes = vec!["dark".into(), "light".into()];
+        press(&mut settings, KeyCode::Down);
+
+        let config = changed(press(&mut settings, KeyCode::Right));
+        assert_eq!(config.config.theme, "light");
+
+        settings.register_config_handler(config).unwrap();
+        let config = changed(press(&mut settings, KeyCode::Right));
+        assert_eq!(config.config.theme, "dark");
+
+        settings.register_config_handler(config).unwrap();
+        let config = changed(press(&mut settings, KeyCode::Left));
+        assert_eq!(config.config.theme, "light");
+    }
+
+    #[test]
+    fn cycles_the_accent_color_both_ways() {
+        let mut settings = settings();
+        press(&mut settings, KeyCode::Down);
+        press(&mut settings, KeyCode::Down);
+
+        let config = changed(press(&mut settings, KeyCode::Right));
+        assert_eq!(config.theme.accent.fg, Some(ACCENT_COLORS[0].1));
+
+        settings.register_config_handler(config).unwrap();
+        let config = changed(press(&mut settings, KeyCode::Left));
+        assert_eq!(config.theme.accent.fg, Some(ACCENT_COLORS[5].1));
+    }
+
+    #[test]
+    fn effective_config_view_opens_with_c_and_closes_with_esc() {
+        let mut settings = settings();
+        assert!(press(&mut settings, KeyCode::Char('c')).is_none());
+        assert!(settings.show_effective_config);
+
+        // Esc leaves the config view, not the settings screen.
+        assert!(press(&mut settings, KeyCode::Esc).is_none());
+        assert!(!settings.show_effective_config);
+    }
+
+    #[test]
+    fn e_edits_the_config_file() {
+        let mut settings = settings();
+        assert!(matches!(
+            press(&mut settings, KeyCode::Char('e')),
+            Some(Action::EditConfig)
+        ));
+    }
+
+    #[test]
+    fn esc_returns_home() {
+        let mut settings = settings();
+        assert!(matches!(
+            press(&mut settings, KeyCode::Esc),
+            Some(Action::SwitchMode(Mode::Home))
+        ));
+    }
+}
@@ -12,6 +12,9 @@ use tokio::sync::oneshot;
 
 use crate::action::Action;
 use crate::app::Mode;
+use crate::components::home::HomeCommand;
+use crate::components::home::focusable::Focusable;
+use crate::components::ux::Icon;
 use crate::components::{Component, Placement};
 use crate::config::Config;
 use crate::errors::AppResult;
@@ -52,11 +55,65 @@ struct StatusMessage {
     message: String,
 }
 
+/// The key hints shown on the right of the status bar, as `(key, description)`, for what's focused.
+/// `help_key` is the key bound to toggling help, if any; its hint is left out without one.
+fn key_hints(
+    mode: &Mode,
+    focused: Focusable,
+    help_key: Option<&str>,
+) -> Vec<(String, &'static str)> {
+    if *mode == Mode::Settings {
+        return vec![("esc".into(), "back")];
+    }
+    let (hints, help): (&[(&str, &'static str)], _) = match focused {
+        Focusable::Search => (&[("enter", "search"), ("↓", "results")], Some("help")),
+        Focusable::Results => (
+            &[
+                ("a/r", "add/remove"),
+                ("i/u", "install/uninstall"),
+                ("enter", "links"),
+                ("←→", "page"),
+                ("/", "jump"),
+            ],
+            Some("help"),
+        ),
+        Focusable::Help => (&[("↑↓", "scroll"), ("tab", "next")], Some("hide")),
+        Focusable::Details => (
+            &[("↑↓", "select"), ("enter", "copy"), ("esc", "search")],
+            None,
+        ),
+        Focusable::DocsButton
+        | Focusable::RepositoryButton
+        | Focusable::CratesIoButton
+        | Focusable::LibRsButton => (
+            &[("↑↓←→", "move"), ("enter", "open"), ("esc", "search")],
+            None,
+        ),
+    };
+    hints
+        .iter()
+        .map(|(key, description)| (key.to_string(), *description))
+        .chain(
+            help.zip(help_key)
+                .map(|(description, key)| (key.to_string(), description)),
+        )
+        .collect()
+}
+
+/// How the status bar spells a key from the keybinding reference, e.g. `Ctrl + h` as `ctrl+h`.
+fn hint_key(key: &str) -> String {
+    key.to_lowercase().replace(" + ", "+")
+}
+
 /// The statusbar component.
 pub struct StatusBar {
     status: Option<StatusMessage>,
+    /// What's focused on the home screen, tracked from [`HomeCommand::Focus`] to pick the hints.
+    focused: Focusable,
     last_annoying: Option<StatusMessage>,
     config: Config,
+    /// The key toggling help is bound to on the home screen, as hinted.
+    help_key: Option<String>,
     cancel_tx: Option<oneshot::Sender<()>>,
     action_tx: UnboundedSender<Action>,
 }
@@ -65,8 +122,10 @@ impl StatusBar {
     pub fn new(action_tx: UnboundedSender<Action>) -> Self {
         StatusBar {
             status: None,
+            focused: Focusable::default(),
             last_annoying: None,
             config: Config::default(),
+            help_key: None,
             cancel_tx: None,
             action_tx,
         }
@@ -161,6 +220,11 @@ impl StatusBar {
 #[async_trait]
 impl Component for StatusBar {
     fn register_config_handler(&mut self, config: Config) -> AppResult<()> {
+        self.help_key = config
+            .keybindings
+            .keys_for(Mode::Home, &Action::Home(HomeCommand::ToggleHelp))
+            .first()
+            .map(|key| hint_key(key));
         self.config = config;
         Ok(())
     }
@@ -193,33 +257,37 @@ impl Component for StatusBar {
             Action::Status(StatusCommand::ResetStatus) => {
                 self.info("Ready");
             }
+            Action::Home(HomeCommand::Focus(focused)) => self.focused = *focused,
             _ => {}
         };
 
         Ok(None)
     }
 
-    fn draw(&mut self, _: &Mode, frame: &mut Frame, area: Rect) -> AppResult<()> {
+    fn draw(&mut self, mode: &Mode, frame: &mut Frame, area: Rect) -> AppResult<()> {
         let accent = self.config.theme.accent;
-        let text = vec![
-            "/: ".set_style(accent),
-            "search".into(),
-            "  ".into(),
-            "ctrl+h: ".set_style(accent),
-            "help".into(),
-        ];
-        let text_length = text.iter().map(|x| x.content.len()).sum::<usize>();
+        let mut text = Vec::new();
+        let hints = key_hints(mode, self.focused, self.help_key.as_deref());
+        for (ix, (key, description)) in hints.iter().enumerate() {
+            if ix > 0 {
+                text.push("  ".into());
+            }
+            text.push(format!("{key}: ").set_style(accent));
+            text.push((*description).into());
+        }
+        let text_length = text.iter().map(|x| x.width()).sum::<usize>();
 
         let [left, right] =
             Layout::horizontal([Constraint::Min(1), Constraint::Length(text_length as u16)])
                 .areas(area);
 
         if let Some(status) = &self.status {
+            let ascii = self.config.config.ascii_icons;
             let icon = match status.level {
-                StatusLevel::Info => "ℹ️".cyan(),
-                StatusLevel::Progress => "⏳".yellow(),
-                StatusLevel::Success => "✅".green(),
-                StatusLevel::Error => "❌".red(),
+                StatusLevel::Info => Icon::Info.glyph(ascii).cyan(),
+                StatusLevel::Progress => Icon::Progress.glyph(ascii).yellow(),
+                StatusLevel::Success => Icon::Success.glyph(ascii).green(),
+                StatusLevel::Error => Icon::Error.glyph(ascii).red(),
             };
 
             let text = Text::from(Line::from(vec![
@@ -242,3 +310,61 @@ impl Component for StatusBar {
         Placement::StatusBar
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use pretty_assertions::assert_eq;
+
+    fn keys(mode: Mode, focused: Focusable) -> Vec<String> {
+        key_hints(&mode, focused, Some("ctrl+h"))
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    #[test]
+    fn hints_follow_the_focus() {
+        assert_eq!(
+            keys(Mode::Home, Focusable::Search),
+            vec!["enter", "↓", "ctrl+h"]
+        );
+        assert!(keys(Mode::Home, Focusable::Results).contains(&"a/r".to_string()));
+        assert!(keys(Mode::Home, Focusable::LibRsButton).contains(&"enter".to_string()));
+    }
+
+    #[test]
+    fn settings_only_hints_going_back() {
+        assert_eq!(keys(Mode::Settings, Focusable::Results), vec!["esc"]);
+    }
+
+    #[test]
+    fn help_hint_follows_the_keybinding() -> AppResult<()> {
+        let mut config = Config::new()?;
+        let mut status_bar = StatusBar::new(tokio::sync::mpsc::unbounded_channel().0);
+        status_bar.register_config_handler(config.clone())?;
+        assert_eq!(status_bar.help_key.as_deref(), Some("ctrl+h"));
+
+        let home = config.keybindings.get_mut(&Mode::Home).unwrap();
+        home.retain(|_, action| !matches!(action, Action::Home(HomeCommand::ToggleHelp)));
+        home.insert(
+            vec![KeyEvent::new(KeyCode::Char('h'), KeyModifiers::ALT)],
+            Action::Home(HomeCommand::ToggleHelp),
+        );
+        status_bar.register_config_handler(config.clone())?;
+        assert_eq!(status_bar.help_key.as_deref(), Some("alt+h"));
+
+        config
+            .keybindings
+            .get_mut(&Mode::Home)
+            .unwrap()
+            .retain(|_, action| !matches!(action, Action::Home(HomeCommand::ToggleHelp)));
+        status_bar.register_config_handler(config)?;
+        assert_eq!(
+            key_hints(&Mode::Home, Focusable::Help, status_bar.help_key.as_deref()),
+            vec![("↑↓".to_string(), "scroll"), ("tab".to_string(), "next")]
+        );
+        Ok(())
+    }
+}
@@ -100,6 +100,15 @@ pub const PURPLE: Theme = Theme {
     shadow: Color::Rgb(64, 32, 96),
 };
 
+/// Uses the terminal's own colors, for the monochrome theme. A selected button is still marked by
+/// its underline.
+pub const MONOCHROME: Theme = Theme {
+    text: Color::Reset,
+    background: Color::Reset,
+    highlight: Color::Reset,
+    shadow: Color::Reset,
+};
+
 impl<'a> Button<'a> {
     pub fn new<T: Into<Line<'a>>>(label: T) -> Self {
         Button {
@@ -114,6 +123,14 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Replaces the theme with [`MONOCHROME`] when `monochrome` is set.
+    pub const fn monochrome(mut self, monochrome: bool) -> Self {
+        if monochrome {
+            self.theme = MONOCHROME;
+        }
+        self
+    }
+
     pub const fn state(mut self, state: State) -> Self {
         self.state = state;
         self
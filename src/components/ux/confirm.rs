@@ -94,6 +94,7 @@ impl Confirm {
         frame.render_widget(
             Button::new("Cancel")
                 .theme(super::button::GRAY)
+                .monochrome(self.config.theme.monochrome)
                 .state(if self.selected == 0 {
                     State::Selected
                 } else {
@@ -104,6 +105,7 @@ impl Confirm {
         frame.render_widget(
             Button::new("Yes")
                 .theme(super::button::RED)
+                .monochrome(self.config.theme.monochrome)
                 .state(if self.selected == 1 {
                     State::Selected
                 } else {
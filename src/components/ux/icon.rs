@@ -0,0 +1,70 @@
+use std::fmt::Display;
+
+/// An icon drawn in a title or the status bar. Each has an emoji and, for `ascii_icons`, a plain
+/// ASCII stand-in for terminals that draw emoji as boxes or at the wrong width. Purely decorative
+/// icons have no stand-in and are left out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Icon {
+    Info,
+    Progress,
+    Success,
+    Error,
+    Package,
+    Project,
+    Details,
+    Help,
+    Settings,
+    Owner,
+    Keyword,
+    Link,
+}
+
+impl Icon {
+    pub const fn glyph(self, ascii: bool) -> &'static str {
+        match (self, ascii) {
+            (Icon::Info, false) => "ℹ️",
+            (Icon::Info, true) => "[i]",
+            (Icon::Progress, false) => "⏳",
+            (Icon::Progress, true) => "[..]",
+            (Icon::Success, false) => "✅",
+            (Icon::Success, true) => "[ok]",
+            (Icon::Error, false) => "❌",
+            (Icon::Error, true) => "[x]",
+            (Icon::Package, false) => "📦",
+            (Icon::Project, false) => "📁",
+            (Icon::Details, false) => "🧐",
+            (Icon::Help, false) => "📖",
+            (Icon::Settings, false) => "⚙️",
+            (Icon::Owner, false) => "👤",
+            (Icon::Owner, true) => "owner:",
+            (Icon::Keyword, false) => "🔖",
+            (Icon::Keyword, true) => "keyword:",
+            (Icon::Link, false) => "🔗",
+            (Icon::Link, true) => "links:",
+            (Icon::Package | Icon::Project | Icon::Details | Icon::Help | Icon::Settings, true) => {
+                ""
+            }
+        }
+    }
+
+    /// A title such as ` 📖 Help `: `text` after the icon, padded by a space on each side.
+    pub fn label(self, text: impl Display, ascii: bool) -> String {
+        match self.glyph(ascii) {
+            "" => format!(" {text} "),
+            glyph => format!(" {glyph} {text} "),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn label_drops_decorative_icons_in_ascii() {
+        assert_eq!(Icon::Help.label("Help", false), " 📖 Help ");
+        assert_eq!(Icon::Help.label("Help", true), " Help ");
+        assert_eq!(Icon::Owner.label("dtolnay", true), " owner: dtolnay ");
+    }
+}
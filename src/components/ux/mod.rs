@@ -1,11 +1,15 @@
 mod button;
 mod confirm;
 mod dropdown;
+mod icon;
 mod multi_select;
 mod popup;
+mod prompt;
 
 pub use button::*;
 pub use confirm::*;
 pub use dropdown::*;
+pub use icon::*;
 pub use multi_select::*;
 pub use popup::*;
+pub use prompt::*;
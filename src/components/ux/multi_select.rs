@@ -24,11 +24,13 @@ impl<T> MultiSelectItem<T> {
     }
 }
 
-/// A modal list of toggleable items rendered as a popup.
+/// A modal list of toggleable items rendered as a popup. Typing narrows the list to the items
+/// whose label contains the typed text; `state` indexes into that narrowed list.
 pub struct MultiSelect<T> {
     config: Config,
     title: String,
     items: Vec<MultiSelectItem<T>>,
+    filter: String,
     state: ListState,
 }
 
@@ -39,6 +41,7 @@ impl<T: Clone> MultiSelect<T> {
             config,
             title,
             items,
+            filter: String::new(),
             state: ListState::default().with_selected(selected),
         }
     }
@@ -54,29 +57,52 @@ impl<T: Clone> MultiSelect<T> {
 
     pub fn handle_key(&mut self, key: KeyEvent) -> KeyOutcome<Vec<T>> {
         match key.code {
+            // The first Esc only clears the filter.
+            KeyCode::Esc if !self.filter.is_empty() => self.set_filter(String::new()),
             KeyCode::Esc => return KeyOutcome::Cancelled,
             KeyCode::Enter => return KeyOutcome::Submitted(self.checked()),
             KeyCode::Up => self.select_previous(),
             KeyCode::Down => self.select_next(),
             KeyCode::Char(' ') => self.toggle_selected(),
+            KeyCode::Char(c) => self.set_filter(format!("{}{c}", self.filter)),
+            KeyCode::Backspace => {
+                let mut filter = self.filter.clone();
+                filter.pop();
+                self.set_filter(filter);
+            }
             _ => {}
         }
         KeyOutcome::Pending
     }
 
+    /// Indices into `items` of the rows matching the filter, in display order.
+    fn visible(&self) -> Vec<usize> {
+        let filter = self.filter.to_lowercase();
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.label.to_string().to_lowercase().contains(&filter))
+            .map(|(ix, _)| ix)
+            .collect()
+    }
+
+    fn set_filter(&mut self, filter: String) {
+        self.filter = filter;
+        let selected = (!self.visible().is_empty()).then_some(0);
+        self.state.select(selected);
+    }
+
     fn select_next(&mut self) {
-        if self.items.is_empty() {
+        let len = self.visible().len();
+        if len == 0 {
             return;
         }
-        let next = self
-            .state
-            .selected()
-            .map_or(0, |i| (i + 1).min(self.items.len() - 1));
+        let next = self.state.selected().map_or(0, |i| (i + 1).min(len - 1));
         self.state.select(Some(next));
     }
 
     fn select_previous(&mut self) {
-        if self.items.is_empty() {
+        if self.visible().is_empty() {
             return;
         }
         let prev = self.state.selected().map_or(0, |i| i.saturating_sub(1));
@@ -85,20 +111,22 @@ impl<T: Clone> MultiSelect<T> {
 
     fn toggle_selected(&mut self) {
         if let Some(index) = self.state.selected()
-            && let Some(item) = self.items.get_mut(index)
+            && let Some(&item_ix) = self.visible().get(index)
         {
+            let item = &mut self.items[item_ix];
             item.checked = !item.checked;
         }
     }
 
     pub fn draw(&mut self, frame: &mut Frame, area: Rect) {
         // Cap the popup to the available area; the list scrolls via its state when it overflows.
-        let inner_height = (self.items.len() as u16).clamp(1, area.height.saturating_sub(4).max(1));
+        let visible = self.visible();
+        let inner_height = (visible.len() as u16).clamp(1, area.height.saturating_sub(4).max(1));
 
-        let list_items: Vec<ListItem> = self
-            .items
+        let list_items: Vec<ListItem> = visible
             .iter()
-            .map(|item| {
+            .map(|&ix| {
+                let item = &self.items[ix];
                 let checkbox = if item.checked { "[x] " } else { "[ ] " };
                 let mut spans = vec![Span::from(checkbox)];
                 spans.extend(item.label.spans.iter().cloned());
@@ -110,9 +138,15 @@ impl<T: Clone> MultiSelect<T> {
             .highlight_style(self.config.theme.accent.bold())
             .highlight_symbol("▶ ");
 
+        let title = if self.filter.is_empty() {
+            self.title.clone()
+        } else {
+            format!("{}/{} ", self.title, self.filter)
+        };
+
         let inner = Popup::new(54.min(area.width), inner_height + 2)
-            .title(self.title.as_str())
-            .footer(" Space toggle · Enter confirm · Esc cancel ")
+            .title(title)
+            .footer(" Type to filter · Space toggle · Enter confirm · Esc cancel ")
             .border_style(self.config.theme.accent)
             .render(frame, area);
 
@@ -170,6 +204,38 @@ mod tests {
         assert_eq!(ms.checked(), vec!["b".to_string()]);
     }
 
+    fn type_text(ms: &mut MultiSelect<String>, text: &str) {
+        for c in text.chars() {
+            press(ms, KeyCode::Char(c));
+        }
+    }
+
+    #[test]
+    fn typing_filters_the_list_and_space_toggles_the_match() {
+        let mut ms = multi_select(&[("derive", false), ("std", false), ("rc", false)]);
+        type_text(&mut ms, "RC");
+        press(&mut ms, KeyCode::Char(' '));
+        assert_eq!(ms.checked(), vec!["rc".to_string()]);
+    }
+
+    #[test]
+    fn backspace_and_esc_widen_the_filter_again() {
+        let mut ms = multi_select(&[("derive", false), ("std", false)]);
+        type_text(&mut ms, "sx");
+        press(&mut ms, KeyCode::Char(' ')); // nothing matches, nothing toggles
+        assert!(ms.checked().is_empty());
+
+        press(&mut ms, KeyCode::Backspace);
+        press(&mut ms, KeyCode::Char(' '));
+        assert_eq!(ms.checked(), vec!["std".to_string()]);
+
+        // Esc clears the filter before it cancels.
+        assert_eq!(press(&mut ms, KeyCode::Esc), KeyOutcome::Pending);
+        press(&mut ms, KeyCode::Char(' ')); // back on "derive"
+        assert_eq!(ms.checked(), vec!["derive".to_string(), "std".to_string()]);
+        assert_eq!(press(&mut ms, KeyCode::Esc), KeyOutcome::Cancelled);
+    }
+
     #[test]
     fn enter_submits_and_esc_cancels() {
         let mut ms = multi_select(&[("a", true)]);
@@ -0,0 +1,80 @@
+use crossterm::event::{Event, KeyCode, KeyEvent};
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::widgets::Paragraph;
+use tui_input::Input;
+use tui_input::backend::crossterm::EventHandler;
+
+use crate::components::ux::{KeyOutcome, Popup};
+use crate::config::Config;
+
+/// A modal single-line text input, rendered as a popup.
+pub struct Prompt {
+    config: Config,
+    title: String,
+    input: Input,
+}
+
+impl Prompt {
+    /// Builds a prompt titled `title`, prefilled with `value`.
+    pub fn new(config: Config, title: String, value: &str) -> Self {
+        Self {
+            config,
+            title,
+            input: Input::default().with_value(value.to_string()),
+        }
+    }
+
+    /// Submits the trimmed text on Enter; it may be empty.
+    pub fn handle_key(&mut self, key: KeyEvent) -> KeyOutcome<String> {
+        match key.code {
+            KeyCode::Esc => return KeyOutcome::Cancelled,
+            KeyCode::Enter => return KeyOutcome::Submitted(self.input.value().trim().to_string()),
+            _ => {
+                self.input.handle_event(&Event::Key(key));
+            }
+        }
+        KeyOutcome::Pending
+    }
+
+    pub fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = Popup::new(40.min(area.width), 3)
+            .title(format!(" {} ", self.title))
+            .footer(" Enter apply · Esc cancel ")
+            .border_style(self.config.theme.accent)
+            .render(frame, area);
+
+        let scroll = self.input.visual_scroll(usize::from(inner.width));
+        frame.render_widget(
+            Paragraph::new(self.input.value()).scroll((0, scroll as u16)),
+            inner,
+        );
+        frame.set_cursor_position((
+            inner.x + (self.input.visual_cursor().max(scroll) - scroll) as u16,
+            inner.y,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use pretty_assertions::assert_eq;
+
+    fn press(prompt: &mut Prompt, code: KeyCode) -> KeyOutcome<String> {
+        prompt.handle_key(KeyEvent::new(code, KeyModifiers::empty()))
+    }
+
+    #[test]
+    fn enter_submits_the_typed_text_trimmed() {
+        let mut prompt = Prompt::new(Config::default(), "Owner".into(), "dtoln");
+        press(&mut prompt, KeyCode::Char('a'));
+        press(&mut prompt, KeyCode::Char('y'));
+        press(&mut prompt, KeyCode::Char(' '));
+        assert_eq!(
+            press(&mut prompt, KeyCode::Enter),
+            KeyOutcome::Submitted("dtolnay".to_string())
+        );
+    }
+}
@@ -3,25 +3,285 @@
 
 #![allow(dead_code)]
 
+use chrono::format::{Item, StrftimeItems};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use derive_deref::{Deref, DerefMut};
 use directories::ProjectDirs;
 use ratatui::style::{Color, Modifier, Style};
-use serde::{Deserialize, de::Deserializer};
+use serde::{Deserialize, Serialize, de::Deserializer};
 use std::sync::LazyLock;
-use std::{collections::HashMap, env, path::PathBuf};
-use tracing::error;
-
+use std::{
+    collections::{BTreeMap, HashMap},
+    env,
+    path::PathBuf,
+};
+use tracing::{error, warn};
+
+use crate::search::{Scope, Sort};
 use crate::{action::Action, app::Mode};
 
 const CONFIG: &str = include_str!("../.config/config.json5");
 
-#[derive(Clone, Debug, Deserialize, Default)]
+/// The built-in themes, as `(name, styles)`. `dark` is the embedded default styles as they are.
+const BUILTIN_THEMES: [(&str, &str); 2] = [
+    ("dark", ""),
+    ("light", include_str!("../.config/themes/light.toml")),
+];
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AppConfig {
     #[serde(default)]
     pub data_dir: PathBuf,
     #[serde(default)]
     pub config_dir: PathBuf,
+    /// Open links (docs, repository, crates.io, lib.rs) in the browser. When off, the link is
+    /// shown in the status bar instead.
+    #[serde(default = "default_true")]
+    pub open_links_in_browser: bool,
+    /// How long a crates.io request may take before it is abandoned, in seconds.
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+    /// Minimum delay between crates.io requests, in milliseconds. Never below
+    /// [`MIN_RATE_LIMIT_MS`].
+    #[serde(default = "default_rate_limit_ms")]
+    pub rate_limit_ms: u64,
+    /// Name of an alternate registry (from cargo's config or `CARGO_REGISTRIES_<NAME>_INDEX`) to
+    /// search and add/install from instead of crates.io.
+    #[serde(default)]
+    pub registry: Option<String>,
+    /// Index URL of an alternate registry, used when `registry` isn't set.
+    #[serde(default)]
+    pub registry_index: Option<String>,
+    /// The scope searches start in, e.g. `"All"` or `"Project"`.
+    #[serde(default)]
+    pub default_scope: Scope,
+    /// The scope of the search given at startup (`cargo seek -s <term>`). Unset uses
+    /// `default_scope`.
+    #[serde(default)]
+    pub search_term_scope: Option<Scope>,
+    /// The sort searches start with, e.g. `"Downloads"` or `"RecentlyUpdated"`.
+    #[serde(default)]
+    pub default_sort: Sort,
+    /// The cargo executable to run. Defaults to `$CARGO` when set, else `cargo` from `PATH`.
+    #[serde(default)]
+    pub cargo_path: Option<PathBuf>,
+    /// Offer pre-release versions (e.g. `2.0.0-rc.1`) as a crate's latest version. When off, the
+    /// newest stable release is used wherever one exists.
+    #[serde(default)]
+    pub show_prereleases: bool,
+    /// Enable vim-style navigation in the results list: `j`/`k`, `gg`/`G` and `Ctrl+d`/`Ctrl+u`.
+    /// Off by default so the single-letter keys stay free.
+    #[serde(default)]
+    pub vim_keys: bool,
+    /// Up on the first result wraps to the last, and Down on the last to the first, instead of
+    /// Up moving to the search box.
+    #[serde(default)]
+    pub list_wrap: bool,
+    /// Show versions in the results list as full semver (`1.2.0`), and project versions, which are
+    /// requirements, with their operator (`^1.2`).
+    #[serde(default)]
+    pub list_semver_versions: bool,
+    /// The most pages of search results that can be navigated to, however many crates match.
+    /// Keeps paging through a broad term from making request after request. At least 1.
+    #[serde(default = "default_max_pages")]
+    pub max_pages: usize,
+    /// Show each crate's description on a second line in the results list (toggle with `d`).
+    #[serde(default)]
+    pub list_show_description: bool,
+    /// Tint results by crates.io downloads: dim below 10k, bold from 1M. Local-only results,
+    /// which have no download count, are left as is.
+    #[serde(default)]
+    pub color_by_popularity: bool,
+    /// Before adding a crate to the project, show what `cargo add --dry-run` reports and ask for
+    /// confirmation.
+    #[serde(default)]
+    pub preview_add: bool,
+    /// Before removing a crate from the project, show what `cargo remove --dry-run` reports (or
+    /// why the removal would fail) in place of the usual confirmation.
+    #[serde(default)]
+    pub preview_remove: bool,
+    /// Show the open issue count of crates hosted on GitHub, fetched from GitHub's API. Requests
+    /// are unauthenticated and rate-limited to 60 an hour.
+    #[serde(default)]
+    pub fetch_github_stats: bool,
+    /// Send a desktop notification when a long add or install finishes.
+    #[serde(default)]
+    pub notify_on_completion: bool,
+    /// How many parent directories above the starting one to look in for the project's
+    /// `Cargo.toml`. Unset walks up to the filesystem root.
+    #[serde(default)]
+    pub project_search_depth: Option<usize>,
+    /// Don't look for the project's `Cargo.toml` above the home directory when starting inside it.
+    #[serde(default)]
+    pub project_search_stop_at_home: bool,
+    /// Use the built-in monochrome theme, ignoring `styles`. Implied when `NO_COLOR` is set.
+    #[serde(default)]
+    pub monochrome: bool,
+    /// Name of the theme `styles` are layered over: a built-in one (`dark`, `light`) or a
+    /// `themes/<name>.toml` file in the config directory.
+    #[serde(default = "default_theme")]
+    pub theme: String,
+    /// Draw icons as ASCII, e.g. `[ok]` for `✅`, or leave out purely decorative ones, for
+    /// terminals that show emoji as boxes or at the wrong width.
+    #[serde(default)]
+    pub ascii_icons: bool,
+    /// List the most downloaded crates on start when no search term is given. Off by default to
+    /// avoid a network call on every start.
+    #[serde(default)]
+    pub show_popular_on_start: bool,
+    /// Proxy for crates.io and GitHub requests, e.g. `"http://proxy.corp:3128"`, overriding
+    /// `HTTPS_PROXY` and the like. Read at start.
+    #[serde(default)]
+    pub proxy_url: Option<String>,
+    /// List a few popular crates sharing the focused crate's top category or keyword in its
+    /// details. Takes an extra crates.io request per crate.
+    #[serde(default)]
+    pub show_related_crates: bool,
+    /// How crate details show dates, as a `chrono` format string, e.g. `"%Y-%m-%d %H:%M"` for
+    /// ISO 8601. Dates are in UTC.
+    #[serde(default = "default_date_format")]
+    pub date_format: String,
+    /// The symbols marking project dependencies, installed binaries and available updates in the
+    /// results list, and in the legend on the help screen.
+    #[serde(default)]
+    pub markers: Markers,
+}
+
+/// The symbols in front of results that are in the project or installed, and after versions
+/// with an update available.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Markers {
+    pub project: String,
+    pub installed: String,
+    pub update: String,
+}
+
+impl Default for Markers {
+    fn default() -> Self {
+        Self {
+            project: "+".into(),
+            installed: "i".into(),
+            update: "↑".into(),
+        }
+    }
+}
+
+impl Markers {
+    /// The tag in front of a result: `marker` padded to the widest of the project and installed
+    /// markers, so names line up, and a space.
+    pub fn tag(&self, marker: Option<&str>) -> String {
+        let width = self
+            .project
+            .chars()
+            .count()
+            .max(self.installed.chars().count());
+        format!("{:<width$} ", marker.unwrap_or_default())
+    }
+}
+
+/// crates.io's crawler policy allows at most one request per second; going faster risks a block.
+pub const MIN_RATE_LIMIT_MS: u64 = 1000;
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            data_dir: PathBuf::default(),
+            config_dir: PathBuf::default(),
+            open_links_in_browser: true,
+            request_timeout_secs: default_request_timeout_secs(),
+            rate_limit_ms: default_rate_limit_ms(),
+            registry: None,
+            registry_index: None,
+            default_scope: Scope::default(),
+            search_term_scope: None,
+            default_sort: Sort::default(),
+            cargo_path: None,
+            show_prereleases: false,
+            vim_keys: false,
+            list_wrap: false,
+            list_semver_versions: false,
+            max_pages: default_max_pages(),
+            list_show_description: false,
+            color_by_popularity: false,
+            preview_add: false,
+            preview_remove: false,
+            fetch_github_stats: false,
+            notify_on_completion: false,
+            project_search_depth: None,
+            project_search_stop_at_home: false,
+            monochrome: false,
+            theme: default_theme(),
+            ascii_icons: false,
+            show_popular_on_start: false,
+            proxy_url: None,
+            show_related_crates: false,
+            date_format: default_date_format(),
+            markers: Markers::default(),
+        }
+    }
+}
+
+impl AppConfig {
+    /// The scope to start in: `search_term_scope` when starting with a search term, else
+    /// `default_scope`.
+    pub fn start_scope(&self, has_search_term: bool) -> Scope {
+        match &self.search_term_scope {
+            Some(scope) if has_search_term => scope.clone(),
+            _ => self.default_scope.clone(),
+        }
+    }
+
+    /// Clamps values that would misbehave into their valid range, logging what was changed.
+    fn validate(&mut self) {
+        if self.rate_limit_ms < MIN_RATE_LIMIT_MS {
+            warn!(
+                "rate_limit_ms {} is below crates.io's minimum, using {MIN_RATE_LIMIT_MS}",
+                self.rate_limit_ms
+            );
+            self.rate_limit_ms = MIN_RATE_LIMIT_MS;
+        }
+        if self.request_timeout_secs == 0 {
+            warn!("request_timeout_secs must be at least 1, using 1");
+            self.request_timeout_secs = 1;
+        }
+        if self.max_pages == 0 {
+            warn!("max_pages must be at least 1, using 1");
+            self.max_pages = 1;
+        }
+        if StrftimeItems::new(&self.date_format).any(|item| item == Item::Error) {
+            warn!(
+                "date_format {:?} is not a valid format, using {:?}",
+                self.date_format,
+                default_date_format()
+            );
+            self.date_format = default_date_format();
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_request_timeout_secs() -> u64 {
+    10
+}
+
+fn default_rate_limit_ms() -> u64 {
+    1100
+}
+
+fn default_max_pages() -> usize {
+    100
+}
+
+fn default_theme() -> String {
+    BUILTIN_THEMES[0].0.into()
+}
+
+fn default_date_format() -> String {
+    "%d/%m/%Y %H:%M:%S (UTC)".into()
 }
 
 #[derive(Clone, Debug, Default, Deref, DerefMut)]
@@ -49,11 +309,229 @@ impl<'de> Deserialize<'de> for KeyBindings {
     }
 }
 
+impl KeyBindings {
+    /// The action the last key of `pressed` (the keys pressed this tick) is bound to, on its own
+    /// or as the end of the whole sequence; `mode`'s bindings first, then the app-wide ones.
+    /// `raw_keys` leaves `mode`'s bindings out, for when a component wants those keys itself.
+    pub fn action_for(&self, mode: Mode, pressed: &[KeyEvent], raw_keys: bool) -> Option<&Action> {
+        let key = pressed.last()?;
+        [Some(mode).filter(|_| !raw_keys), Some(Mode::App)]
+            .into_iter()
+            .flatten()
+            .filter_map(|mode| self.get(&mode))
+            .find_map(|keymap| {
+                keymap
+                    .get(std::slice::from_ref(key))
+                    .or_else(|| keymap.get(pressed))
+            })
+    }
+
+    /// Describes the key sequences that can never fire: [`KeyBindings::action_for`] tries single
+    /// keys first, so a sequence starting with a key that is bound on its own is unreachable.
+    fn shadowed_sequences(&self) -> Vec<String> {
+        let mut shadowed = self
+            .iter()
+            .flat_map(|(mode, bindings)| {
+                bindings
+                    .keys()
+                    .filter(|keys| keys.len() > 1 && bindings.contains_key(&keys[..1]))
+                    .map(move |keys| {
+                        let keys = keys.iter().map(key_event_to_string).collect::<Vec<_>>();
+                        format!(
+                            "{mode:?}: `{}` can never fire, `{}` is bound on its own",
+                            keys.join(" "),
+                            keys[0]
+                        )
+                    })
+            })
+            .collect::<Vec<_>>();
+        shadowed.sort();
+        shadowed
+    }
+}
+
+/// An action in the keybinding reference, with every key sequence bound to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundAction {
+    pub category: &'static str,
+    pub action: String,
+    /// Human-readable key sequences, e.g. `Ctrl + c` or `g g`.
+    pub keys: Vec<String>,
+}
+
+impl KeyBindings {
+    /// What the keybindings in effect in `mode` do: its own bindings plus the app-wide ones it
+    /// doesn't override, one entry per action, sorted by category and then action.
+    pub fn reference(&self, mode: Mode) -> Vec<BoundAction> {
+        let own = self.get(&mode);
+        let app_wide = self
+            .get(&Mode::App)
+            .filter(|_| mode != Mode::App)
+            .into_iter()
+            .flatten()
+            .filter(|(keys, _)| !own.is_some_and(|own| own.contains_key(*keys)));
+
+        let mut by_action = BTreeMap::<(&'static str, String), Vec<String>>::new();
+        for (keys, action) in own.into_iter().flatten().chain(app_wide) {
+            by_action
+                .entry((action_category(action), action_label(action)))
+                .or_default()
+                .push(key_sequence_label(keys));
+        }
+
+        by_action
+            .into_iter()
+            .map(|((category, action), mut keys)| {
+                keys.sort();
+                BoundAction {
+                    category,
+                    action,
+                    keys,
+                }
+            })
+            .collect()
+    }
+
+    /// The key sequences bound to `action` in `mode`, spelled as in [`KeyBindings::reference`];
+    /// empty when it's unbound.
+    pub fn keys_for(&self, mode: Mode, action: &Action) -> Vec<String> {
+        let (category, label) = (action_category(action), action_label(action));
+        self.reference(mode)
+            .into_iter()
+            .find(|bound| bound.category == category && bound.action == label)
+            .map(|bound| bound.keys)
+            .unwrap_or_default()
+    }
+}
+
+fn action_category(action: &Action) -> &'static str {
+    match action {
+        Action::Home(_) => "Home",
+        Action::Search(_) => "Search",
+        Action::Cargo(_) => "Cargo",
+        Action::Status(_) => "Status",
+        _ => "App",
+    }
+}
+
+/// An action's name in words, e.g. `Home(GoToFirstResult)` reads "Go to first result".
+fn action_label(action: &Action) -> String {
+    let name = match action {
+        Action::Home(command) => command.to_string(),
+        Action::Search(command) => command.to_string(),
+        Action::Cargo(command) => command.to_string(),
+        Action::Status(command) => command.to_string(),
+        action => action.to_string(),
+    };
+
+    let mut label = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            label.push(' ');
+            label.extend(c.to_lowercase());
+        } else {
+            label.push(c);
+        }
+    }
+    label
+}
+
+/// A key sequence the way the help screen spells keys, e.g. `Ctrl + c` or `g g`.
+fn key_sequence_label(keys: &[KeyEvent]) -> String {
+    keys.iter()
+        .map(|key| {
+            let mut parts = Vec::with_capacity(4);
+            if key.modifiers.intersects(KeyModifiers::CONTROL) {
+                parts.push("Ctrl".to_string());
+            }
+            if key.modifiers.intersects(KeyModifiers::ALT) {
+                parts.push("Alt".to_string());
+            }
+            if key.modifiers.intersects(KeyModifiers::SHIFT) {
+                parts.push("Shift".to_string());
+            }
+            parts.push(key_event_to_string(&KeyEvent::new(
+                key.code,
+                KeyModifiers::NONE,
+            )));
+            parts.join(" + ")
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Keybindings exactly as written in a config file. Unlike [`KeyBindings`] this never fails to
+/// deserialize: bad entries are kept so [`RawKeyBindings::resolve`] can report them.
+#[derive(Default, Deserialize)]
+struct RawKeyBindings(HashMap<String, HashMap<String, RawBinding>>);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawBinding {
+    Action(Action),
+    Invalid(serde_json::Value),
+}
+
+impl RawKeyBindings {
+    /// Parses every binding, skipping (and describing) unknown modes, unparsable keys, unknown
+    /// actions and keys bound twice in one mode.
+    fn resolve(self) -> (KeyBindings, Vec<String>) {
+        let mut errors = Vec::new();
+        let mut keybindings = KeyBindings::default();
+
+        let mut modes = self.0.into_iter().collect::<Vec<_>>();
+        modes.sort_by(|a, b| a.0.cmp(&b.0));
+        for (mode_name, bindings) in modes {
+            let Ok(mode) =
+                serde_json::from_value::<Mode>(serde_json::Value::String(mode_name.clone()))
+            else {
+                errors.push(format!("unknown mode `{mode_name}`"));
+                continue;
+            };
+            let mode_bindings = keybindings.entry(mode).or_default();
+
+            let mut bindings = bindings.into_iter().collect::<Vec<_>>();
+            bindings.sort_by(|a, b| a.0.cmp(&b.0));
+            for (key_str, binding) in bindings {
+                let keys = match parse_key_sequence(&key_str) {
+                    Ok(keys) if !keys.is_empty() => keys,
+                    _ => {
+                        errors.push(format!("{mode_name}: unable to parse key `{key_str}`"));
+                        continue;
+                    }
+                };
+                let action = match binding {
+                    RawBinding::Action(action) => action,
+                    RawBinding::Invalid(value) => {
+                        errors.push(format!(
+                            "{mode_name}: `{key_str}` is bound to unknown action {value}"
+                        ));
+                        continue;
+                    }
+                };
+                if let Some(existing) = mode_bindings.get(&keys) {
+                    errors.push(format!(
+                        "{mode_name}: `{key_str}` is bound to both {existing:?} and {action:?}"
+                    ));
+                    continue;
+                }
+                mode_bindings.insert(keys, action);
+            }
+        }
+
+        (keybindings, errors)
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Config {
     pub config: AppConfig,
     pub keybindings: KeyBindings,
     pub theme: Theme,
+    /// Problems found in the user's keybindings. The offending entries are skipped.
+    pub keybinding_errors: Vec<String>,
+    /// The user's `styles`, kept to layer over another theme when switching to it.
+    styles: ThemeConfig,
 }
 
 /// Config exactly as parsed from disk, before the embedded defaults are merged in.
@@ -62,23 +540,39 @@ struct RawConfig {
     #[serde(default, flatten)]
     config: AppConfig,
     #[serde(default)]
-    keybindings: KeyBindings,
+    keybindings: RawKeyBindings,
     #[serde(default)]
     styles: ThemeConfig,
 }
 
 pub static PROJECT_NAME: LazyLock<String> =
     LazyLock::new(|| env!("CARGO_CRATE_NAME").to_uppercase().to_string());
-static DATA_FOLDER: LazyLock<Option<PathBuf>> = LazyLock::new(|| {
-    env::var(format!("{}_DATA", PROJECT_NAME.clone()))
-        .ok()
-        .map(PathBuf::from)
-});
-static CONFIG_FOLDER: LazyLock<Option<PathBuf>> = LazyLock::new(|| {
-    env::var(format!("{}_CONFIG", PROJECT_NAME.clone()))
-        .ok()
-        .map(PathBuf::from)
-});
+/// The data directory set with `CARGO_SEEK_DATA_DIR` (or the older `CARGO_SEEK_DATA`).
+static DATA_FOLDER: LazyLock<Option<PathBuf>> = LazyLock::new(|| dir_from_env("DATA"));
+/// The config directory set with `CARGO_SEEK_CONFIG_DIR` (or the older `CARGO_SEEK_CONFIG`).
+static CONFIG_FOLDER: LazyLock<Option<PathBuf>> = LazyLock::new(|| dir_from_env("CONFIG"));
+
+/// The directory in `<PROJECT_NAME>_<kind>_DIR`, else `<PROJECT_NAME>_<kind>`. Empty values are
+/// ignored.
+fn dir_from_env(kind: &str) -> Option<PathBuf> {
+    [
+        format!("{}_{kind}_DIR", *PROJECT_NAME),
+        format!("{}_{kind}", *PROJECT_NAME),
+    ]
+    .iter()
+    .filter_map(env::var_os)
+    .find(|value| !value.is_empty())
+    .map(PathBuf::from)
+}
+
+/// The config files read from the config directory, all merged in this order.
+const CONFIG_FILES: [(&str, config::FileFormat); 5] = [
+    ("config.json5", config::FileFormat::Json5),
+    ("config.json", config::FileFormat::Json),
+    ("config.yaml", config::FileFormat::Yaml),
+    ("config.toml", config::FileFormat::Toml),
+    ("config.ini", config::FileFormat::Ini),
+];
 
 impl Config {
     pub fn new() -> Result<Self, config::ConfigError> {
@@ -90,15 +584,8 @@ impl Config {
             .set_default("data_dir", data_dir.to_string_lossy().to_string())?
             .set_default("config_dir", config_dir.to_string_lossy().to_string())?;
 
-        let config_files = [
-            ("config.json5", config::FileFormat::Json5),
-            ("config.json", config::FileFormat::Json),
-            ("config.yaml", config::FileFormat::Yaml),
-            ("config.toml", config::FileFormat::Toml),
-            ("config.ini", config::FileFormat::Ini),
-        ];
         let mut found_config = false;
-        for (file, format) in &config_files {
+        for (file, format) in &CONFIG_FILES {
             let source = config::File::from(config_dir.join(file))
                 .format(*format)
                 .required(false);
@@ -112,24 +599,164 @@ impl Config {
         }
 
         let mut cfg: RawConfig = builder.build()?.try_deserialize()?;
+        cfg.config.validate();
+        if no_color() {
+            cfg.config.monochrome = true;
+        }
+
+        let (default_keybindings, default_errors) = default_config.keybindings.resolve();
+        debug_assert!(
+            default_errors.is_empty(),
+            "the embedded default keybindings are invalid: {default_errors:?}"
+        );
+        let (mut keybindings, mut keybinding_errors) = cfg.keybindings.resolve();
 
-        for (mode, default_bindings) in default_config.keybindings.iter() {
-            let user_bindings = cfg.keybindings.entry(*mode).or_default();
+        for (mode, default_bindings) in default_keybindings.iter() {
+            let user_bindings = keybindings.entry(*mode).or_default();
             for (key, cmd) in default_bindings.iter() {
                 user_bindings
                     .entry(key.clone())
                     .or_insert_with(|| cmd.clone());
             }
         }
+        keybinding_errors.extend(keybindings.shadowed_sequences());
+        for error in &keybinding_errors {
+            warn!("Invalid keybinding: {error}");
+        }
 
-        Ok(Config {
+        let mut config = Config {
             config: cfg.config,
-            theme: cfg.styles.resolve(&default_config.styles),
-            keybindings: cfg.keybindings,
+            theme: Theme::default(),
+            keybindings,
+            keybinding_errors,
+            styles: cfg.styles,
+        };
+        let theme = config.config.theme.clone();
+        if let Err(err) = config.set_theme(&theme) {
+            warn!("{err}, using {:?}", default_theme());
+            config.set_theme(&default_theme())?;
+        }
+        Ok(config)
+    }
+
+    /// Switches to the theme called `name`, with the user's `styles` still layered over it. The
+    /// monochrome theme stays in place when `monochrome` is set.
+    pub fn set_theme(&mut self, name: &str) -> Result<(), config::ConfigError> {
+        let styles = load_theme(name)?.or(embedded_styles());
+        self.config.theme = name.to_string();
+        self.theme = if self.config.monochrome {
+            Theme::monochrome()
+        } else {
+            self.styles.clone().resolve(&styles)
+        };
+        Ok(())
+    }
+}
+
+/// The styles of the embedded default config.
+fn embedded_styles() -> ThemeConfig {
+    let default_config: RawConfig = json5::from_str(CONFIG)
+        .expect("the embedded default config (.config/config.json5) must be valid JSON5");
+    default_config.styles
+}
+
+/// The directory theme files are read from.
+fn get_themes_dir() -> PathBuf {
+    get_config_dir().join("themes")
+}
+
+/// The names of the themes to choose from: the built-in ones, then the `.toml` files in the themes
+/// directory, sorted. A file named after a built-in theme replaces it rather than being listed twice.
+pub fn theme_names() -> Vec<String> {
+    let mut files: Vec<String> = std::fs::read_dir(get_themes_dir())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            (path.extension()? == "toml").then_some(())?;
+            Some(path.file_stem()?.to_string_lossy().into_owned())
         })
+        .filter(|name| BUILTIN_THEMES.iter().all(|(builtin, _)| builtin != name))
+        .collect();
+    files.sort();
+
+    let mut names: Vec<String> = BUILTIN_THEMES
+        .iter()
+        .map(|(name, _)| name.to_string())
+        .collect();
+    names.extend(files);
+    names
+}
+
+/// The styles of the theme called `name`: its file in the themes directory, else the built-in theme.
+fn load_theme(name: &str) -> Result<ThemeConfig, config::ConfigError> {
+    let path = get_themes_dir().join(format!("{name}.toml"));
+    let builder = config::Config::builder();
+    let builder = if path.exists() {
+        builder.add_source(config::File::from(path).format(config::FileFormat::Toml))
+    } else {
+        let (_, styles) = BUILTIN_THEMES
+            .iter()
+            .find(|(builtin, _)| *builtin == name)
+            .ok_or_else(|| config::ConfigError::Message(format!("unknown theme {name:?}")))?;
+        builder.add_source(config::File::from_str(styles, config::FileFormat::Toml))
+    };
+    builder.build()?.try_deserialize()
+}
+
+impl Config {
+    /// The merged config as pretty-printed JSON: flags, resolved styles and every keybinding.
+    /// Keys are sorted so the output is stable.
+    pub fn to_effective_json(&self) -> String {
+        let mut value = serde_json::to_value(&self.config).unwrap_or_default();
+        let theme = &self.theme;
+        value["styles"] = serde_json::json!({
+            "accent": format!("{:?}", theme.accent),
+            "accent_active": format!("{:?}", theme.accent_active),
+            "title": format!("{:?}", theme.title),
+            "throbber": format!("{:?}", theme.throbber),
+            "selected": format!("{:?}", theme.selected),
+            "project_selected": format!("{:?}", theme.project_selected),
+            "installed_selected": format!("{:?}", theme.installed_selected),
+        });
+
+        let mut keybindings = serde_json::Map::new();
+        for (mode, bindings) in self.keybindings.iter() {
+            let bindings = bindings
+                .iter()
+                .map(|(keys, action)| {
+                    let keys = keys
+                        .iter()
+                        .map(|key| format!("<{}>", key_event_to_string(key)))
+                        .collect::<String>();
+                    (keys, format!("{action:?}").into())
+                })
+                .collect();
+            keybindings.insert(format!("{mode:?}"), serde_json::Value::Object(bindings));
+        }
+        value["keybindings"] = serde_json::Value::Object(keybindings);
+
+        serde_json::to_string_pretty(&value).unwrap_or_default()
     }
 }
 
+/// The config file to edit: the first one that exists in the config directory, else
+/// `config.json5` there.
+pub fn get_config_file() -> PathBuf {
+    let config_dir = get_config_dir();
+    CONFIG_FILES
+        .iter()
+        .map(|(file, _)| config_dir.join(file))
+        .find(|path| path.exists())
+        .unwrap_or_else(|| config_dir.join(CONFIG_FILES[0].0))
+}
+
+/// Whether color output is disabled through `NO_COLOR` (see <https://no-color.org>): set and
+/// non-empty.
+fn no_color() -> bool {
+    env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
 pub fn get_data_dir() -> PathBuf {
     if let Some(s) = DATA_FOLDER.clone() {
         s
@@ -332,30 +959,83 @@ pub struct Theme {
     pub accent_active: Style,
     pub title: Style,
     pub throbber: Style,
+    /// The selected row in the results list, and when that row is a project dependency or an
+    /// installed binary.
+    pub selected: Style,
+    pub project_selected: Style,
+    pub installed_selected: Style,
+    /// Set for the monochrome theme. Render code that hardcodes colors (result tags, buttons)
+    /// falls back to modifiers like bold and underline instead.
+    pub monochrome: bool,
+}
+
+impl Theme {
+    /// The built-in theme without any colors, used with `monochrome` or when `NO_COLOR` is set.
+    pub fn monochrome() -> Self {
+        Theme {
+            accent: Style::default(),
+            accent_active: Style::default().add_modifier(Modifier::BOLD),
+            title: Style::default().add_modifier(Modifier::BOLD),
+            throbber: Style::default(),
+            selected: Style::default(),
+            project_selected: Style::default(),
+            installed_selected: Style::default(),
+            monochrome: true,
+        }
+    }
 }
 
 /// A theme as written in a config file: each field is an optional style string (e.g. `"bold
 /// lightyellow"`). Unset fields fall back to the embedded defaults when resolved into a [`Theme`].
-#[derive(Default, Deserialize)]
+#[derive(Clone, Debug, Default, Deserialize)]
 #[serde(default)]
 struct ThemeConfig {
     accent: Option<String>,
     accent_active: Option<String>,
     title: Option<String>,
     throbber: Option<String>,
+    selected: Option<String>,
+    project_selected: Option<String>,
+    installed_selected: Option<String>,
 }
 
 impl ThemeConfig {
+    /// Each field set here, otherwise `fallback`'s.
+    fn or(self, fallback: ThemeConfig) -> ThemeConfig {
+        ThemeConfig {
+            accent: self.accent.or(fallback.accent),
+            accent_active: self.accent_active.or(fallback.accent_active),
+            title: self.title.or(fallback.title),
+            throbber: self.throbber.or(fallback.throbber),
+            selected: self.selected.or(fallback.selected),
+            project_selected: self.project_selected.or(fallback.project_selected),
+            installed_selected: self.installed_selected.or(fallback.installed_selected),
+        }
+    }
+
     /// Resolve into a [`Theme`]: each field is the user's value if set, otherwise `fallback`'s.
     fn resolve(self, fallback: &ThemeConfig) -> Theme {
         let pick = |user: Option<String>, default: &Option<String>| {
             parse_style(user.as_deref().or(default.as_deref()).unwrap_or_default())
         };
+        let accent = pick(self.accent, &fallback.accent);
+        // Without its own style, the selected row is filled with the accent color.
+        let selected = match self.selected.as_deref().or(fallback.selected.as_deref()) {
+            Some(selected) => parse_style(selected),
+            None => Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Black)
+                .bg(accent.fg.unwrap_or(Color::Yellow)),
+        };
         Theme {
-            accent: pick(self.accent, &fallback.accent),
+            accent,
             accent_active: pick(self.accent_active, &fallback.accent_active),
             title: pick(self.title, &fallback.title),
             throbber: pick(self.throbber, &fallback.throbber),
+            selected,
+            project_selected: pick(self.project_selected, &fallback.project_selected),
+            installed_selected: pick(self.installed_selected, &fallback.installed_selected),
+            monochrome: false,
         }
     }
 }
@@ -488,6 +1168,7 @@ fn parse_color(s: &str) -> Option<Color> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::home::HomeCommand;
     use crate::errors::AppResult;
     use pretty_assertions::{assert_eq, assert_matches};
 
@@ -567,6 +1248,46 @@ mod tests {
         assert_eq!(parse_style("İ on red").bg, Some(Color::Indexed(1)));
     }
 
+    #[test]
+    fn default_scope_and_sort_deserialize_by_variant_name() {
+        let config: AppConfig =
+            json5::from_str(r#"{ "default_scope": "Project", "default_sort": "RecentlyUpdated" }"#)
+                .unwrap();
+        assert_eq!(config.default_scope, Scope::Project);
+        assert_eq!(config.default_sort, Sort::RecentlyUpdated);
+    }
+
+    #[test]
+    fn search_term_scope_applies_only_with_a_term() {
+        let config = AppConfig {
+            default_scope: Scope::Online,
+            search_term_scope: Some(Scope::All),
+            ..Default::default()
+        };
+        assert_eq!(config.start_scope(true), Scope::All);
+        assert_eq!(config.start_scope(false), Scope::Online);
+
+        let config = AppConfig {
+            search_term_scope: None,
+            ..config
+        };
+        assert_eq!(config.start_scope(true), Scope::Online);
+    }
+
+    #[test]
+    fn monochrome_theme_has_no_colors() {
+        let theme = Theme::monochrome();
+        for style in [
+            theme.accent,
+            theme.accent_active,
+            theme.title,
+            theme.throbber,
+        ] {
+            assert_eq!((style.fg, style.bg), (None, None));
+        }
+        assert!(theme.monochrome);
+    }
+
     #[test]
     fn theme_resolve_prefers_user_then_falls_back() {
         let fallback = ThemeConfig {
@@ -574,6 +1295,8 @@ mod tests {
             accent_active: Some("lightyellow".into()),
             title: Some("bold lightyellow".into()),
             throbber: Some("lightyellow".into()),
+            project_selected: Some("bold black on lightcyan".into()),
+            ..Default::default()
         };
         let user = ThemeConfig {
             accent: Some("red".into()),
@@ -585,6 +1308,122 @@ mod tests {
         assert_eq!(theme.accent, parse_style("red"));
         assert_eq!(theme.accent_active, parse_style("lightyellow"));
         assert_eq!(theme.title, parse_style("bold lightyellow"));
+        assert_eq!(
+            theme.project_selected,
+            parse_style("bold black on lightcyan")
+        );
+    }
+
+    #[test]
+    fn builtin_themes_load_and_unknown_ones_fail() {
+        let light = load_theme("light").unwrap();
+        assert_eq!(light.accent.as_deref(), Some("blue"));
+        assert!(load_theme("dark").unwrap().accent.is_none());
+        assert!(load_theme("no-such-theme").is_err());
+
+        assert_eq!(&theme_names()[..2], ["dark", "light"]);
+    }
+
+    #[test]
+    fn set_theme_keeps_the_users_styles_on_top() {
+        let mut config = Config {
+            styles: ThemeConfig {
+                title: Some("bold red".into()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        config.set_theme("light").unwrap();
+
+        assert_eq!(config.config.theme, "light");
+        assert_eq!(config.theme.accent, parse_style("blue"));
+        assert_eq!(config.theme.title, parse_style("bold red"));
+
+        config.set_theme("dark").unwrap();
+        assert_eq!(config.theme.accent, parse_style("yellow"));
+        assert!(config.set_theme("no-such-theme").is_err());
+        assert_eq!(config.config.theme, "dark");
+    }
+
+    #[test]
+    fn theme_selected_defaults_to_the_accent_color() {
+        let fallback = ThemeConfig {
+            accent: Some("yellow".into()),
+            ..Default::default()
+        };
+        let user = ThemeConfig {
+            accent: Some("red".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            user.resolve(&fallback).selected,
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(Color::Black)
+                .bg(parse_style("red").fg.unwrap())
+        );
+
+        let user = ThemeConfig {
+            selected: Some("white on blue".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            user.resolve(&fallback).selected,
+            parse_style("white on blue")
+        );
+    }
+
+    #[test]
+    fn validate_clamps_the_rate_limit_to_the_crates_io_minimum() {
+        let mut config = AppConfig {
+            rate_limit_ms: 200,
+            request_timeout_secs: 0,
+            max_pages: 0,
+            ..Default::default()
+        };
+        config.validate();
+        assert_eq!(config.rate_limit_ms, MIN_RATE_LIMIT_MS);
+        assert_eq!(config.request_timeout_secs, 1);
+        assert_eq!(config.max_pages, 1);
+    }
+
+    #[test]
+    fn validate_keeps_values_in_range() {
+        let mut config = AppConfig {
+            rate_limit_ms: 2500,
+            request_timeout_secs: 30,
+            ..Default::default()
+        };
+        config.validate();
+        assert_eq!(config.rate_limit_ms, 2500);
+        assert_eq!(config.request_timeout_secs, 30);
+    }
+
+    #[test]
+    fn validate_falls_back_to_the_default_date_format_when_invalid() {
+        let mut config = AppConfig {
+            date_format: "%Y-%m-%d %Q".into(),
+            ..Default::default()
+        };
+        config.validate();
+        assert_eq!(config.date_format, default_date_format());
+
+        config.date_format = "%Y-%m-%d".into();
+        config.validate();
+        assert_eq!(config.date_format, "%Y-%m-%d");
+    }
+
+    #[test]
+    fn marker_tags_are_padded_to_the_widest_marker() {
+        let markers = Markers {
+            project: "[p]".into(),
+            ..Default::default()
+        };
+        assert_eq!(markers.tag(Some("[p]")), "[p] ");
+        assert_eq!(markers.tag(Some("i")), "i   ");
+        assert_eq!(markers.tag(None), "    ");
+        assert_eq!(Markers::default().tag(None), "  ");
     }
 
     #[test]
@@ -601,6 +1440,67 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn default_config_leaves_g_to_the_search_box() -> AppResult<()> {
+        // `gg` is handled by the vim keys in the results list; bound in the keymap, it would take
+        // every second `g` typed into the search box.
+        let c = Config::new()?;
+        let home = c.keybindings.get(&Mode::Home);
+        for keys in ["<g>", "<g><g>"] {
+            let keys = parse_key_sequence(keys).unwrap_or_default();
+            assert!(home.is_none_or(|bindings| !bindings.contains_key(&keys)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn default_home_shortcuts_come_from_the_keymap() -> AppResult<()> {
+        let c = Config::new()?;
+        let bound: Vec<(String, Vec<String>)> = c
+            .keybindings
+            .reference(Mode::Home)
+            .into_iter()
+            .filter(|bound| bound.category != "App")
+            .map(|bound| (bound.action, bound.keys))
+            .collect();
+
+        let expected = [
+            ("Copy search command", "Ctrl + y"),
+            ("Toggle help", "Ctrl + h"),
+            ("List all installed", "Ctrl + b"),
+            ("List all project", "Ctrl + p"),
+            ("Refresh", "Ctrl + r"),
+            ("Toggle normal deps only", "Ctrl + t"),
+        ]
+        .map(|(action, key)| (action.to_string(), vec![key.to_string()]));
+        assert_eq!(bound, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn home_shortcuts_give_way_to_a_component_that_wants_raw_keys() -> AppResult<()> {
+        let c = Config::new()?;
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        assert!(matches!(
+            c.keybindings.action_for(Mode::Home, &[ctrl('h')], false),
+            Some(Action::Home(HomeCommand::ToggleHelp))
+        ));
+        for key in ['h', 'r', 'p', 'b', 't', 'y'] {
+            assert!(
+                c.keybindings
+                    .action_for(Mode::Home, &[ctrl(key)], true)
+                    .is_none()
+            );
+        }
+        // App-wide bindings still apply.
+        assert!(matches!(
+            c.keybindings.action_for(Mode::Home, &[ctrl('c')], true),
+            Some(Action::Quit)
+        ));
+        Ok(())
+    }
+
     #[test]
     fn malformed_keybinding_is_an_error_not_a_panic() {
         let json = r#"{ "Home": { "<not-a-real-key>": "Quit" } }"#;
@@ -608,6 +1508,67 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn effective_json_lists_flags_styles_and_keybindings() -> AppResult<()> {
+        let config = Config::new()?;
+        let json: serde_json::Value = serde_json::from_str(&config.to_effective_json())?;
+        assert_eq!(json["request_timeout_secs"], 10);
+        assert_eq!(json["keybindings"]["App"]["<ctrl-c>"], "Quit");
+        assert!(json["styles"]["accent"].is_string());
+        Ok(())
+    }
+
+    fn resolve_user_bindings(json: &str) -> (KeyBindings, Vec<String>) {
+        json5::from_str::<RawKeyBindings>(json).unwrap().resolve()
+    }
+
+    #[test]
+    fn invalid_user_keybindings_are_reported_and_skipped() {
+        let (keybindings, errors) = resolve_user_bindings(
+            r#"{
+                Home: { "<q>": "Quit", "<not-a-real-key>": "Quit", "<x>": "Explode" },
+                Nowhere: { "<q>": "Quit" },
+            }"#,
+        );
+        assert_eq!(keybindings.get(&Mode::Home).unwrap().len(), 1);
+        assert_eq!(
+            errors,
+            vec![
+                "Home: unable to parse key `<not-a-real-key>`".to_string(),
+                "Home: `<x>` is bound to unknown action \"Explode\"".to_string(),
+                "unknown mode `Nowhere`".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn keys_bound_twice_in_a_mode_are_a_conflict() {
+        let (_, errors) =
+            resolve_user_bindings(r#"{ Home: { "<ctrl-a>": "Quit", "<Ctrl-A>": "Suspend" } }"#);
+        assert_eq!(
+            errors,
+            vec!["Home: `<ctrl-a>` is bound to both Suspend and Quit".to_string()]
+        );
+    }
+
+    #[test]
+    fn sequences_shadowed_by_a_single_key_are_reported() {
+        let (keybindings, _) =
+            resolve_user_bindings(r#"{ Home: { "<g>": "Quit", "<g><g>": "Suspend" } }"#);
+        assert_eq!(
+            keybindings.shadowed_sequences(),
+            vec!["Home: `g g` can never fire, `g` is bound on its own".to_string()]
+        );
+    }
+
+    #[test]
+    fn default_keybindings_are_valid() {
+        let default_config: RawConfig = json5::from_str(CONFIG).unwrap();
+        let (keybindings, errors) = default_config.keybindings.resolve();
+        assert_eq!(errors, Vec::<String>::new());
+        assert_eq!(keybindings.shadowed_sequences(), Vec::<String>::new());
+    }
+
     #[test]
     fn test_simple_keys() {
         assert_eq!(
@@ -689,4 +1650,52 @@ mod tests {
             KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT)
         );
     }
+
+    #[test]
+    fn reference_lists_every_key_per_action_with_app_wide_bindings() {
+        let mut keybindings = KeyBindings::default();
+        keybindings.insert(
+            Mode::App,
+            HashMap::from([
+                (parse_key_sequence("<Ctrl-c>").unwrap(), Action::Quit),
+                (parse_key_sequence("<q>").unwrap(), Action::Quit),
+                (parse_key_sequence("<x>").unwrap(), Action::Suspend),
+            ]),
+        );
+        keybindings.insert(
+            Mode::Home,
+            HashMap::from([
+                (
+                    parse_key_sequence("<g><g>").unwrap(),
+                    Action::Home(HomeCommand::GoToFirstResult),
+                ),
+                // Overrides the app-wide `x`.
+                (
+                    parse_key_sequence("<x>").unwrap(),
+                    Action::Home(HomeCommand::ToggleHelp),
+                ),
+            ]),
+        );
+
+        let bound = |category, action: &str, keys: &[&str]| BoundAction {
+            category,
+            action: action.to_string(),
+            keys: keys.iter().map(ToString::to_string).collect(),
+        };
+        assert_eq!(
+            keybindings.reference(Mode::Home),
+            vec![
+                bound("App", "Quit", &["Ctrl + c", "q"]),
+                bound("Home", "Go to first result", &["g g"]),
+                bound("Home", "Toggle help", &["x"]),
+            ]
+        );
+        assert_eq!(
+            keybindings.reference(Mode::App),
+            vec![
+                bound("App", "Quit", &["Ctrl + c", "q"]),
+                bound("App", "Suspend", &["x"]),
+            ]
+        );
+    }
 }
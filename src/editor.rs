@@ -0,0 +1,59 @@
+//! Launching the user's editor (`$VISUAL`, then `$EDITOR`).
+//!
+//! The editor takes over the terminal, so callers run [`open`] with the TUI released.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+use color_eyre::eyre::{WrapErr, bail, eyre};
+
+use crate::errors::AppResult;
+
+/// Opens `path` in the user's editor and waits for it to exit.
+pub fn open(path: &Path) -> AppResult<()> {
+    let command =
+        editor_command().ok_or_else(|| eyre!("set $EDITOR to open crates in an editor"))?;
+    let (program, args) = command
+        .split_first()
+        .expect("editor_command never returns an empty command");
+
+    let status = Command::new(program)
+        .args(args)
+        .arg(path)
+        .status()
+        .wrap_err_with(|| format!("failed to run `{program}`"))?;
+
+    if !status.success() {
+        bail!("`{program}` exited with {status}");
+    }
+    Ok(())
+}
+
+/// The editor command split into program and arguments (e.g. `code --wait`), from `$VISUAL` or
+/// `$EDITOR`, whichever is set first.
+fn editor_command() -> Option<Vec<String>> {
+    ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|var| env::var(var).ok())
+        .map(|value| split_command(&value))
+        .find(|command| !command.is_empty())
+}
+
+/// `value` split on whitespace into a program and its arguments.
+pub fn split_command(value: &str) -> Vec<String> {
+    value.split_whitespace().map(str::to_string).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn split_command_separates_the_program_from_its_arguments() {
+        assert_eq!(split_command("code --wait"), vec!["code", "--wait"]);
+        assert_eq!(split_command("  vim "), vec!["vim"]);
+        assert!(split_command("   ").is_empty());
+    }
+}
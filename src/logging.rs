@@ -3,6 +3,7 @@
 //! `init` writes the log under the data directory; verbosity follows `RUST_LOG` or
 //! `CARGO_SEEK_LOGLEVEL`.
 
+use std::path::PathBuf;
 use std::sync::LazyLock;
 use tracing_error::ErrorLayer;
 use tracing_subscriber::{EnvFilter, fmt, prelude::*};
@@ -12,11 +13,14 @@ use crate::config;
 static LOG_ENV: LazyLock<String> = LazyLock::new(|| format!("{}_LOGLEVEL", &*config::PROJECT_NAME));
 static LOG_FILE: LazyLock<String> = LazyLock::new(|| format!("{}.log", env!("CARGO_PKG_NAME")));
 
+/// Where the log is written: `cargo-seek.log` in the data directory.
+pub fn log_path() -> PathBuf {
+    config::get_data_dir().join(&*LOG_FILE)
+}
+
 pub fn init() -> color_eyre::Result<()> {
-    let directory = config::get_data_dir();
-    std::fs::create_dir_all(directory.clone())?;
-    let log_path = directory.join(&*LOG_FILE);
-    let log_file = std::fs::File::create(log_path)?;
+    std::fs::create_dir_all(config::get_data_dir())?;
+    let log_file = std::fs::File::create(log_path())?;
     let env_filter = EnvFilter::builder().with_default_directive(tracing::Level::INFO.into());
     // If the `RUST_LOG` environment variable is set, use that as the default, otherwise use the
     // value of the `LOG_ENV` environment variable. If the `LOG_ENV` environment variable contains
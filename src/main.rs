@@ -12,20 +12,29 @@
 
 mod action;
 mod app;
+mod batch;
 mod cargo;
 mod cli;
+mod clipboard;
 mod components;
 mod config;
+mod editor;
 mod errors;
 mod logging;
+mod notification;
+mod pager;
+mod readme;
 mod search;
 mod tui;
 mod util;
 
 use clap::Parser;
 use cli::Cli;
+use color_eyre::eyre::eyre;
+use std::path::Path;
 
 use crate::app::App;
+use crate::search::InitialSearch;
 
 #[tokio::main]
 async fn main() -> color_eyre::Result<()> {
@@ -34,25 +43,110 @@ async fn main() -> color_eyre::Result<()> {
 
     let args = filter_subcommand(std::env::args().collect());
     let args = Cli::parse_from(args);
+    if args.version_json {
+        println!("{}", cli::version_json());
+        return Ok(());
+    }
+
+    if let Some(manifest_path) = &args.manifest_path
+        && !manifest_path.is_file()
+    {
+        return Err(eyre!(
+            "manifest path `{}` is not a file",
+            manifest_path.display()
+        ));
+    }
+
+    if args.batch_add {
+        // cargo finds the project from the working directory, as it does for PROJECT_DIR
+        if let Some(project_dir) = &args.project_dir {
+            std::env::set_current_dir(project_dir)?;
+        }
+        cargo::set_manifest_path(args.manifest_path);
+        return batch::add_all(std::io::stdin().lock());
+    }
+
     let mut app = App::new(
         args.tick_rate,
         args.frame_rate,
         args.counter,
         args.project_dir,
-        args.search_term,
+        args.manifest_path,
+        InitialSearch {
+            term: args.search_term,
+            scope: args.scope,
+            sort: args.sort,
+        },
     )?;
     app.run().await?;
     Ok(())
 }
 
+/// The subcommand name cargo passes through as our first argument.
+const SUBCOMMAND: &str = "seek";
+
+/// Drops the `seek` token cargo inserts when running us as `cargo seek ...`, which arrives as
+/// `cargo-seek seek ...`. A direct `cargo-seek seek` could instead mean a project directory named
+/// `seek`, so outside of cargo the token is only dropped when no such directory exists.
 fn filter_subcommand(args: Vec<String>) -> Vec<String> {
-    // Check if the binary was invoked as a Cargo subcommand
-    if args.get(1).map(String::as_str) == Some("seek") {
-        // Skip the seek subcommand
-        std::iter::once(args[0].clone())
-            .chain(args.into_iter().skip(2))
-            .collect()
-    } else {
-        args
+    // Cargo sets `CARGO` in the environment of the subcommands it runs
+    let via_cargo = std::env::var_os("CARGO").is_some();
+    strip_subcommand(args, via_cargo, |dir| Path::new(dir).is_dir())
+}
+
+fn strip_subcommand(
+    mut args: Vec<String>,
+    via_cargo: bool,
+    is_dir: impl Fn(&str) -> bool,
+) -> Vec<String> {
+    if args.get(1).map(String::as_str) == Some(SUBCOMMAND) && (via_cargo || !is_dir(SUBCOMMAND)) {
+        args.remove(1);
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn strips_the_subcommand_cargo_passes() {
+        let stripped = strip_subcommand(
+            args(&[
+                "/home/me/.cargo/bin/cargo-seek",
+                "seek",
+                "../app",
+                "-s",
+                "serde",
+            ]),
+            true,
+            |_| true,
+        );
+        assert_eq!(
+            stripped,
+            args(&["/home/me/.cargo/bin/cargo-seek", "../app", "-s", "serde"])
+        );
+    }
+
+    #[test]
+    fn keeps_a_project_dir_as_the_first_positional() {
+        let direct = args(&["cargo-seek", "../app", "--counter"]);
+        assert_eq!(strip_subcommand(direct.clone(), false, |_| true), direct);
+        assert_eq!(strip_subcommand(direct.clone(), true, |_| true), direct);
+    }
+
+    #[test]
+    fn direct_invocation_keeps_seek_when_it_names_a_directory() {
+        let direct = args(&["cargo-seek", "seek"]);
+        assert_eq!(strip_subcommand(direct.clone(), false, |_| true), direct);
+        assert_eq!(
+            strip_subcommand(direct, false, |_| false),
+            args(&["cargo-seek"])
+        );
     }
 }
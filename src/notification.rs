@@ -0,0 +1,53 @@
+//! Desktop notifications for cargo commands that finish while the user is away.
+//!
+//! Add and install run attached to the terminal, so focus changes can't be observed while they
+//! run; a command that took at least [`MIN_DURATION`] is assumed to have been left alone.
+
+use std::time::Duration;
+
+use color_eyre::eyre::WrapErr;
+use tracing::warn;
+
+use crate::errors::AppResult;
+
+/// Commands quicker than this finish while the user is still watching, so they don't notify.
+pub const MIN_DURATION: Duration = Duration::from_secs(10);
+
+/// Whether a command that ran for `elapsed` should notify, given the `notify_on_completion` flag.
+pub fn should_notify(enabled: bool, elapsed: Duration) -> bool {
+    enabled && elapsed >= MIN_DURATION
+}
+
+/// Shows `summary` as a desktop notification in the background. Failures (e.g. no notification
+/// daemon) are only logged.
+pub fn notify(summary: String) {
+    tokio::task::spawn_blocking(move || {
+        if let Err(report) = show(&summary) {
+            warn!("{report:#}");
+        }
+    });
+}
+
+fn show(summary: &str) -> AppResult<()> {
+    notify_rust::Notification::new()
+        .appname("cargo-seek")
+        .summary(summary)
+        .show()
+        .wrap_err("failed to show a desktop notification")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_long_commands_notify_and_only_when_enabled() {
+        assert!(should_notify(true, MIN_DURATION));
+        assert!(!should_notify(
+            true,
+            MIN_DURATION - Duration::from_millis(1)
+        ));
+        assert!(!should_notify(false, Duration::from_secs(600)));
+    }
+}
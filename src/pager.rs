@@ -0,0 +1,43 @@
+//! Showing long output in the user's pager (`$PAGER`, else `less`).
+//!
+//! The pager takes over the terminal, so callers run [`show`] with the TUI released.
+
+use std::env;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use color_eyre::eyre::WrapErr;
+
+use crate::editor;
+use crate::errors::AppResult;
+
+/// Pipes `text` into the pager and waits for it to exit.
+pub fn show(text: &str) -> AppResult<()> {
+    let command = pager_command();
+    let (program, args) = command
+        .split_first()
+        .expect("pager_command never returns an empty command");
+
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .spawn()
+        .wrap_err_with(|| format!("failed to run `{program}`"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The pager closes its end when quit before reading everything; that's not an error.
+        let _ = stdin.write_all(text.as_bytes());
+    }
+    child
+        .wait()
+        .wrap_err_with(|| format!("failed to run `{program}`"))?;
+    Ok(())
+}
+
+/// The pager command split into program and arguments, from `$PAGER` when set, else `less`.
+fn pager_command() -> Vec<String> {
+    env::var("PAGER")
+        .ok()
+        .map(|value| editor::split_command(&value))
+        .filter(|command| !command.is_empty())
+        .unwrap_or_else(|| vec!["less".to_string()])
+}
@@ -0,0 +1,96 @@
+//! Rendering a crate's README in the terminal with `glow`, else `mdcat`.
+//!
+//! The renderer takes over the terminal, so callers run [`render`] with the TUI released, and fall
+//! back to opening the crate's repository when it fails.
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use color_eyre::eyre::{WrapErr, eyre};
+
+use crate::errors::AppResult;
+
+/// The markdown renderers tried in order, with the arguments that make them page their output.
+const RENDERERS: [(&str, &[&str]); 2] = [("glow", &["--pager"]), ("mdcat", &["--paging"])];
+
+/// Writes `markdown` to a temporary file and shows it with the first renderer that can be run,
+/// waiting for it to exit. The file is removed afterwards, whether or not one could.
+pub fn render(markdown: &str) -> AppResult<()> {
+    let path = std::env::temp_dir().join(format!("cargo-seek-readme-{}.md", std::process::id()));
+    render_with(&RENDERERS, markdown, &path)
+}
+
+fn render_with(renderers: &[(&str, &[&str])], markdown: &str, path: &Path) -> AppResult<()> {
+    fs::write(path, markdown).wrap_err("failed to write the README to a temporary file")?;
+    let _remove = RemoveOnDrop(path);
+
+    for (program, args) in renderers {
+        match Command::new(program).args(*args).arg(path).status() {
+            Ok(_) => return Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => continue,
+            Err(err) => return Err(err).wrap_err_with(|| format!("failed to run `{program}`")),
+        }
+    }
+    let names: Vec<&str> = renderers.iter().map(|(program, _)| *program).collect();
+    Err(eyre!("couldn't find {} to render it", names.join(" or ")))
+}
+
+/// The README in a package's source directory, preferring a markdown one, e.g. `README.md` over
+/// `README`.
+pub fn find(dir: &Path) -> Option<PathBuf> {
+    let mut readmes: Vec<PathBuf> = fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .is_some_and(|name| name.to_string_lossy().to_lowercase().starts_with("readme"))
+        })
+        .collect();
+    readmes.sort_by_key(|path| (path.extension().is_none_or(|ext| ext != "md"), path.clone()));
+    readmes.into_iter().next()
+}
+
+/// Removes the file at its path when dropped.
+struct RemoveOnDrop<'a>(&'a Path);
+
+impl Drop for RemoveOnDrop<'_> {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[test]
+    fn render_fails_and_cleans_up_when_no_renderer_can_run() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("README.md");
+
+        let result = render_with(&[("cargo-seek-no-such-renderer", &[])], "# serde", &path);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "couldn't find cargo-seek-no-such-renderer to render it"
+        );
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn find_prefers_a_markdown_readme() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(find(dir.path()), None);
+
+        fs::write(dir.path().join("README"), "").unwrap();
+        assert_eq!(find(dir.path()), Some(dir.path().join("README")));
+        fs::write(dir.path().join("README.md"), "").unwrap();
+        assert_eq!(find(dir.path()), Some(dir.path().join("README.md")));
+    }
+}
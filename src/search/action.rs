@@ -1,7 +1,7 @@
 use serde::Deserialize;
 use strum::Display;
 
-use crate::search::{Scope, SearchResults, Sort};
+use crate::search::{CrateDependency, OutdatedBinary, RequiredLink, Scope, SearchResults, Sort};
 
 /// A search instruction: run/clear a search, change sort/scope, paginate, or move the selection.
 #[derive(Debug, Clone, PartialEq, Eq, Display, Deserialize)]
@@ -13,8 +13,30 @@ pub enum SearchCommand {
         hide_help: bool,
         status: Option<String>,
     },
+    /// Re-read the cargo environment, then re-run the current search on the current page.
+    Refresh,
     SortBy(Sort),
+    /// Switch to the next/previous sort and re-run the search, without leaving the results.
+    NextSort,
+    PrevSort,
     Scope(Scope),
+    /// Switch to the next/previous scope and re-run the search, without leaving the results.
+    NextScope,
+    PrevScope,
+    /// Switch to this scope and list everything in it (an empty search), e.g. every project
+    /// dependency.
+    #[strum(to_string = "ListAll{0}")]
+    ListAll(Scope),
+    /// Switch to the online scope sorted by downloads and list the most downloaded crates.
+    ListPopular,
+    /// Filter online results to crates owned by this crates.io username; `None` clears it.
+    Owner(Option<String>),
+    /// Filter online results to crates with this crates.io keyword; `None` clears it.
+    Keyword(Option<String>),
+    /// Toggle leaving crates that are only dev or build dependencies out of project results.
+    ToggleNormalDepsOnly,
+    /// Toggle hiding online results without this link; `None` shows them all again.
+    ToggleLinkFilter(Option<RequiredLink>),
     NavPagesForward(usize),
     NavPagesBack(usize),
     NavFirstPage,
@@ -29,14 +51,42 @@ pub enum SearchCommand {
 /// The result of search-related work performed off the UI thread.
 #[derive(Debug, Clone, Display)]
 pub enum SearchEvent {
-    /// A search finished and produced these results.
-    Completed(SearchResults),
+    /// A search finished and produced these results. `generation` identifies the search (see
+    /// [`CrateSearchManager::is_current`](crate::search::CrateSearchManager::is_current)).
+    Completed {
+        generation: u64,
+        results: SearchResults,
+    },
+    /// A search found these results so far (the project and installed ones) and is still
+    /// searching online. Followed by `Completed` or `Failed` for the same generation.
+    Partial {
+        generation: u64,
+        results: SearchResults,
+    },
     /// A search failed with this message.
-    Failed(String),
+    Failed { generation: u64, message: String },
     /// The selected crate's metadata finished loading.
     MetadataLoaded {
         response: Box<crates_io_api::CrateResponse>,
     },
+    /// The named crate's daily download history (oldest first) finished loading.
+    DownloadsLoaded { name: String, daily: Vec<u64> },
+    /// The open issue count of the named crate's GitHub repository finished loading.
+    OpenIssuesLoaded { name: String, open_issues: u64 },
+    /// The direct dependencies of the named crate's version finished loading.
+    DependenciesLoaded {
+        name: String,
+        dependencies: Vec<CrateDependency>,
+    },
+    /// The most downloaded crates sharing the named crate's top category or keyword finished
+    /// loading.
+    RelatedLoaded { name: String, related: Vec<String> },
+    /// Checking the installed binaries finished: these have a newer release, and `unchecked`
+    /// couldn't be looked up (e.g. ones installed from git).
+    BinariesChecked {
+        outdated: Vec<OutdatedBinary>,
+        unchecked: usize,
+    },
     /// Lazy hydration of the named crate's metadata failed with this message.
     MetadataFailed { name: String, message: String },
 }
@@ -1,6 +1,86 @@
 use chrono::{DateTime, Utc};
+use reqwest::Url;
+use semver::{Version, VersionReq};
+use std::collections::BTreeMap;
+use strum::Display;
 
-use crate::cargo::{Dependency, InstalledBinary};
+use crate::cargo::{Dependency, InstalledBinary, SearchHit};
+
+/// A dependency a published crate version declares, as crates.io lists it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrateDependency {
+    pub name: String,
+    /// The version requirement, e.g. `^1.0`.
+    pub req: String,
+    /// `normal`, `build` or `dev`.
+    pub kind: String,
+    pub optional: bool,
+}
+
+impl CrateDependency {
+    /// Lists crates.io's dependencies normal ones first, then build and dev ones, each by name.
+    pub fn sorted(dependencies: Vec<crates_io_api::Dependency>) -> Vec<Self> {
+        let mut dependencies: Vec<Self> = dependencies
+            .into_iter()
+            .map(|dep| Self {
+                name: dep.crate_id,
+                req: dep.req,
+                kind: dep.kind,
+                optional: dep.optional,
+            })
+            .collect();
+        dependencies.sort_by_cached_key(|dep| {
+            let rank = match dep.kind.as_str() {
+                "normal" => 0,
+                "build" => 1,
+                _ => 2,
+            };
+            (rank, dep.name.clone())
+        });
+        dependencies
+    }
+}
+
+/// How a crate's features changed between two of its versions (see [`Crate::feature_diff`]).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeatureDiff {
+    pub from: String,
+    pub to: String,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    /// Features that joined the `default` set.
+    pub defaults_added: Vec<String>,
+    /// Features that left the `default` set.
+    pub defaults_removed: Vec<String>,
+}
+
+impl FeatureDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.defaults_added.is_empty()
+            && self.defaults_removed.is_empty()
+    }
+}
+
+/// How widely used a crate is, going by its all-time downloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Popularity {
+    /// Under 10k downloads.
+    Obscure,
+    Moderate,
+    /// 1M downloads or more.
+    Popular,
+}
+
+/// Where a search result came from. Results are listed grouped in this order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Display)]
+pub enum ResultSource {
+    Project,
+    Installed,
+    #[default]
+    Registry,
+}
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Crate {
@@ -15,32 +95,199 @@ pub struct Crate {
     pub max_stable_version: Option<String>,
     pub downloads: Option<u64>,
     pub recent_downloads: Option<u64>,
+    /// Downloads per day over crates.io's recent window (~90 days), oldest first. `None` until
+    /// loaded, or when crates.io didn't return any history.
+    pub daily_downloads: Option<Vec<u64>>,
     /// The crate's selectable feature names (the `default` umbrella excluded), sorted. `None` until
     /// metadata is hydrated; `Some(empty)` for a crate with no features.
     pub features: Option<Vec<String>>,
     /// Names of the features enabled by the crate's `default` feature.
     pub default_features: Vec<String>,
+    /// Size in bytes of the `.crate` file published for `version`. `None` until metadata is
+    /// hydrated, or when crates.io doesn't report it.
+    pub crate_size: Option<u64>,
+    /// The features declared by every non-yanked version, newest first, as `(version, features)`.
+    /// Empty until metadata is hydrated.
+    pub version_features: Vec<(String, BTreeMap<String, Vec<String>>)>,
+    /// Open issues and pull requests in the crate's GitHub repository. Only loaded with
+    /// `fetch_github_stats` on.
+    pub open_issues: Option<u64>,
+    /// The direct dependencies of `version`, sorted with [`CrateDependency::sorted`]. `None` until
+    /// asked for, as they take a request of their own.
+    pub dependencies: Option<Vec<CrateDependency>>,
+    /// The most downloaded crates sharing its top category (or a keyword). Only loaded with
+    /// `show_related_crates` on.
+    pub related: Option<Vec<String>>,
     pub categories: Option<Vec<String>>,
+    /// At-a-glance suitability badges, e.g. `no_std`, `wasm` or `MSRV 1.70` (see [`badges`]).
+    /// Empty until metadata is hydrated.
+    pub badges: Vec<String>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
 
     pub exact_match: bool,
     /// Whether full metadata has been hydrated for this crate (see [`Crate::is_metadata_loaded`]).
     pub metadata_loaded: bool,
+    /// Hydrating this crate's metadata failed (e.g. a local crate that isn't on crates.io), so
+    /// whatever is missing now stays missing.
+    pub metadata_failed: bool,
     pub project_version: Option<String>,
     pub installed_version: Option<String>,
+    pub source: ResultSource,
 }
 
 impl Crate {
+    /// `None` for crates with no download count, e.g. local-only ones.
+    pub fn popularity(&self) -> Option<Popularity> {
+        self.downloads.map(|downloads| match downloads {
+            ..10_000 => Popularity::Obscure,
+            1_000_000.. => Popularity::Popular,
+            _ => Popularity::Moderate,
+        })
+    }
+
     pub fn is_metadata_loaded(&self) -> bool {
         self.metadata_loaded
     }
 
+    /// Whether this crate's metadata is still expected to arrive.
+    pub fn is_metadata_loading(&self) -> bool {
+        !self.metadata_loaded && !self.metadata_failed
+    }
+
+    /// The newest published version: the newest stable one unless `show_prereleases` is set or
+    /// the crate has no stable release. `None` until registry data is loaded.
+    pub fn latest_version(&self, show_prereleases: bool) -> Option<&str> {
+        if show_prereleases {
+            self.max_version.as_deref()
+        } else {
+            self.max_stable_version
+                .as_deref()
+                .or(self.max_version.as_deref())
+        }
+    }
+
+    /// The latest stable version when the project's requirement doesn't allow it, i.e. the
+    /// dependency is outdated. `None` when it's up to date, the requirement or version can't be
+    /// parsed, or the crate isn't a project dependency.
+    ///
+    /// Workspace members with differing requirements show up joined (`"1.0, 2.0"`), which parses
+    /// as all of them at once, so the crate counts as outdated when any member is behind.
+    pub fn update_available(&self) -> Option<&str> {
+        let req = VersionReq::parse(self.project_version.as_deref()?).ok()?;
+        let stable = self.max_stable_version.as_deref()?;
+        let latest = Version::parse(stable).ok()?;
+
+        // A requirement that excludes the latest version only because it points past it (or at
+        // something unusual like `<1.0`) isn't outdated.
+        let is_ahead_of_every_bound = req
+            .comparators
+            .iter()
+            .all(|c| latest > Version::new(c.major, c.minor.unwrap_or(0), c.patch.unwrap_or(0)));
+
+        (!req.matches(&latest) && is_ahead_of_every_bound).then_some(stable)
+    }
+
+    /// How features changed from the newest version the project's requirement allows to the
+    /// [latest version](Self::latest_version). `None` when the crate isn't a project dependency,
+    /// its metadata isn't loaded, or either version can't be found.
+    pub fn feature_diff(&self, show_prereleases: bool) -> Option<FeatureDiff> {
+        let req = VersionReq::parse(self.project_version.as_deref()?).ok()?;
+        let (from, from_features) = self
+            .version_features
+            .iter()
+            .find(|(num, _)| Version::parse(num).is_ok_and(|v| req.matches(&v)))?;
+        let to = self.latest_version(show_prereleases)?;
+        let (_, to_features) = self.version_features.iter().find(|(num, _)| num == to)?;
+
+        let names = |features: &BTreeMap<String, Vec<String>>| -> Vec<String> {
+            features
+                .keys()
+                .filter(|f| *f != "default")
+                .cloned()
+                .collect()
+        };
+        let defaults = |features: &BTreeMap<String, Vec<String>>| -> Vec<String> {
+            let mut defaults = features.get("default").cloned().unwrap_or_default();
+            defaults.sort();
+            defaults
+        };
+        let missing_from = |a: &[String], b: &[String]| -> Vec<String> {
+            a.iter().filter(|f| !b.contains(f)).cloned().collect()
+        };
+
+        let (old, new) = (names(from_features), names(to_features));
+        let (old_defaults, new_defaults) = (defaults(from_features), defaults(to_features));
+        Some(FeatureDiff {
+            from: from.clone(),
+            to: to.to_string(),
+            added: missing_from(&new, &old),
+            removed: missing_from(&old, &new),
+            defaults_added: missing_from(&new_defaults, &old_defaults),
+            defaults_removed: missing_from(&old_defaults, &new_defaults),
+        })
+    }
+
     /// Whether `feature` is enabled by the crate's default feature set.
     pub fn is_default_feature(&self, feature: &str) -> bool {
         self.default_features.iter().any(|f| f == feature)
     }
 
+    /// A `git clone` command for the crate's repository when it's on GitHub or GitLab. Repository
+    /// links often point into the tree (e.g. `/tree/main/subcrate`) or vary in a trailing `.git`;
+    /// the command always clones the repository itself.
+    pub fn clone_command(&self) -> Option<String> {
+        self.repository
+            .as_deref()
+            .and_then(clone_url)
+            .map(|url| format!("git clone {url}"))
+    }
+
+    /// Every link the crate has, as `(label, url)`: its homepage, docs and repository when set,
+    /// then its crates.io and lib.rs pages. A URL set for several of them (often the homepage is
+    /// the repository) is listed once, under the first.
+    pub fn links(&self) -> Vec<(&'static str, String)> {
+        let mut links: Vec<(&'static str, String)> = Vec::new();
+        let optional = [
+            ("Homepage", &self.homepage),
+            ("Docs", &self.documentation),
+            ("Repository", &self.repository),
+        ];
+        for (label, url) in optional {
+            if let Some(url) = url
+                && !links.iter().any(|(_, listed)| listed == url)
+            {
+                links.push((label, url.clone()));
+            }
+        }
+        links.push(("crates.io", format!("https://crates.io/crates/{}", self.id)));
+        links.push(("lib.rs", format!("https://lib.rs/crates/{}", self.id)));
+        links
+    }
+
+    /// The `Cargo.toml` dependency line for this crate at its resolved version, e.g.
+    /// `serde = "1.0.200"`.
+    ///
+    /// With `with_features` and loaded features, the defaults are spelled out explicitly (behind
+    /// `default-features = false`) so unwanted ones can be pruned by hand. Returns the plain line
+    /// when features aren't loaded yet or the crate has no default features.
+    pub fn dependency_snippet(&self, with_features: bool) -> String {
+        if with_features && self.features.is_some() && !self.default_features.is_empty() {
+            let features = self
+                .default_features
+                .iter()
+                .map(|f| format!("\"{f}\""))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "{} = {{ version = \"{}\", default-features = false, features = [{features}] }}",
+                self.name, self.version
+            )
+        } else {
+            format!("{} = \"{}\"", self.name, self.version)
+        }
+    }
+
     /// Builds a stub crate from a globally installed binary.
     pub fn from_binary(bin: &InstalledBinary) -> Self {
         Crate {
@@ -48,6 +295,7 @@ impl Crate {
             name: bin.name.clone(),
             version: bin.version.clone(),
             installed_version: Some(bin.version.clone()),
+            source: ResultSource::Installed,
             ..Default::default()
         }
     }
@@ -59,24 +307,38 @@ impl Crate {
             name: dep.name.clone(),
             version: dep.req.clone(),
             project_version: Some(dep.req.clone()),
+            source: ResultSource::Project,
+            ..Default::default()
+        }
+    }
+
+    /// Builds a crate from an alternate registry's `cargo search` hit. There is no metadata API to
+    /// hydrate from, so the crate is marked loaded as-is, with no known features.
+    pub fn from_search_hit(hit: SearchHit) -> Self {
+        Crate {
+            id: hit.name.clone(),
+            name: hit.name,
+            description: hit.description,
+            version: hit.version.clone(),
+            max_version: Some(hit.version),
+            features: Some(Vec::new()),
+            metadata_loaded: true,
             ..Default::default()
         }
     }
 
     /// Builds a crate from a crates.io search result. Metadata-only fields (`features`,
     /// `project_version`, `installed_version`) are left for later hydration/annotation.
-    pub fn from_crates_io(c: crates_io_api::Crate) -> Self {
-        Crate {
+    ///
+    /// `version` is the [latest version](Self::latest_version) per `show_prereleases`.
+    pub fn from_crates_io(c: crates_io_api::Crate, show_prereleases: bool) -> Self {
+        let mut cr = Crate {
             id: c.id,
             name: c.name,
             description: c.description,
             homepage: c.homepage,
             documentation: c.documentation,
             repository: c.repository,
-            version: c
-                .max_stable_version
-                .clone()
-                .unwrap_or_else(|| c.max_version.clone()),
             max_version: Some(c.max_version),
             max_stable_version: c.max_stable_version,
             downloads: Some(c.downloads),
@@ -86,26 +348,40 @@ impl Crate {
             categories: c.categories,
             exact_match: c.exact_match.unwrap_or(false),
             ..Default::default()
-        }
+        };
+        cr.version = cr
+            .latest_version(show_prereleases)
+            .unwrap_or_default()
+            .to_string();
+        cr
     }
 
     /// Fills in full metadata from a crates.io response (the lazy hydration of a selected crate).
-    pub fn hydrate(&mut self, response: &crates_io_api::CrateResponse) {
+    /// `version` and the features become those of the [latest version](Self::latest_version) per
+    /// `show_prereleases`.
+    pub fn hydrate(&mut self, response: &crates_io_api::CrateResponse, show_prereleases: bool) {
         let data = &response.crate_data;
         self.name = data.name.clone();
         self.description = data.description.clone();
         self.homepage = data.homepage.clone();
         self.documentation = data.documentation.clone();
         self.repository = data.repository.clone();
-        self.version = data
-            .max_stable_version
-            .clone()
-            .unwrap_or_else(|| data.max_version.clone());
         self.max_version = Some(data.max_version.clone());
         self.max_stable_version = data.max_stable_version.clone();
+        self.version = self
+            .latest_version(show_prereleases)
+            .unwrap_or_default()
+            .to_string();
         self.downloads = Some(data.downloads);
         self.recent_downloads = data.recent_downloads;
-        if let Some(latest) = response.versions.first() {
+        // Versions are listed newest first, so the first one may be a pre-release newer than
+        // `version`.
+        let latest = response
+            .versions
+            .iter()
+            .find(|v| v.num == self.version)
+            .or(response.versions.first());
+        if let Some(latest) = latest {
             self.default_features = latest.features.get("default").cloned().unwrap_or_default();
             // Drop `default` since it isn't an individually selectable feature.
             let mut features: Vec<String> = latest
@@ -116,10 +392,21 @@ impl Crate {
                 .collect();
             features.sort();
             self.features = Some(features);
+            self.crate_size = latest.crate_size;
         } else {
             self.features = Some(Vec::new());
             self.default_features = Vec::new();
+            self.crate_size = None;
         }
+        self.version_features = response
+            .versions
+            .iter()
+            .filter(|v| !v.yanked)
+            .map(|v| {
+                let features = v.features.iter().map(|(k, v)| (k.clone(), v.clone()));
+                (v.num.clone(), features.collect())
+            })
+            .collect();
         if self.categories.is_none() {
             self.categories = Some(
                 response
@@ -129,9 +416,333 @@ impl Crate {
                     .collect(),
             )
         }
+        let category_slugs: Vec<&str> = response
+            .categories
+            .iter()
+            .map(|c| c.slug.as_str())
+            .collect();
+        let keywords: Vec<&str> = response
+            .keywords
+            .iter()
+            .map(|k| k.keyword.as_str())
+            .collect();
+        self.badges = badges(
+            &category_slugs,
+            &keywords,
+            latest.and_then(|v| v.rust_version.as_deref()),
+        );
         self.created_at = Some(data.created_at);
         self.updated_at = Some(data.updated_at);
         self.exact_match = data.exact_match.unwrap_or_default();
         self.metadata_loaded = true;
     }
 }
+
+/// Derives badges from a crate's category slugs and keywords, and the `rust-version` of its latest
+/// version. crates.io doesn't say whether a crate has a build script, so there's no badge for it.
+fn badges(category_slugs: &[&str], keywords: &[&str], rust_version: Option<&str>) -> Vec<String> {
+    let tagged = |categories: &[&str], tags: &[&str]| {
+        category_slugs.iter().any(|slug| {
+            categories
+                .iter()
+                .any(|c| slug == c || slug.starts_with(&format!("{c}::")))
+        }) || keywords
+            .iter()
+            .any(|keyword| tags.contains(&keyword.to_lowercase().as_str()))
+    };
+
+    let mut badges = Vec::new();
+    if tagged(&["no-std"], &["no_std", "no-std", "nostd"]) {
+        badges.push("no_std".to_string());
+    }
+    if tagged(&["wasm"], &["wasm", "wasm32", "webassembly"]) {
+        badges.push("wasm".to_string());
+    }
+    if tagged(&["embedded"], &["embedded"]) {
+        badges.push("embedded".to_string());
+    }
+    if let Some(rust_version) = rust_version {
+        badges.push(format!("MSRV {rust_version}"));
+    }
+    badges
+}
+
+/// The clone URL of a GitHub or GitLab repository link. GitLab projects can sit in nested groups,
+/// so its path runs up to the `-` (or legacy `tree`/`blob`) segment.
+fn clone_url(repository: &str) -> Option<String> {
+    let url = Url::parse(repository.trim()).ok()?;
+    let host = url.host_str()?.trim_start_matches("www.");
+    let segments: Vec<&str> = url.path_segments()?.filter(|s| !s.is_empty()).collect();
+    let path = match host {
+        "github.com" => segments.get(..2)?.to_vec(),
+        "gitlab.com" => segments
+            .into_iter()
+            .take_while(|s| !matches!(*s, "-" | "tree" | "blob"))
+            .collect(),
+        _ => return None,
+    };
+    if path.len() < 2 {
+        return None;
+    }
+
+    let path = path.join("/");
+    let path = path.strip_suffix(".git").unwrap_or(&path);
+    Some(format!("https://{host}/{path}.git"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn features(names: &[&str], defaults: &[&str]) -> BTreeMap<String, Vec<String>> {
+        let mut features: BTreeMap<String, Vec<String>> = names
+            .iter()
+            .map(|name| (name.to_string(), Vec::new()))
+            .collect();
+        features.insert(
+            "default".into(),
+            defaults.iter().map(|name| name.to_string()).collect(),
+        );
+        features
+    }
+
+    #[test]
+    fn feature_diff_compares_the_allowed_version_with_the_latest() {
+        let cr = Crate {
+            project_version: Some("1.1".into()),
+            max_stable_version: Some("2.0.0".into()),
+            version_features: vec![
+                (
+                    "2.0.0".into(),
+                    features(&["std", "serde"], &["std", "serde"]),
+                ),
+                ("1.2.0".into(), features(&["std", "alloc"], &["std"])),
+                ("1.1.0".into(), features(&["std"], &["std"])),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            cr.feature_diff(false),
+            Some(FeatureDiff {
+                from: "1.2.0".into(),
+                to: "2.0.0".into(),
+                added: vec!["serde".into()],
+                removed: vec!["alloc".into()],
+                defaults_added: vec!["serde".into()],
+                defaults_removed: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn feature_diff_needs_a_project_dependency() {
+        let cr = Crate {
+            max_stable_version: Some("1.0.0".into()),
+            version_features: vec![("1.0.0".into(), features(&[], &[]))],
+            ..Default::default()
+        };
+        assert_eq!(cr.feature_diff(false), None);
+    }
+
+    fn with_versions(max_version: &str, max_stable_version: Option<&str>) -> Crate {
+        Crate {
+            max_version: Some(max_version.into()),
+            max_stable_version: max_stable_version.map(Into::into),
+            ..Default::default()
+        }
+    }
+
+    fn dependency(project_version: &str, max_stable_version: &str) -> Crate {
+        Crate {
+            project_version: Some(project_version.into()),
+            max_stable_version: Some(max_stable_version.into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn update_available_when_the_requirement_excludes_the_latest_stable() {
+        assert_eq!(
+            dependency("0.11", "0.12.3").update_available(),
+            Some("0.12.3")
+        );
+        assert_eq!(
+            dependency("=1.0.0", "1.0.1").update_available(),
+            Some("1.0.1")
+        );
+        // One workspace member is behind.
+        assert_eq!(
+            dependency("1.0, 2.0", "2.3.0").update_available(),
+            Some("2.3.0")
+        );
+    }
+
+    #[test]
+    fn no_update_when_the_requirement_allows_the_latest_stable() {
+        assert_eq!(dependency("1.0", "1.9.2").update_available(), None);
+        assert_eq!(dependency("*", "3.0.0").update_available(), None);
+        assert_eq!(dependency("2.0", "1.9.0").update_available(), None);
+        assert_eq!(dependency("not a req", "1.0.0").update_available(), None);
+        assert_eq!(Crate::default().update_available(), None);
+    }
+
+    #[test]
+    fn latest_version_prefers_stable_unless_prereleases_are_shown() {
+        let cr = with_versions("2.0.0-rc.1", Some("1.9.0"));
+        assert_eq!(cr.latest_version(false), Some("1.9.0"));
+        assert_eq!(cr.latest_version(true), Some("2.0.0-rc.1"));
+    }
+
+    #[test]
+    fn latest_version_falls_back_to_a_prerelease_without_a_stable_release() {
+        let cr = with_versions("0.1.0-alpha.3", None);
+        assert_eq!(cr.latest_version(false), Some("0.1.0-alpha.3"));
+        assert_eq!(Crate::default().latest_version(false), None);
+    }
+
+    fn serde(features: Option<&[&str]>, defaults: &[&str]) -> Crate {
+        Crate {
+            id: "serde".into(),
+            name: "serde".into(),
+            version: "1.0.200".into(),
+            features: features.map(|fs| fs.iter().map(|s| s.to_string()).collect()),
+            default_features: defaults.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn dependency_snippet_is_a_plain_version_line() {
+        let cr = serde(Some(&["derive", "std"]), &["std"]);
+        assert_eq!(cr.dependency_snippet(false), r#"serde = "1.0.200""#);
+    }
+
+    #[test]
+    fn dependency_snippet_spells_out_default_features() {
+        let cr = serde(Some(&["alloc", "derive", "std"]), &["alloc", "std"]);
+        assert_eq!(
+            cr.dependency_snippet(true),
+            r#"serde = { version = "1.0.200", default-features = false, features = ["alloc", "std"] }"#
+        );
+    }
+
+    #[test]
+    fn dependency_snippet_falls_back_to_plain_without_loaded_defaults() {
+        assert_eq!(
+            serde(None, &["std"]).dependency_snippet(true),
+            r#"serde = "1.0.200""#
+        );
+        assert_eq!(
+            serde(Some(&["derive"]), &[]).dependency_snippet(true),
+            r#"serde = "1.0.200""#
+        );
+    }
+
+    #[test]
+    fn clone_url_points_at_the_repository_root() {
+        assert_eq!(
+            clone_url("https://github.com/serde-rs/serde"),
+            Some("https://github.com/serde-rs/serde.git".into())
+        );
+        assert_eq!(
+            clone_url("https://www.github.com/rust-lang/futures-rs/tree/master/futures-util/"),
+            Some("https://github.com/rust-lang/futures-rs.git".into())
+        );
+        assert_eq!(
+            clone_url("https://github.com/tokio-rs/tokio.git"),
+            Some("https://github.com/tokio-rs/tokio.git".into())
+        );
+        assert_eq!(
+            clone_url("https://gitlab.com/group/subgroup/project/-/tree/main"),
+            Some("https://gitlab.com/group/subgroup/project.git".into())
+        );
+    }
+
+    #[test]
+    fn clone_url_is_none_for_other_hosts() {
+        assert_eq!(clone_url("https://codeberg.org/a/b"), None);
+        assert_eq!(clone_url("https://github.com/only-owner"), None);
+        assert_eq!(clone_url("not a url"), None);
+    }
+
+    #[test]
+    fn links_list_each_url_once() {
+        let cr = Crate {
+            id: "serde".into(),
+            homepage: Some("https://serde.rs".into()),
+            documentation: Some("https://docs.rs/serde".into()),
+            repository: Some("https://serde.rs".into()),
+            ..Default::default()
+        };
+        let labels: Vec<_> = cr.links().into_iter().map(|(label, _)| label).collect();
+        assert_eq!(labels, vec!["Homepage", "Docs", "crates.io", "lib.rs"]);
+
+        let bare = Crate {
+            id: "demo".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            bare.links(),
+            vec![
+                ("crates.io", "https://crates.io/crates/demo".to_string()),
+                ("lib.rs", "https://lib.rs/crates/demo".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn badges_come_from_categories_keywords_and_the_msrv() {
+        assert_eq!(
+            badges(&["no-std::no-alloc", "embedded"], &[], Some("1.70")),
+            vec!["no_std", "embedded", "MSRV 1.70"]
+        );
+        assert_eq!(badges(&["wasm"], &["WebAssembly"], None), vec!["wasm"]);
+        assert_eq!(
+            badges(&["no-stdlib"], &["embedded-hal"], None),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn popularity_goes_by_downloads() {
+        let with = |downloads| Crate {
+            downloads,
+            ..Default::default()
+        };
+        assert_eq!(with(None).popularity(), None);
+        assert_eq!(with(Some(9_999)).popularity(), Some(Popularity::Obscure));
+        assert_eq!(with(Some(10_000)).popularity(), Some(Popularity::Moderate));
+        assert_eq!(
+            with(Some(1_000_000)).popularity(),
+            Some(Popularity::Popular)
+        );
+    }
+
+    #[test]
+    fn dependencies_sort_normal_then_build_then_dev() {
+        let dep = |name: &str, kind: &str| crates_io_api::Dependency {
+            crate_id: name.into(),
+            default_features: true,
+            downloads: 0,
+            features: Vec::new(),
+            id: 0,
+            kind: kind.into(),
+            optional: false,
+            req: "^1".into(),
+            target: None,
+            version_id: 0,
+        };
+        let sorted = CrateDependency::sorted(vec![
+            dep("serde_json", "dev"),
+            dep("serde_derive", "normal"),
+            dep("cc", "build"),
+            dep("itoa", "normal"),
+        ]);
+        assert_eq!(
+            sorted.iter().map(|d| d.name.as_str()).collect::<Vec<_>>(),
+            vec!["itoa", "serde_derive", "cc", "serde_json"]
+        );
+    }
+}
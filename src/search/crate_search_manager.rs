@@ -1,51 +1,140 @@
-use crates_io_api::{AsyncClient, CratesQuery};
+use chrono::NaiveDate;
+use color_eyre::eyre::{WrapErr, eyre};
+use crates_io_api::{AsyncClient, CrateDownloads, CratesQuery};
 use reqwest::{Client, header};
-use std::sync::Arc;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::{Range, RangeInclusive};
+use std::sync::{Arc, Mutex, PoisonError};
 use std::time::Duration;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::{RwLock, oneshot};
-use tracing::error;
+use tracing::{error, warn};
 
 use crate::action::Action;
-use crate::cargo::{CargoEnv, Project};
+use crate::cargo::{self, CargoEnv, IndexedCrate, InstalledBinary, Registry};
+use crate::components::status_bar::{StatusCommand, StatusLevel};
+use crate::config::AppConfig;
 use crate::errors::AppResult;
+use crate::search::github::GitHubStats;
+use crate::search::proxy;
 use crate::search::{
-    Crate, DEFAULT_PER_PAGE, Scope, SearchEvent, SearchOptions, SearchResults, Sort,
+    Crate, CrateDependency, DEFAULT_PER_PAGE, OutdatedBinary, Scope, SearchEvent, SearchOptions,
+    SearchResults, Sort, exact_name,
 };
 
+/// The most hits asked of `cargo search` (its own cap), for alternate registries.
+const ALTERNATE_REGISTRY_LIMIT: usize = 100;
+
+/// How many related crates are listed in the details.
+const RELATED_LIMIT: usize = 5;
+
+/// Where a page of results falls when several sources are searched at once.
+///
+/// The results are one sequence: the project's matches, then the installed binaries', then the
+/// online ones, paged by `per_page` like any other list. `total_count` is the sum of the three, so
+/// the page count covers them all. Local matches fill the first page(s); the online results pick
+/// up right after the last one, possibly partway through a page.
+#[derive(Debug, PartialEq, Eq)]
+struct PageWindow {
+    /// The slice of the local matches on the page.
+    local: Range<usize>,
+    /// The slice of the online results on the page, 0-based into the registry's own results.
+    online: Range<usize>,
+}
+
+impl PageWindow {
+    /// The window of `page` (1-based) when there are `local` local matches.
+    fn new(page: usize, per_page: usize, local: usize) -> Self {
+        let start = (page.max(1) - 1) * per_page;
+        let end = start + per_page;
+        Self {
+            local: start.min(local)..end.min(local),
+            online: start.saturating_sub(local)..end.saturating_sub(local),
+        }
+    }
+}
+
+/// The 1-based pages of `per_page` results that together hold the (non-empty) `range`.
+fn online_pages(range: &Range<usize>, per_page: usize) -> RangeInclusive<usize> {
+    (range.start / per_page + 1)..=((range.end - 1) / per_page + 1)
+}
+
+/// The crates.io search query for `term` among crates with `keyword`. crates.io reads a
+/// `keyword:` term in the query as a filter, which `CratesQuery` has no setter for.
+fn keyword_query(term: &str, keyword: Option<&str>) -> String {
+    match keyword {
+        Some(keyword) => format!("{term} keyword:{keyword}").trim_start().to_string(),
+        None => term.to_string(),
+    }
+}
+
 pub struct CrateSearchManager {
     crates_io_client: Arc<AsyncClient>,
+    /// Where online searches go. Anything other than crates.io is searched with `cargo search`.
+    registry: Registry,
+    /// The proxy requests go through, if any, to point at when a connection fails.
+    proxy: Option<String>,
+    /// See [`AppConfig::show_prereleases`].
+    show_prereleases: bool,
     action_tx: UnboundedSender<Action>,
     cancel_search_tx: Option<oneshot::Sender<()>>,
     cancel_hydrate_tx: Option<oneshot::Sender<()>>,
+    /// Set when `fetch_github_stats` is on.
+    github_stats: Option<GitHubStats>,
+    /// See [`AppConfig::show_related_crates`].
+    related_crates: bool,
+    /// crates.io user ids by username, resolved once per owner filter.
+    owner_ids: Arc<Mutex<HashMap<String, u64>>>,
+    /// Bumped for every search and every cancellation, so events from a superseded search can be
+    /// told apart from the current one.
+    generation: u64,
 }
 
 impl CrateSearchManager {
-    pub fn new(action_tx: UnboundedSender<Action>) -> AppResult<Self> {
+    /// Builds the manager and its crates.io client, using the request timeout and rate limit from
+    /// `config`.
+    pub fn new(action_tx: UnboundedSender<Action>, config: &AppConfig) -> AppResult<Self> {
         let mut headers = header::HeaderMap::new();
         headers.insert(
             header::USER_AGENT,
             header::HeaderValue::from_str("cargo-seek (github:tareqimbasher/cargo-seek)")?,
         );
 
-        let client = AsyncClient::with_http_client(
-            Client::builder()
-                .default_headers(headers)
-                .timeout(Duration::from_secs(10))
-                .build()?,
-            Duration::from_millis(1100),
-        );
+        let builder = Client::builder()
+            .default_headers(headers)
+            .timeout(Duration::from_secs(config.request_timeout_secs));
+        let http_client = proxy::apply(builder, config.proxy_url.as_deref())?.build()?;
+        let github_stats = config
+            .fetch_github_stats
+            .then(|| GitHubStats::new(http_client.clone()));
+        let client =
+            AsyncClient::with_http_client(http_client, Duration::from_millis(config.rate_limit_ms));
 
         Ok(CrateSearchManager {
             crates_io_client: Arc::new(client),
+            registry: Registry::from_config(config),
+            proxy: proxy::describe(config.proxy_url.as_deref()),
+            show_prereleases: config.show_prereleases,
             action_tx,
             cancel_search_tx: None,
             cancel_hydrate_tx: None,
+            github_stats,
+            related_crates: config.show_related_crates,
+            owner_ids: Arc::default(),
+            generation: 0,
         })
     }
 
-    pub fn search(&mut self, options: SearchOptions, cargo_env: Arc<RwLock<CargoEnv>>) {
-        // Cancel any in-flight search or hydrate operation.
+    /// Whether `generation` belongs to the latest search, i.e. it was neither superseded by a newer
+    /// search nor cancelled. Events from stale generations should be ignored.
+    pub fn is_current(&self, generation: u64) -> bool {
+        generation == self.generation
+    }
+
+    /// Cancels any in-flight search or hydrate operation. Events already queued by the cancelled
+    /// search become stale (see [`Self::is_current`]).
+    pub fn cancel(&mut self) {
         if let Some(cancel_search_tx) = self.cancel_search_tx.take() {
             let _ = cancel_search_tx.send(());
         }
@@ -54,10 +143,23 @@ impl CrateSearchManager {
             let _ = cancel_hydrate_tx.send(());
         }
 
+        self.generation += 1;
+    }
+
+    /// Starts a search, cancelling the previous one. The search reports back with
+    /// [`SearchEvent::Completed`] or [`SearchEvent::Failed`], tagged with its generation.
+    pub fn search(&mut self, options: SearchOptions, cargo_env: Arc<RwLock<CargoEnv>>) {
+        self.cancel();
+        let generation = self.generation;
+
         let (cancel_search_tx, mut cancel_search_rx) = oneshot::channel();
         self.cancel_search_tx = Some(cancel_search_tx);
         let tx = self.action_tx.clone();
         let crates_io_client = self.crates_io_client.clone();
+        let registry = self.registry.clone();
+        let proxy = self.proxy.clone();
+        let show_prereleases = self.show_prereleases;
+        let owner_ids = self.owner_ids.clone();
 
         tokio::spawn(async move {
             if cancel_search_rx.try_recv().is_ok() {
@@ -65,56 +167,66 @@ impl CrateSearchManager {
             }
 
             let term = options.term.unwrap_or_default().to_lowercase();
+            // `=name` asks for that one crate: local matches must be exact and crates.io is asked
+            // for the crate directly rather than searched.
+            let exact = exact_name(&term).is_some();
+            let term = exact_name(&term).map(str::to_string).unwrap_or(term);
             // Pages are 1-indexed
             let page = options.page.unwrap_or(1).max(1);
             let per_page = options.per_page.unwrap_or(DEFAULT_PER_PAGE);
-            let mut still_needed = per_page;
-            let mut search_results = SearchResults::new(page, per_page);
+            let mut search_results =
+                SearchResults::new(page, per_page).with_max_pages(options.max_pages);
 
             // The read guard must not be held across the network call below.
-            {
+            let window = {
                 let cargo_env = cargo_env.read().await;
 
-                // Search crates added to the current project
-                if options.scope.includes(Scope::Project)
-                    && let Some(project) = &cargo_env.project
-                {
-                    let mut results = Self::search_project(&term, project);
-                    search_results.total_count += results.len();
-                    results = results
-                        .into_iter()
-                        .skip((page - 1) * per_page)
-                        .take(still_needed)
-                        .collect();
-                    Self::extend_results(
-                        &mut search_results,
-                        &mut results,
-                        per_page,
-                        &mut still_needed,
-                    );
+                // Crates added to the current project, then globally installed binaries. Local
+                // crates carry no owner or keywords, so those filters leave them out.
+                let mut local = Vec::new();
+                for (scope, index) in [
+                    (Scope::Project, &cargo_env.project_index),
+                    (Scope::Installed, &cargo_env.binary_index),
+                ] {
+                    if options.scope.includes(scope.clone())
+                        && options.owner.is_none()
+                        && options.keyword.is_none()
+                    {
+                        let mut results = Self::search_local(&term, index);
+                        if exact {
+                            results.retain(|cr| cr.exact_match);
+                        }
+                        if scope == Scope::Project
+                            && options.normal_deps_only
+                            && let Some(project) = &cargo_env.project
+                        {
+                            results.retain(|cr| project.is_normal_dependency(&cr.name));
+                        }
+                        Self::sort_local(&mut results, &options.sort);
+                        local.append(&mut results);
+                    }
                 }
 
+                let window = PageWindow::new(page, per_page, local.len());
+                search_results.total_count = local.len();
+                search_results.crates = local.drain(window.local.clone()).collect();
+
                 if cancel_search_rx.try_recv().is_ok() {
                     return;
                 }
 
-                // Search globally installed binaries
-                if options.scope.includes(Scope::Installed) {
-                    let mut results = Self::search_binaries(&term, &cargo_env);
-                    search_results.total_count += results.len();
-                    results = results
-                        .into_iter()
-                        .skip((page - 1) * per_page)
-                        .take(still_needed)
-                        .collect();
-                    Self::extend_results(
-                        &mut search_results,
-                        &mut results,
-                        per_page,
-                        &mut still_needed,
-                    );
+                // Show the local results while the (much slower) online search runs.
+                if options.scope.includes(Scope::Online) && !search_results.crates.is_empty() {
+                    let mut partial = search_results.clone();
+                    partial.update_results(&cargo_env);
+                    tx.send(Action::SearchEvent(SearchEvent::Partial {
+                        generation,
+                        results: partial,
+                    }))
+                    .ok();
                 }
-            }
+                window
+            };
 
             if cancel_search_rx.try_recv().is_ok() {
                 return;
@@ -122,31 +234,60 @@ impl CrateSearchManager {
 
             // Search the online registry
             if options.scope.includes(Scope::Online) {
-                let registry = Self::search_registry(
-                    crates_io_client,
-                    &term,
-                    still_needed,
-                    page,
-                    options.sort,
-                );
+                let search = async {
+                    if exact {
+                        Self::lookup_exact(
+                            crates_io_client,
+                            registry,
+                            &term,
+                            &window.online,
+                            show_prereleases,
+                        )
+                        .await
+                    } else if registry.is_crates_io() {
+                        let owner_id = match &options.owner {
+                            Some(owner) => Some(
+                                Self::resolve_owner(&crates_io_client, &owner_ids, owner).await?,
+                            ),
+                            None => None,
+                        };
+                        Self::search_crates_io(
+                            crates_io_client,
+                            &keyword_query(&term, options.keyword.as_deref()),
+                            &window.online,
+                            per_page,
+                            options.sort,
+                            owner_id,
+                            show_prereleases,
+                        )
+                        .await
+                    } else if options.owner.is_some() {
+                        Err(eyre!("{registry} can't be filtered by owner"))
+                    } else if options.keyword.is_some() {
+                        Err(eyre!("{registry} can't be filtered by keyword"))
+                    } else {
+                        Self::search_alternate_registry(registry, &term, &window.online).await
+                    }
+                };
                 let outcome = tokio::select! {
                     biased;
                     _ = &mut cancel_search_rx => return,
-                    outcome = registry => outcome,
+                    outcome = search => outcome,
                 };
                 match outcome {
                     Ok((mut results, count)) => {
-                        Self::extend_results(
-                            &mut search_results,
-                            &mut results,
-                            per_page,
-                            &mut still_needed,
-                        );
+                        // An exact lookup asked for that crate, links or not.
+                        if !exact {
+                            results.retain(|cr| options.links.keeps(cr));
+                        }
+                        search_results.crates.append(&mut results);
                         search_results.total_count += count;
                     }
                     Err(err) => {
-                        let _ =
-                            tx.send(Action::SearchEvent(SearchEvent::Failed(format!("{err:#}"))));
+                        let _ = tx.send(Action::SearchEvent(SearchEvent::Failed {
+                            generation,
+                            message: proxy::explain(&err, proxy.as_deref()),
+                        }));
                         return;
                     }
                 }
@@ -162,49 +303,77 @@ impl CrateSearchManager {
                 search_results.update_results(&cargo_env);
             }
 
-            tx.send(Action::SearchEvent(SearchEvent::Completed(search_results)))
-                .ok();
+            tx.send(Action::SearchEvent(SearchEvent::Completed {
+                generation,
+                results: search_results,
+            }))
+            .ok();
         });
     }
 
-    fn search_binaries(term: &str, cargo_env: &CargoEnv) -> Vec<Crate> {
-        let mut results: Vec<Crate> = Vec::new();
+    /// The indexed local crates whose name contains `term` (already lowercased).
+    fn search_local(term: &str, index: &[IndexedCrate]) -> Vec<Crate> {
+        index
+            .iter()
+            .filter(|entry| entry.name.contains(term))
+            .map(|entry| Crate {
+                exact_match: entry.name == term,
+                ..entry.result.clone()
+            })
+            .collect()
+    }
 
-        for bin in &cargo_env.installed_binaries {
-            let name_lower = bin.name.to_lowercase();
-            if name_lower.contains(term) {
-                let mut cr = Crate::from_binary(bin);
-                cr.exact_match = name_lower == term;
-                results.push(cr);
-            }
+    /// Orders project/installed results by `sort`, as crates.io does for online ones, before they
+    /// are paged. Local crates carry little metadata, so orders that need data they lack (e.g.
+    /// download counts) leave them in discovery order.
+    fn sort_local(results: &mut [Crate], sort: &Sort) {
+        match sort {
+            Sort::Relevance => results.sort_by_key(|cr| !cr.exact_match),
+            Sort::Name => results.sort_by_cached_key(|cr| cr.name.to_lowercase()),
+            Sort::Downloads => results.sort_by_key(|cr| Reverse(cr.downloads)),
+            Sort::RecentDownloads => results.sort_by_key(|cr| Reverse(cr.recent_downloads)),
+            Sort::RecentlyUpdated => results.sort_by_key(|cr| Reverse(cr.updated_at)),
+            Sort::NewlyAdded => results.sort_by_key(|cr| Reverse(cr.created_at)),
         }
-
-        results
     }
 
-    fn search_project(term: &str, project: &Project) -> Vec<Crate> {
-        let mut results: Vec<Crate> = Vec::new();
-
-        for package in &project.packages {
-            for dep in &package.dependencies {
-                let name_lower = dep.name.to_lowercase();
-                if name_lower.contains(term) {
-                    let mut cr = Crate::from_dependency(dep);
-                    cr.exact_match = name_lower == term;
-                    results.push(cr);
-                }
-            }
+    /// Looks up the crates.io user id of `owner`, caching it for later searches.
+    async fn resolve_owner(
+        crates_io_client: &AsyncClient,
+        owner_ids: &Mutex<HashMap<String, u64>>,
+        owner: &str,
+    ) -> AppResult<u64> {
+        let cached = owner_ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(owner)
+            .copied();
+        if let Some(id) = cached {
+            return Ok(id);
         }
 
-        results
+        let user = crates_io_client
+            .user(owner)
+            .await
+            .wrap_err_with(|| format!("couldn't find the crates.io user {owner}"))?;
+        owner_ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(owner.to_string(), user.id);
+        Ok(user.id)
     }
 
-    async fn search_registry(
+    /// Fetches the `range` of crates.io's results (0-based, at most `per_page` long) and the total
+    /// number of matches. crates.io pages are fixed at `per_page`, so a range straddling two of
+    /// them takes both; an empty range still asks for the total.
+    async fn search_crates_io(
         crates_io_client: Arc<AsyncClient>,
         term: &str,
+        range: &Range<usize>,
         per_page: usize,
-        page: usize,
         sort: Sort,
+        owner_id: Option<u64>,
+        show_prereleases: bool,
     ) -> AppResult<(Vec<Crate>, usize)> {
         let sort = match sort {
             Sort::Relevance => crates_io_api::Sort::Relevance,
@@ -214,44 +383,104 @@ impl CrateSearchManager {
             Sort::RecentlyUpdated => crates_io_api::Sort::RecentUpdates,
             Sort::NewlyAdded => crates_io_api::Sort::NewlyAdded,
         };
+        let (page_size, pages) = if range.is_empty() {
+            (1, 1..=1)
+        } else {
+            (per_page, online_pages(range, per_page))
+        };
+        let skip = range.start - (pages.start() - 1) * page_size;
+
+        let mut results = Vec::new();
+        let mut total = 0;
+        for page in pages {
+            let mut query = CratesQuery::builder()
+                .search(term)
+                .sort(sort.clone())
+                .page_size(page_size as u64)
+                .page(page as u64);
+            if let Some(owner_id) = owner_id {
+                query = query.user_id(owner_id);
+            }
+            let result = crates_io_client.crates(query.build()).await?;
+            total = result.meta.total as usize;
+            results.extend(
+                result
+                    .crates
+                    .into_iter()
+                    .map(|c| Crate::from_crates_io(c, show_prereleases)),
+            );
+        }
+
+        let results = results.into_iter().skip(skip).take(range.len()).collect();
+        Ok((results, total))
+    }
+
+    /// Searches an alternate registry through `cargo search`, returning the `range` of its hits.
+    /// It has no paging or sorting, so the most it lists (in the registry's order) stands in for
+    /// every match and the total is capped to that.
+    async fn search_alternate_registry(
+        registry: Registry,
+        term: &str,
+        range: &Range<usize>,
+    ) -> AppResult<(Vec<Crate>, usize)> {
+        let term = term.to_string();
+        let (hits, _) = tokio::task::spawn_blocking(move || {
+            cargo::search(&term, ALTERNATE_REGISTRY_LIMIT, &registry)
+        })
+        .await??;
 
-        let result = crates_io_client
-            .crates(
-                CratesQuery::builder()
-                    .search(term)
-                    .sort(sort)
-                    .page_size(per_page as u64)
-                    .page(page as u64)
-                    .build(),
-            )
-            .await?;
-
-        let results = result
-            .crates
+        let count = hits.len();
+        let results = hits
             .into_iter()
-            .map(Crate::from_crates_io)
+            .skip(range.start)
+            .take(range.len())
+            .map(Crate::from_search_hit)
             .collect();
-        Ok((results, result.meta.total as usize))
+        Ok((results, count))
     }
 
-    fn extend_results(
-        search_results: &mut SearchResults,
-        new_results: &mut Vec<Crate>,
-        per_page: usize,
-        still_needed: &mut usize,
-    ) {
-        if *still_needed >= new_results.len() {
-            search_results.crates.append(new_results);
-        } else if *still_needed > 0 {
-            search_results
-                .crates
-                .extend(new_results.drain(..*still_needed));
+    /// Fetches the one crate named `name`, for `=name` searches. Not finding it is an empty result
+    /// rather than an error. Sorting and the owner filter don't apply to a single crate.
+    async fn lookup_exact(
+        crates_io_client: Arc<AsyncClient>,
+        registry: Registry,
+        name: &str,
+        range: &Range<usize>,
+        show_prereleases: bool,
+    ) -> AppResult<(Vec<Crate>, usize)> {
+        // The one crate is the first online result, only ever on the first page.
+        if range.start > 0 {
+            return Ok((Vec::new(), 0));
         }
-        *still_needed = per_page.saturating_sub(search_results.crates.len());
+
+        let results = if registry.is_crates_io() {
+            match crates_io_client.get_crate(name).await {
+                Ok(response) => {
+                    let mut cr = Crate::from_crates_io(response.crate_data, show_prereleases);
+                    cr.exact_match = true;
+                    vec![cr]
+                }
+                Err(crates_io_api::Error::NotFound(_)) => Vec::new(),
+                Err(err) => return Err(err.into()),
+            }
+        } else {
+            // `cargo search` is all an alternate registry offers; keep only the exact hit.
+            let (mut results, _) =
+                Self::search_alternate_registry(registry, name, &(0..ALTERNATE_REGISTRY_LIMIT))
+                    .await?;
+            results.retain(|cr| cr.name.eq_ignore_ascii_case(name));
+            results
+        };
+        let count = results.len();
+        Ok((results, count))
     }
 
     /// Starts the fetching of metadata for the named crate and then fires
-    /// [`SearchEvent::MetadataLoaded`] or [`SearchEvent::MetadataFailed`].
+    /// [`SearchEvent::MetadataLoaded`] or [`SearchEvent::MetadataFailed`]. Once the metadata is in,
+    /// the crate's download history follows as [`SearchEvent::DownloadsLoaded`]; it is fetched
+    /// separately so the rate-limited second request doesn't hold up the details. With GitHub
+    /// stats on, [`SearchEvent::OpenIssuesLoaded`] is fetched alongside it, and with related crates
+    /// on, [`SearchEvent::RelatedLoaded`].
     ///
     /// When `debounce` is [`true`] the load waits for a short period before fetching so that rapid
     /// consecutive calls coalesce into a single request. When `debounce` is [`false`], metadata is
@@ -269,6 +498,8 @@ impl CrateSearchManager {
         self.cancel_hydrate_tx = Some(cancel_hydrate_tx);
         let tx = self.action_tx.clone();
         let crates_io_client = self.crates_io_client.clone();
+        let github_stats = self.github_stats.clone();
+        let related_crates = self.related_crates;
         let name = name.to_owned();
 
         tokio::spawn(async move {
@@ -288,6 +519,14 @@ impl CrateSearchManager {
 
             match response {
                 Ok(response) => {
+                    if let (Some(github_stats), Some(repository)) =
+                        (github_stats, response.crate_data.repository.clone())
+                    {
+                        load_open_issues(github_stats, name.clone(), repository, tx.clone());
+                    }
+                    if related_crates && let Some(filter) = RelatedFilter::of(&response) {
+                        load_related(crates_io_client.clone(), name.clone(), filter, tx.clone());
+                    }
                     tx.send(Action::SearchEvent(SearchEvent::MetadataLoaded {
                         response: Box::new(response),
                     }))
@@ -300,17 +539,224 @@ impl CrateSearchManager {
                         message: format!("{err}"),
                     }))
                     .ok();
+                    return;
+                }
+            }
+
+            let downloads = tokio::select! {
+                biased;
+                _ = &mut cancel_hydrate_rx => return,
+                downloads = crates_io_client.crate_downloads(&name) => downloads,
+            };
+
+            // The history is optional garnish; without it the details simply omit the trend.
+            match downloads {
+                Ok(downloads) => {
+                    let daily = daily_downloads(&downloads);
+                    if !daily.is_empty() {
+                        tx.send(Action::SearchEvent(SearchEvent::DownloadsLoaded {
+                            name,
+                            daily,
+                        }))
+                        .ok();
+                    }
                 }
+                Err(err) => warn!("failed to load download history for `{name}`: {err:#}"),
             }
         });
 
         Ok(())
     }
+
+    /// Looks up the latest stable release of each of `binaries` on crates.io in the background,
+    /// one at a time under the rate limit, reporting progress in the status bar and the outdated
+    /// ones as [`SearchEvent::BinariesChecked`].
+    pub fn check_binaries(&self, binaries: Vec<InstalledBinary>) {
+        let tx = self.action_tx.clone();
+        let crates_io_client = self.crates_io_client.clone();
+        tokio::spawn(async move {
+            let mut outdated = Vec::new();
+            let mut unchecked = 0;
+            for (checked, binary) in binaries.iter().enumerate() {
+                tx.send(Action::Status(StatusCommand::UpdateStatus(
+                    StatusLevel::Progress,
+                    format!(
+                        "Checking installed binaries for updates ({checked}/{})",
+                        binaries.len()
+                    ),
+                )))
+                .ok();
+                match crates_io_client.get_crate(&binary.name).await {
+                    Ok(response) => {
+                        let data = response.crate_data;
+                        let latest = data.max_stable_version.unwrap_or(data.max_version);
+                        outdated.extend(OutdatedBinary::check(
+                            &binary.name,
+                            &binary.version,
+                            &latest,
+                        ));
+                    }
+                    Err(err) => {
+                        warn!("failed to check `{}` for updates: {err:#}", binary.name);
+                        unchecked += 1;
+                    }
+                }
+            }
+            tx.send(Action::SearchEvent(SearchEvent::BinariesChecked {
+                outdated,
+                unchecked,
+            }))
+            .ok();
+        });
+    }
+
+    /// Fetches the dependencies `name` declares at `version` in the background, reporting them as
+    /// [`SearchEvent::DependenciesLoaded`], or a failure as an error status. Only crates.io lists
+    /// them.
+    pub fn load_dependencies(&self, name: &str, version: &str) {
+        if !self.registry.is_crates_io() {
+            self.action_tx
+                .send(Action::Status(StatusCommand::UpdateStatus(
+                    StatusLevel::Info,
+                    format!("{} doesn't list dependencies", self.registry),
+                )))
+                .ok();
+            return;
+        }
+
+        let tx = self.action_tx.clone();
+        let crates_io_client = self.crates_io_client.clone();
+        let (name, version) = (name.to_string(), version.to_string());
+        tokio::spawn(async move {
+            let action = match crates_io_client.crate_dependencies(&name, &version).await {
+                Ok(dependencies) => Action::SearchEvent(SearchEvent::DependenciesLoaded {
+                    name,
+                    dependencies: CrateDependency::sorted(dependencies),
+                }),
+                Err(err) => Action::Status(StatusCommand::UpdateStatus(
+                    StatusLevel::Error,
+                    format!("Couldn't load {name}'s dependencies: {err}"),
+                )),
+            };
+            tx.send(action).ok();
+        });
+    }
+}
+
+/// What related crates have in common with a crate: its top category, or failing that (many crates
+/// have none) its first keyword.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RelatedFilter {
+    Category(String),
+    Keyword(String),
+}
+
+impl RelatedFilter {
+    fn of(response: &crates_io_api::CrateResponse) -> Option<Self> {
+        match (response.categories.first(), response.keywords.first()) {
+            (Some(category), _) => Some(Self::Category(category.slug.clone())),
+            (None, Some(keyword)) => Some(Self::Keyword(keyword.keyword.clone())),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Fetches the most downloaded crates matching `filter`, other than `name` itself, in the
+/// background, reporting them as [`SearchEvent::RelatedLoaded`]. Like the open issues, a late
+/// answer is still applied to the crate it's for.
+fn load_related(
+    crates_io_client: Arc<AsyncClient>,
+    name: String,
+    filter: RelatedFilter,
+    tx: UnboundedSender<Action>,
+) {
+    tokio::spawn(async move {
+        let query = CratesQuery::builder()
+            .sort(crates_io_api::Sort::Downloads)
+            .page_size(RELATED_LIMIT as u64 + 1);
+        let query = match filter {
+            RelatedFilter::Category(slug) => query.category(slug),
+            RelatedFilter::Keyword(keyword) => query.search(keyword),
+        };
+        match crates_io_client.crates(query.build()).await {
+            Ok(page) => {
+                let related = page
+                    .crates
+                    .into_iter()
+                    .map(|c| c.name)
+                    .filter(|related| *related != name)
+                    .take(RELATED_LIMIT)
+                    .collect();
+                tx.send(Action::SearchEvent(SearchEvent::RelatedLoaded {
+                    name,
+                    related,
+                }))
+                .ok();
+            }
+            // Garnish like the download history; the details just go without it.
+            Err(err) => warn!("failed to load crates related to `{name}`: {err:#}"),
+        }
+    });
+}
+
+/// Fetches the open issue count for `repository` in the background, reporting it as
+/// [`SearchEvent::OpenIssuesLoaded`]. Results for a crate that's no longer selected are still
+/// applied (and cached), so this isn't cancelled with the rest of the metadata load.
+fn load_open_issues(
+    github_stats: GitHubStats,
+    name: String,
+    repository: String,
+    tx: UnboundedSender<Action>,
+) {
+    tokio::spawn(async move {
+        match github_stats.open_issues(&repository).await {
+            Ok(Some(open_issues)) => {
+                tx.send(Action::SearchEvent(SearchEvent::OpenIssuesLoaded {
+                    name,
+                    open_issues,
+                }))
+                .ok();
+            }
+            Ok(None) => {}
+            // Likely the hourly rate limit; the details just go without the count.
+            Err(err) => warn!("{err:#}"),
+        }
+    });
+}
+
+/// Sums a crate's per-version and extra downloads into one total per day, oldest first. Days with
+/// no downloads inside the covered range count as zero so the series stays evenly spaced.
+fn daily_downloads(downloads: &CrateDownloads) -> Vec<u64> {
+    let mut by_date = BTreeMap::<NaiveDate, u64>::new();
+    let per_version = downloads
+        .version_downloads
+        .iter()
+        .map(|d| (d.date, d.downloads));
+    let extra = downloads
+        .meta
+        .extra_downloads
+        .iter()
+        .map(|d| (d.date, d.downloads));
+    for (date, count) in per_version.chain(extra) {
+        *by_date.entry(date).or_default() += count;
+    }
+
+    let (Some(first), Some(last)) = (by_date.keys().next(), by_date.keys().next_back()) else {
+        return Vec::new();
+    };
+    first
+        .iter_days()
+        .take_while(|date| date <= last)
+        .map(|date| by_date.get(&date).copied().unwrap_or_default())
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Days;
+    use crates_io_api::{CrateDownloadsMeta, ExtraDownloads, VersionDownloads};
+    use pretty_assertions::assert_eq;
 
     fn cr(id: &str, metadata_loaded: bool) -> Crate {
         Crate {
@@ -321,37 +767,142 @@ mod tests {
         }
     }
 
+    fn manager() -> CrateSearchManager {
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        CrateSearchManager::new(tx, &AppConfig::default()).unwrap()
+    }
+
+    fn names(results: &[Crate]) -> Vec<&str> {
+        results.iter().map(|cr| cr.name.as_str()).collect()
+    }
+
+    #[test]
+    fn keyword_query_adds_a_keyword_term() {
+        assert_eq!(keyword_query("http", Some("async")), "http keyword:async");
+        assert_eq!(keyword_query("", Some("cli")), "keyword:cli");
+        assert_eq!(keyword_query("http", None), "http");
+    }
+
+    #[test]
+    fn sort_local_orders_by_name_case_insensitively() {
+        let mut results = vec![
+            cr("tokio", false),
+            cr("Inflector", false),
+            cr("anyhow", false),
+        ];
+        CrateSearchManager::sort_local(&mut results, &Sort::Name);
+        assert_eq!(names(&results), vec!["anyhow", "Inflector", "tokio"]);
+    }
+
     #[test]
-    fn extend_appends_all_when_there_is_room() {
-        let mut results = SearchResults::new(1, DEFAULT_PER_PAGE);
-        let mut new = vec![cr("a", false), cr("b", false)];
-        let mut still_needed = 5;
-        CrateSearchManager::extend_results(&mut results, &mut new, 5, &mut still_needed);
-        assert_eq!(results.crates.len(), 2);
-        assert_eq!(still_needed, 3);
-        assert!(new.is_empty());
+    fn sort_local_keeps_discovery_order_without_the_data() {
+        let mut results = vec![cr("tokio", false), cr("anyhow", false)];
+        CrateSearchManager::sort_local(&mut results, &Sort::RecentlyUpdated);
+        assert_eq!(names(&results), vec!["tokio", "anyhow"]);
+
+        results[1].exact_match = true;
+        CrateSearchManager::sort_local(&mut results, &Sort::Relevance);
+        assert_eq!(names(&results), vec!["anyhow", "tokio"]);
     }
 
     #[test]
-    fn extend_takes_only_whats_still_needed() {
-        let mut results = SearchResults::new(1, DEFAULT_PER_PAGE);
-        let mut new = vec![cr("a", false), cr("b", false), cr("c", false)];
-        let mut still_needed = 2;
-        CrateSearchManager::extend_results(&mut results, &mut new, 5, &mut still_needed);
-        assert_eq!(results.crates.len(), 2);
-        assert_eq!(still_needed, 3);
-        assert_eq!(new.len(), 1); // "c" is left behind
+    fn cancel_makes_the_previous_generation_stale() {
+        let mut manager = manager();
+        let before = manager.generation;
+        assert!(manager.is_current(before));
+
+        manager.cancel();
+        assert!(!manager.is_current(before));
+        assert!(manager.is_current(manager.generation));
+    }
+
+    fn day(n: u64) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, 1).unwrap() + Days::new(n)
+    }
+
+    #[test]
+    fn daily_downloads_sums_versions_and_fills_gaps() {
+        let downloads = CrateDownloads {
+            version_downloads: vec![
+                VersionDownloads {
+                    date: day(2),
+                    downloads: 5,
+                    version: 1,
+                },
+                VersionDownloads {
+                    date: day(0),
+                    downloads: 3,
+                    version: 1,
+                },
+                VersionDownloads {
+                    date: day(0),
+                    downloads: 4,
+                    version: 2,
+                },
+            ],
+            meta: CrateDownloadsMeta {
+                extra_downloads: vec![ExtraDownloads {
+                    date: day(2),
+                    downloads: 1,
+                }],
+            },
+        };
+        assert_eq!(daily_downloads(&downloads), vec![7, 0, 6]);
+    }
+
+    #[test]
+    fn daily_downloads_is_empty_without_data() {
+        let downloads = CrateDownloads {
+            version_downloads: Vec::new(),
+            meta: CrateDownloadsMeta {
+                extra_downloads: Vec::new(),
+            },
+        };
+        assert!(daily_downloads(&downloads).is_empty());
+    }
+
+    #[test]
+    fn local_matches_fill_the_first_pages_then_online_results_follow() {
+        // 30 local matches with 25 per page: page 1 is all local, page 2 has the last 5 local
+        // ones and the first 20 online ones.
+        assert_eq!(
+            PageWindow::new(1, 25, 30),
+            PageWindow {
+                local: 0..25,
+                online: 0..0
+            }
+        );
+        assert_eq!(
+            PageWindow::new(2, 25, 30),
+            PageWindow {
+                local: 25..30,
+                online: 0..20
+            }
+        );
+        assert_eq!(
+            PageWindow::new(3, 25, 30),
+            PageWindow {
+                local: 30..30,
+                online: 20..45
+            }
+        );
+    }
+
+    #[test]
+    fn without_local_matches_pages_are_the_online_pages() {
+        assert_eq!(
+            PageWindow::new(2, 100, 0),
+            PageWindow {
+                local: 0..0,
+                online: 100..200
+            }
+        );
+        assert_eq!(online_pages(&(100..200), 100), 2..=2);
     }
 
     #[test]
-    fn extend_adds_nothing_when_already_full() {
-        let mut results = SearchResults::new(1, DEFAULT_PER_PAGE);
-        results.crates = vec![cr("x", false)];
-        let mut new = vec![cr("a", false)];
-        let mut still_needed = 0;
-        CrateSearchManager::extend_results(&mut results, &mut new, 1, &mut still_needed);
-        assert_eq!(results.crates.len(), 1);
-        assert_eq!(still_needed, 0);
-        assert_eq!(new.len(), 1); // untouched
+    fn a_window_straddling_two_online_pages_fetches_both() {
+        assert_eq!(online_pages(&(20..45), 25), 1..=2);
+        assert_eq!(online_pages(&(0..20), 25), 1..=1);
     }
 }
@@ -0,0 +1,120 @@
+//! Repository stats from the GitHub API, shown next to a crate's details when `fetch_github_stats`
+//! is on. Requests are unauthenticated (60 an hour), so results are cached and failures only
+//! logged.
+
+use color_eyre::eyre::{WrapErr, eyre};
+use reqwest::{Client, Url};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::errors::AppResult;
+
+#[derive(Deserialize)]
+struct Repository {
+    /// GitHub counts open pull requests as issues too.
+    open_issues_count: u64,
+}
+
+/// Fetches and caches the open issue count of GitHub repositories, keyed by `owner/repo`.
+#[derive(Clone)]
+pub struct GitHubStats {
+    client: Client,
+    open_issues: Arc<Mutex<HashMap<String, u64>>>,
+}
+
+impl GitHubStats {
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            open_issues: Arc::default(),
+        }
+    }
+
+    /// The open issue (and pull request) count of the GitHub repository at `repository_url`.
+    /// `Ok(None)` for repositories hosted elsewhere.
+    pub async fn open_issues(&self, repository_url: &str) -> AppResult<Option<u64>> {
+        let Some(repo) = github_repo(repository_url) else {
+            return Ok(None);
+        };
+        if let Some(count) = self.cached(&repo) {
+            return Ok(Some(count));
+        }
+
+        let response = self
+            .client
+            .get(format!("https://api.github.com/repos/{repo}"))
+            .header("Accept", "application/vnd.github+json")
+            .send()
+            .await
+            .wrap_err_with(|| format!("failed to request GitHub stats for {repo}"))?;
+        if !response.status().is_success() {
+            // 403/429 once the hourly limit is spent, 404 for a renamed or private repo.
+            return Err(eyre!("GitHub returned {} for {repo}", response.status()));
+        }
+        let body = response
+            .text()
+            .await
+            .wrap_err_with(|| format!("failed to read GitHub stats for {repo}"))?;
+        let count = serde_json::from_str::<Repository>(&body)
+            .wrap_err_with(|| format!("unexpected GitHub response for {repo}"))?
+            .open_issues_count;
+
+        self.open_issues
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(repo, count);
+        Ok(Some(count))
+    }
+
+    fn cached(&self, repo: &str) -> Option<u64> {
+        self.open_issues
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(repo)
+            .copied()
+    }
+}
+
+/// The `owner/repo` of a GitHub repository URL, ignoring a `.git` suffix and any deeper path
+/// (e.g. `/tree/main/subcrate`).
+fn github_repo(url: &str) -> Option<String> {
+    let url = Url::parse(url).ok()?;
+    if !matches!(url.host_str()?, "github.com" | "www.github.com") {
+        return None;
+    }
+    let mut segments = url.path_segments()?.filter(|s| !s.is_empty());
+    let owner = segments.next()?;
+    let repo = segments.next()?;
+    let repo = repo.strip_suffix(".git").unwrap_or(repo);
+    Some(format!("{owner}/{repo}").to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn github_repo_extracts_owner_and_name() {
+        assert_eq!(
+            github_repo("https://github.com/serde-rs/serde"),
+            Some("serde-rs/serde".to_string())
+        );
+        assert_eq!(
+            github_repo("https://github.com/tokio-rs/tokio.git"),
+            Some("tokio-rs/tokio".to_string())
+        );
+        assert_eq!(
+            github_repo("https://www.github.com/rust-lang/futures-rs/tree/master/futures-util"),
+            Some("rust-lang/futures-rs".to_string())
+        );
+    }
+
+    #[test]
+    fn github_repo_is_none_elsewhere() {
+        assert_eq!(github_repo("https://gitlab.com/a/b"), None);
+        assert_eq!(github_repo("https://github.com/only-owner"), None);
+        assert_eq!(github_repo("not a url"), None);
+    }
+}
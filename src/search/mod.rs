@@ -4,11 +4,15 @@
 mod action;
 mod cargo_crate;
 mod crate_search_manager;
+mod github;
+mod outdated;
+mod proxy;
 mod search_options;
 mod search_results;
 
 pub use action::*;
 pub use cargo_crate::*;
 pub use crate_search_manager::*;
+pub use outdated::OutdatedBinary;
 pub use search_options::*;
 pub use search_results::*;
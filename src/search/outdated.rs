@@ -0,0 +1,46 @@
+//! Checking installed binaries against their latest crates.io release.
+
+use semver::Version;
+
+/// An installed binary with a newer stable release on crates.io.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutdatedBinary {
+    pub name: String,
+    pub installed: String,
+    pub latest: String,
+}
+
+impl OutdatedBinary {
+    /// `Some` when `latest` is a newer version than `installed`. Versions that don't parse, e.g.
+    /// from a git install, are never reported as outdated.
+    pub fn check(name: &str, installed: &str, latest: &str) -> Option<Self> {
+        let newer = Version::parse(latest).ok()? > Version::parse(installed).ok()?;
+        newer.then(|| Self {
+            name: name.to_string(),
+            installed: installed.to_string(),
+            latest: latest.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn check_reports_only_newer_releases() {
+        assert_eq!(
+            OutdatedBinary::check("ripgrep", "13.0.0", "14.1.1"),
+            Some(OutdatedBinary {
+                name: "ripgrep".into(),
+                installed: "13.0.0".into(),
+                latest: "14.1.1".into(),
+            })
+        );
+        assert_eq!(OutdatedBinary::check("ripgrep", "14.1.1", "14.1.1"), None);
+        // Installed from a pre-release or a newer git checkout.
+        assert_eq!(OutdatedBinary::check("just", "2.0.0-rc.1", "1.36.0"), None);
+        assert_eq!(OutdatedBinary::check("tool", "git", "1.0.0"), None);
+    }
+}
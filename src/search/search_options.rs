@@ -1,7 +1,12 @@
-use serde::Deserialize;
-use strum::{Display, EnumIter};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
 
-#[derive(Debug, Default, Display, Clone, EnumIter, PartialEq, Eq, Deserialize)]
+use crate::search::Crate;
+
+#[derive(
+    Debug, Default, Display, Clone, EnumIter, PartialEq, Eq, Deserialize, Serialize, ValueEnum,
+)]
 pub enum Scope {
     #[default]
     All,
@@ -17,7 +22,7 @@ impl Scope {
     }
 }
 
-#[derive(Debug, Default, Clone, EnumIter, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Default, Clone, EnumIter, PartialEq, Eq, Deserialize, Serialize, ValueEnum)]
 pub enum Sort {
     #[default]
     Relevance,
@@ -42,11 +47,150 @@ impl std::fmt::Display for Sort {
     }
 }
 
-#[derive(Debug, Default)]
+/// The variant after `current` (or before it, unless `forward`), wrapping around at either end.
+pub fn cycle<T: IntoEnumIterator + PartialEq>(current: &T, forward: bool) -> T {
+    let all: Vec<T> = T::iter().collect();
+    let index = all.iter().position(|v| v == current).unwrap_or_default();
+    let next = if forward {
+        (index + 1) % all.len()
+    } else {
+        (index + all.len() - 1) % all.len()
+    };
+    all.into_iter()
+        .nth(next)
+        .expect("the index is within the variants")
+}
+
+/// A link online results must have to be shown.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum RequiredLink {
+    #[strum(to_string = "docs")]
+    Docs,
+    #[strum(to_string = "repo")]
+    Repo,
+}
+
+/// Hides online results that lack documentation or a repository. crates.io search results
+/// already carry both links, so the filter applies as each page arrives; a filtered page can
+/// hold fewer crates than a full one.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LinkFilter {
+    pub has_docs: bool,
+    pub has_repo: bool,
+}
+
+impl LinkFilter {
+    /// This filter with `link` required, or no longer required if it was.
+    pub fn toggled(self, link: RequiredLink) -> Self {
+        match link {
+            RequiredLink::Docs => Self {
+                has_docs: !self.has_docs,
+                ..self
+            },
+            RequiredLink::Repo => Self {
+                has_repo: !self.has_repo,
+                ..self
+            },
+        }
+    }
+
+    pub fn keeps(&self, cr: &Crate) -> bool {
+        (!self.has_docs || cr.documentation.is_some())
+            && (!self.has_repo || cr.repository.is_some())
+    }
+
+    /// The required links, e.g. `docs + repo`, or `None` while the filter is off.
+    pub fn label(&self) -> Option<String> {
+        let links: Vec<String> = [
+            (self.has_docs, RequiredLink::Docs),
+            (self.has_repo, RequiredLink::Repo),
+        ]
+        .into_iter()
+        .filter(|(required, _)| *required)
+        .map(|(_, link)| link.to_string())
+        .collect();
+        Some(links.join(" + ")).filter(|label| !label.is_empty())
+    }
+}
+
+/// The crate name in a term written as `=name`, which looks up exactly that crate instead of
+/// searching.
+pub fn exact_name(term: &str) -> Option<&str> {
+    term.trim()
+        .strip_prefix('=')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+}
+
+/// The search to start with, as given on the command line. Without a scope or sort, the config's
+/// are used.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct InitialSearch {
+    pub term: Option<String>,
+    pub scope: Option<Scope>,
+    pub sort: Option<Sort>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct SearchOptions {
     pub term: Option<String>,
     pub page: Option<usize>,
     pub per_page: Option<usize>,
+    /// The most pages of results that can be navigated. `None` for no cap.
+    pub max_pages: Option<usize>,
     pub sort: Sort,
     pub scope: Scope,
+    /// Only crates owned by this crates.io user. Local crates carry no owner, so project and
+    /// installed results are left out while it's set.
+    pub owner: Option<String>,
+    /// Only crates tagged with this crates.io keyword. Like `owner`, it leaves out project and
+    /// installed results, which carry no keywords.
+    pub keyword: Option<String>,
+    /// Project results leave out crates that are only dev or build dependencies.
+    pub normal_deps_only: bool,
+    /// Online results must have these links. Local crates are never hidden by it.
+    pub links: LinkFilter,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn cycle_wraps_around_both_ends() {
+        assert_eq!(cycle(&Sort::Relevance, true), Sort::Name);
+        assert_eq!(cycle(&Sort::NewlyAdded, true), Sort::Relevance);
+        assert_eq!(cycle(&Scope::All, false), Scope::Installed);
+        assert_eq!(cycle(&Scope::Online, false), Scope::All);
+    }
+
+    #[test]
+    fn exact_name_reads_an_equals_prefix() {
+        assert_eq!(exact_name("=serde"), Some("serde"));
+        assert_eq!(exact_name(" = serde_json "), Some("serde_json"));
+        assert_eq!(exact_name("serde"), None);
+        assert_eq!(exact_name("="), None);
+    }
+
+    #[test]
+    fn link_filter_hides_crates_missing_a_required_link() {
+        let docs_only = Crate {
+            documentation: Some("https://docs.rs/serde".into()),
+            ..Default::default()
+        };
+        let filter = LinkFilter::default().toggled(RequiredLink::Docs);
+        assert!(filter.keeps(&docs_only));
+        assert_eq!(filter.label().as_deref(), Some("docs"));
+
+        let filter = filter.toggled(RequiredLink::Repo);
+        assert!(!filter.keeps(&docs_only));
+        assert_eq!(filter.label().as_deref(), Some("docs + repo"));
+
+        let filter = filter
+            .toggled(RequiredLink::Docs)
+            .toggled(RequiredLink::Repo);
+        assert_eq!(filter, LinkFilter::default());
+        assert_eq!(filter.label(), None);
+    }
 }
@@ -3,18 +3,30 @@ use indexmap::IndexMap;
 use ratatui::widgets::ListState;
 
 use crate::cargo::CargoEnv;
-use crate::search::Crate;
+use crate::search::{Crate, CrateDependency, ResultSource};
 
 /// Number of results requested per page.
 pub const DEFAULT_PER_PAGE: usize = 100;
 
+/// A row of the results list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultRow {
+    /// Heads the results from one source. Never selected.
+    Header(ResultSource),
+    /// The crate at this index of [`SearchResults::crates`].
+    Crate(usize),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SearchResults {
     pub crates: Vec<Crate>,
     pub total_count: usize,
+    /// Selects a [`ResultRow`], which is only ever a crate row.
     pub list_state: ListState,
     current_page: usize,
     per_page: usize,
+    /// The most pages navigation may reach, however many there are. `None` for no cap.
+    max_pages: Option<usize>,
 }
 
 impl SearchResults {
@@ -24,13 +36,28 @@ impl SearchResults {
             total_count: 0,
             current_page: page,
             per_page,
+            max_pages: None,
             list_state: ListState::default(),
         }
     }
 
+    /// Caps navigation at `max_pages` pages, so a term matching a huge number of crates can't be
+    /// paged through request by request.
+    pub fn with_max_pages(mut self, max_pages: Option<usize>) -> Self {
+        self.max_pages = max_pages;
+        self
+    }
+
     pub fn page_count(&self) -> usize {
         debug_assert!(self.per_page > 0, "per_page must be non-zero");
-        self.total_count.div_ceil(self.per_page)
+        let pages = self.total_count.div_ceil(self.per_page);
+        self.max_pages.map_or(pages, |max| pages.min(max))
+    }
+
+    /// Whether there are more pages of results than [`page_count`](Self::page_count) allows.
+    pub fn is_capped(&self) -> bool {
+        self.max_pages
+            .is_some_and(|max| self.total_count.div_ceil(self.per_page) > max)
     }
 
     pub fn current_page(&self) -> usize {
@@ -59,37 +86,80 @@ impl SearchResults {
         self.current_page.saturating_sub(1) * self.per_page
     }
 
+    /// The selected crate's 1-based position across all pages, for the "X/total" counter. `None`
+    /// without a selection. Kept within `total_count` (or the page, while the total lags behind a
+    /// partial search) so a short last page can't push it past the end.
+    pub fn selected_position(&self) -> Option<usize> {
+        let position = self.items_before_current_page() + self.selected_index()? + 1;
+        Some(position.min(self.total_count.max(self.crates.len())))
+    }
+
+    /// Whether a page follows the current one. Goes by [`page_count`](Self::page_count), like
+    /// [`resolve_page`](Self::resolve_page), so a total that's an exact multiple of the page size
+    /// doesn't offer an empty page after the last.
     pub fn has_next_page(&self) -> bool {
-        self.current_page * self.per_page < self.total_count
+        self.current_page < self.page_count()
     }
 
     pub fn has_prev_page(&self) -> bool {
         self.current_page > 1
     }
 
-    /// The selected index, always clamped into range (or `None` when there is no selection or no
-    /// results). The selection can briefly fall out of range — `ListState` over-increments past
-    /// the last item, and results may be replaced/deduplicated underneath it — so every read
-    /// clamps rather than trusting the raw value.
+    /// The rows to list: the crates, each source's behind a header when the page holds results
+    /// from more than one source (e.g. project dependencies and crates.io with `Scope::All`).
+    pub fn rows(&self) -> Vec<ResultRow> {
+        let grouped = self.crates.windows(2).any(|w| w[0].source != w[1].source);
+        let mut rows = Vec::with_capacity(self.crates.len() + 3);
+        for (index, cr) in self.crates.iter().enumerate() {
+            if grouped && (index == 0 || self.crates[index - 1].source != cr.source) {
+                rows.push(ResultRow::Header(cr.source));
+            }
+            rows.push(ResultRow::Crate(index));
+        }
+        rows
+    }
+
+    /// The selected crate's index in `crates`, always clamped into range (or `None` when there is
+    /// no selection or no results). The selected row can briefly fall out of range or onto a
+    /// header — `ListState` over-increments past the last item, and results may be
+    /// replaced/deduplicated underneath it — so every read clamps to the nearest crate rather than
+    /// trusting the raw value.
     pub fn selected_index(&self) -> Option<usize> {
         let selected = self.list_state.selected()?;
-        if self.crates.is_empty() {
-            None
-        } else {
-            Some(selected.min(self.crates.len() - 1))
-        }
+        let rows = self.rows();
+        let row = selected.min(rows.len().checked_sub(1)?);
+        // A header is always followed by its first crate.
+        rows[row..].iter().find_map(|row| match row {
+            ResultRow::Crate(index) => Some(*index),
+            ResultRow::Header(_) => None,
+        })
     }
 
     pub fn selected(&self) -> Option<&Crate> {
         self.crates.get(self.selected_index()?)
     }
 
+    /// Selects the crate at `index` (clamped into range) and scrolls the list up to it when it's
+    /// above the rows in view, along with its group's header when it heads the group. Scrolling
+    /// down to a selection below the view is left to the list as it renders, which knows the
+    /// height.
     pub fn select_index(&mut self, index: Option<usize>) -> Option<&Crate> {
         let index = match index {
             Some(i) if !self.crates.is_empty() => Some(i.min(self.crates.len() - 1)),
             _ => None,
         };
-        self.list_state.select(index);
+        let rows = self.rows();
+        let row =
+            index.and_then(|index| rows.iter().position(|row| *row == ResultRow::Crate(index)));
+        self.list_state.select(row);
+        if let Some(row) = row {
+            let top = match row.checked_sub(1).map(|above| rows[above]) {
+                Some(ResultRow::Header(_)) => row - 1,
+                _ => row,
+            };
+            let offset = self.list_state.offset_mut();
+            *offset = (*offset).min(top);
+        }
         self.selected()
     }
 
@@ -112,12 +182,47 @@ impl SearchResults {
         self.select_index(Some(last))
     }
 
-    /// Hydrates the selected crate from a metadata response.
-    pub fn hydrate_selected(&mut self, response: &CrateResponse) {
+    /// Hydrates the selected crate from a metadata response (see [`Crate::hydrate`]).
+    pub fn hydrate_selected(&mut self, response: &CrateResponse, show_prereleases: bool) {
         if let Some(index) = self.selected_index()
             && self.crates[index].name == response.crate_data.name
         {
-            self.crates[index].hydrate(response);
+            self.crates[index].hydrate(response, show_prereleases);
+        }
+    }
+
+    /// Marks the crate named `name` as having no metadata to load, ending its loading state.
+    pub fn mark_metadata_failed(&mut self, name: &str) {
+        if let Some(cr) = self.crates.iter_mut().find(|cr| cr.name == name) {
+            cr.metadata_failed = true;
+        }
+    }
+
+    /// Stores the daily download history of the crate named `name`, if it is in the results.
+    pub fn set_daily_downloads(&mut self, name: &str, daily: &[u64]) {
+        if let Some(cr) = self.crates.iter_mut().find(|cr| cr.name == name) {
+            cr.daily_downloads = Some(daily.to_vec());
+        }
+    }
+
+    /// Stores the open issue count of the crate named `name`, if it is in the results.
+    pub fn set_open_issues(&mut self, name: &str, open_issues: u64) {
+        if let Some(cr) = self.crates.iter_mut().find(|cr| cr.name == name) {
+            cr.open_issues = Some(open_issues);
+        }
+    }
+
+    /// Stores the crates related to the crate named `name`, if it is in the results.
+    pub fn set_related(&mut self, name: &str, related: &[String]) {
+        if let Some(cr) = self.crates.iter_mut().find(|cr| cr.name == name) {
+            cr.related = Some(related.to_vec());
+        }
+    }
+
+    /// Stores the dependencies of the crate named `name`, if it is in the results.
+    pub fn set_dependencies(&mut self, name: &str, dependencies: &[CrateDependency]) {
+        if let Some(cr) = self.crates.iter_mut().find(|cr| cr.name == name) {
+            cr.dependencies = Some(dependencies.to_vec());
         }
     }
 
@@ -134,13 +239,17 @@ impl SearchResults {
         }
     }
 
-    /// Collapses crates sharing an id, keeping an already-hydrated copy over a stub.
+    /// Collapses crates sharing an id, keeping an already-hydrated copy over a stub. The crate stays
+    /// in the group it was first listed in.
     fn deduplicate(&mut self) {
         let mut map = IndexMap::<String, Crate>::new();
 
-        for cr in self.crates.drain(0..) {
-            if map.get(&cr.id).is_some_and(|v| v.is_metadata_loaded()) {
-                continue;
+        for mut cr in self.crates.drain(0..) {
+            if let Some(existing) = map.get(&cr.id) {
+                if existing.is_metadata_loaded() {
+                    continue;
+                }
+                cr.source = existing.source;
             }
             map.insert(cr.id.clone(), cr);
         }
@@ -205,6 +314,45 @@ mod tests {
         assert!(!results_with(250, 3, 0).has_next_page());
     }
 
+    #[test]
+    fn has_next_page_stops_at_exact_multiples_of_the_page_size() {
+        assert!(!results_with(100, 1, 0).has_next_page());
+        assert!(results_with(200, 1, 0).has_next_page());
+        assert!(!results_with(200, 2, 0).has_next_page());
+        assert!(results_with(250, 2, 0).has_next_page());
+        assert!(!results_with(0, 1, 0).has_next_page());
+    }
+
+    #[test]
+    fn has_next_page_follows_the_page_size() {
+        let mut r = SearchResults::new(1, 50);
+        r.total_count = 100;
+        assert!(r.has_next_page());
+        r = SearchResults::new(2, 50);
+        r.total_count = 100;
+        assert!(!r.has_next_page());
+        r.total_count = 101;
+        assert!(r.has_next_page());
+    }
+
+    #[test]
+    fn max_pages_caps_navigation() {
+        let capped = results_with(1_000, 2, 0).with_max_pages(Some(3));
+        assert_eq!(capped.page_count(), 3);
+        assert!(capped.is_capped());
+        assert_eq!(capped.resolve_page(9), Some(3));
+        assert!(
+            !results_with(1_000, 3, 0)
+                .with_max_pages(Some(3))
+                .has_next_page()
+        );
+
+        let under_cap = results_with(250, 1, 0).with_max_pages(Some(3));
+        assert_eq!(under_cap.page_count(), 3);
+        assert!(!under_cap.is_capped());
+        assert!(!results_with(1_000, 1, 0).is_capped());
+    }
+
     #[test]
     fn has_prev_page_after_the_first() {
         assert!(!results_with(250, 1, 0).has_prev_page());
@@ -217,6 +365,37 @@ mod tests {
         assert_eq!(results_with(250, 3, 0).items_before_current_page(), 200);
     }
 
+    #[test]
+    fn selected_position_counts_across_pages() {
+        let mut r = results_with(250, 1, 100);
+        r.select_first();
+        assert_eq!(r.selected_position(), Some(1));
+
+        // The last page only holds 50 of its 100 slots.
+        let mut r = results_with(250, 3, 50);
+        r.select_last();
+        assert_eq!(r.selected_position(), Some(250));
+        r.list_state.select(Some(usize::MAX));
+        assert_eq!(r.selected_position(), Some(250));
+    }
+
+    #[test]
+    fn selected_position_is_none_without_a_selection() {
+        let mut r = results_with(250, 2, 100);
+        assert_eq!(r.selected_position(), None);
+        r = results_with(0, 1, 0);
+        r.list_state.select(Some(0));
+        assert_eq!(r.selected_position(), None);
+    }
+
+    #[test]
+    fn selected_position_never_passes_the_total() {
+        // More crates listed than counted, e.g. a total that hasn't caught up yet.
+        let mut r = results_with(250, 3, 100);
+        r.select_last();
+        assert_eq!(r.selected_position(), Some(250));
+    }
+
     #[test]
     fn selected_index_clamps_out_of_range_to_last() {
         let mut r = results_with(3, 1, 3);
@@ -256,6 +435,30 @@ mod tests {
         assert!(r.selected().is_some());
     }
 
+    #[test]
+    fn selecting_above_the_view_scrolls_up_to_it() {
+        let mut r = results_with(300, 1, 100);
+        *r.list_state.offset_mut() = 80;
+        r.select_index(Some(40));
+        assert_eq!(r.list_state.offset(), 40);
+
+        // Below the view, the offset is left for the list to adjust when it renders.
+        r.select_index(Some(99));
+        assert_eq!(r.list_state.offset(), 40);
+    }
+
+    #[test]
+    fn selecting_the_first_crate_of_a_group_shows_its_header() {
+        let mut results = SearchResults::new(1, DEFAULT_PER_PAGE);
+        results.crates = vec![
+            from(ResultSource::Project, "serde"),
+            from(ResultSource::Registry, "serde_json"),
+        ];
+        *results.list_state.offset_mut() = 3;
+        results.select_first();
+        assert_eq!(results.list_state.offset(), 0);
+    }
+
     #[test]
     fn select_previous_stops_at_the_first_item() {
         let mut r = results_with(3, 1, 3);
@@ -292,4 +495,73 @@ mod tests {
         assert_eq!(results.crates.len(), 1);
         assert!(results.crates[0].is_metadata_loaded());
     }
+
+    #[test]
+    fn a_failed_metadata_load_ends_the_loading_state() {
+        let mut results = SearchResults::new(1, DEFAULT_PER_PAGE);
+        results.crates = vec![cr("a", false), cr("b", false)];
+        results.mark_metadata_failed("b");
+        assert!(results.crates[0].is_metadata_loading());
+        assert!(!results.crates[1].is_metadata_loading());
+    }
+
+    fn from(source: ResultSource, name: &str) -> Crate {
+        Crate {
+            id: name.into(),
+            name: name.into(),
+            source,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rows_head_each_source_only_when_mixed() {
+        let mut results = SearchResults::new(1, DEFAULT_PER_PAGE);
+        results.crates = vec![
+            from(ResultSource::Project, "serde"),
+            from(ResultSource::Registry, "serde_json"),
+            from(ResultSource::Registry, "serde_yaml"),
+        ];
+        assert_eq!(
+            results.rows(),
+            vec![
+                ResultRow::Header(ResultSource::Project),
+                ResultRow::Crate(0),
+                ResultRow::Header(ResultSource::Registry),
+                ResultRow::Crate(1),
+                ResultRow::Crate(2),
+            ]
+        );
+
+        results.crates.remove(0);
+        assert_eq!(
+            results.rows(),
+            vec![ResultRow::Crate(0), ResultRow::Crate(1)]
+        );
+    }
+
+    #[test]
+    fn selection_skips_header_rows() {
+        let mut results = SearchResults::new(1, DEFAULT_PER_PAGE);
+        results.crates = vec![
+            from(ResultSource::Project, "serde"),
+            from(ResultSource::Registry, "serde_json"),
+        ];
+
+        results.select_first();
+        assert_eq!(results.list_state.selected(), Some(1));
+        assert_eq!(
+            results.select_next().map(|cr| cr.name.as_str()),
+            Some("serde_json")
+        );
+        assert_eq!(results.list_state.selected(), Some(3));
+        assert_eq!(
+            results.select_previous().map(|cr| cr.name.as_str()),
+            Some("serde")
+        );
+
+        // A selection left on a header resolves to the crate below it.
+        results.list_state.select(Some(2));
+        assert_eq!(results.selected_index(), Some(1));
+    }
 }
@@ -2,6 +2,7 @@
 
 use chrono::{DateTime, Utc};
 use num_format::{Locale, ToFormattedStr, ToFormattedString};
+use semver::{Version, VersionReq};
 use std::str::FromStr;
 use std::sync::LazyLock;
 use sys_locale::get_locale;
@@ -11,18 +12,33 @@ static LOCALE: LazyLock<Locale> = LazyLock::new(|| {
     Locale::from_str(&locale_str).unwrap_or(Locale::en)
 });
 
-/// Gets the elapsed time between two times as a human-readable string.
+/// Gets the elapsed time between two times as a human-readable string, in the largest whole unit
+/// that fits, e.g. `3 hours ago`, `2 weeks ago` or `1 month ago`. Months count as 30 days and
+/// years as 365.
 pub fn get_relative_time(date_time: DateTime<Utc>, since: DateTime<Utc>) -> String {
     let delta = since.signed_duration_since(date_time);
+    let days = delta.num_days();
 
-    if delta.num_days() > 1 {
-        format!("{} days ago", delta.num_days())
-    } else if delta.num_hours() > 1 {
-        format!("{} hours ago", delta.num_hours())
-    } else if delta.num_seconds() > 1 {
-        format!("{} minutes ago", delta.num_minutes())
+    let (value, unit) = if days >= 365 {
+        (days / 365, "year")
+    } else if days >= 30 {
+        (days / 30, "month")
+    } else if days >= 7 {
+        (days / 7, "week")
+    } else if days >= 1 {
+        (days, "day")
+    } else if delta.num_hours() >= 1 {
+        (delta.num_hours(), "hour")
+    } else if delta.num_minutes() >= 1 {
+        (delta.num_minutes(), "minute")
     } else {
-        format!("{} seconds ago", delta.num_seconds())
+        (delta.num_seconds().max(0), "second")
+    };
+
+    if value == 1 {
+        format!("1 {unit} ago")
+    } else {
+        format!("{value} {unit}s ago")
     }
 }
 
@@ -37,3 +53,124 @@ where
         String::default()
     }
 }
+
+/// Formats a size in bytes for display, e.g. `512 B`, `14.2 KB` or `3.1 MB` (1 KB = 1024 B).
+pub fn format_size(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+
+    let size = bytes as f64;
+    if size >= MB {
+        format!("{:.1} MB", size / MB)
+    } else if size >= KB {
+        format!("{:.1} KB", size / KB)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+/// Formats a resolved version as full semver, e.g. `1.2.0` for `1.2` or `v1.2`. Versions that don't
+/// parse are returned as is.
+pub fn format_version(version: &str) -> String {
+    let trimmed = version.trim().trim_start_matches('v');
+    [
+        trimmed.to_string(),
+        format!("{trimmed}.0"),
+        format!("{trimmed}.0.0"),
+    ]
+    .iter()
+    .find_map(|candidate| Version::parse(candidate).ok())
+    .map_or_else(|| version.to_string(), |version| version.to_string())
+}
+
+/// Formats version requirements with their operator spelled out, e.g. `^1.2` for `1.2`, so they read
+/// as a requirement rather than a resolved version. `reqs` may list several, separated by `, `.
+/// Requirements that don't parse are returned as is.
+pub fn format_version_req(reqs: &str) -> String {
+    reqs.split(", ")
+        .map(|req| VersionReq::parse(req).map_or_else(|_| req.to_string(), |req| req.to_string()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Shortens `value` to at most `width` characters, replacing the cut-off tail with `…`.
+pub fn elide(value: &str, width: usize) -> String {
+    if value.chars().count() <= width {
+        return value.to_string();
+    }
+    if width == 0 {
+        return String::new();
+    }
+    let mut elided: String = value.chars().take(width - 1).collect();
+    elided.push('…');
+    elided
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn relative(delta: chrono::TimeDelta) -> String {
+        let since = Utc::now();
+        get_relative_time(since - delta, since)
+    }
+
+    #[test]
+    fn relative_time_uses_the_largest_whole_unit() {
+        use chrono::TimeDelta;
+
+        assert_eq!(relative(TimeDelta::zero()), "0 seconds ago");
+        assert_eq!(relative(TimeDelta::seconds(1)), "1 second ago");
+        assert_eq!(relative(TimeDelta::seconds(59)), "59 seconds ago");
+        assert_eq!(relative(TimeDelta::seconds(60)), "1 minute ago");
+        assert_eq!(relative(TimeDelta::minutes(59)), "59 minutes ago");
+        assert_eq!(relative(TimeDelta::minutes(60)), "1 hour ago");
+        assert_eq!(relative(TimeDelta::hours(23)), "23 hours ago");
+        assert_eq!(relative(TimeDelta::hours(24)), "1 day ago");
+        assert_eq!(relative(TimeDelta::days(6)), "6 days ago");
+        assert_eq!(relative(TimeDelta::days(7)), "1 week ago");
+        assert_eq!(relative(TimeDelta::days(29)), "4 weeks ago");
+        assert_eq!(relative(TimeDelta::days(30)), "1 month ago");
+        assert_eq!(relative(TimeDelta::days(364)), "12 months ago");
+        assert_eq!(relative(TimeDelta::days(365)), "1 year ago");
+        assert_eq!(relative(TimeDelta::days(800)), "2 years ago");
+    }
+
+    #[test]
+    fn relative_time_of_a_future_date_is_zero_seconds() {
+        assert_eq!(relative(chrono::TimeDelta::seconds(-30)), "0 seconds ago");
+    }
+
+    #[test]
+    fn format_size_picks_a_readable_unit() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(14_540), "14.2 KB");
+        assert_eq!(format_size(3_250_586), "3.1 MB");
+    }
+
+    #[test]
+    fn format_version_pads_to_full_semver() {
+        assert_eq!(format_version("1.0.210"), "1.0.210");
+        assert_eq!(format_version("1.2"), "1.2.0");
+        assert_eq!(format_version("v3"), "3.0.0");
+        assert_eq!(format_version("2.0.0-rc.1"), "2.0.0-rc.1");
+        assert_eq!(format_version("git"), "git");
+    }
+
+    #[test]
+    fn format_version_req_spells_out_the_operator() {
+        assert_eq!(format_version_req("1.2"), "^1.2");
+        assert_eq!(format_version_req(">=1"), ">=1");
+        assert_eq!(format_version_req("=0.4.1"), "=0.4.1");
+        assert_eq!(format_version_req("1.0, ~2.1"), "^1.0, ~2.1");
+        assert_eq!(format_version_req("not a req"), "not a req");
+    }
+
+    #[test]
+    fn elide_cuts_long_values_to_the_width() {
+        assert_eq!(elide("https://docs.rs/serde", 30), "https://docs.rs/serde");
+        assert_eq!(elide("https://docs.rs/serde", 12), "https://doc…");
+        assert_eq!(elide("https://docs.rs/serde", 1), "…");
+    }
+}